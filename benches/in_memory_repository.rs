@@ -0,0 +1,184 @@
+//! Throughput of the in-memory repository under concurrent load.
+//!
+//! Credits for distinct clients only contend on their own lock, so they should scale with the
+//! threads, while credits for the same client serialize on it. The baseline runs the same credits
+//! behind a single global lock, as every operation did before the locks per client. With a
+//! write-ahead log every credit also appends a line under the lock of the log, which serializes
+//! them again. Lookups by document go through an index, so they should not grow with the clients.
+
+use std::{
+    future::Future,
+    hint::black_box,
+    pin::pin,
+    sync::Mutex,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use prex_core_challenge::domain::{
+    model::{
+        dto::{create_client::CreateClientRequest, credit_transaction::CreditTransactionRequest},
+        value::{
+            birth_date::BirthDate, client_id::ClientId, client_name::ClientName, country::Country,
+            document::Document, document_type::DocumentType,
+        },
+    },
+    port::outbound::client_balance_repository::ClientBalanceRepository,
+};
+use prex_core_challenge::infrastructure::outbound::{
+    in_memory::InMemoryRepository,
+    write_ahead_log::{FsyncPolicy, WriteAheadLog},
+};
+use rust_decimal::Decimal;
+use tempfile::TempDir;
+
+const THREADS: [usize; 4] = [1, 2, 4, 8];
+const CREDITS_PER_THREAD: usize = 1_000;
+const CLIENTS: [usize; 3] = [100, 1_000, 10_000];
+
+/// The repository never awaits anything, so its futures are ready on their first poll.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+fn document(index: usize) -> Document {
+    Document::new(DocumentType::Dni, &format!("{:08}", 10_000_000 + index)).unwrap()
+}
+
+fn repository_with_clients(clients: usize) -> (InMemoryRepository, Vec<ClientId>) {
+    with_clients(InMemoryRepository::new(), clients)
+}
+
+/// A repository appending to a write-ahead log in a temporary directory, kept alive along with it.
+/// The log is never flushed nor compacted, so only its lock is measured, not the disk.
+fn repository_with_log(clients: usize) -> (InMemoryRepository, Vec<ClientId>, TempDir) {
+    let directory = TempDir::new().unwrap();
+    let write_ahead_log = WriteAheadLog::open(
+        directory.path(),
+        FsyncPolicy::Never,
+        Duration::from_secs(1),
+        u64::MAX,
+    )
+    .unwrap();
+    let repository = InMemoryRepository::new()
+        .with_write_ahead_log(write_ahead_log)
+        .unwrap();
+    let (repository, client_ids) = with_clients(repository, clients);
+    (repository, client_ids, directory)
+}
+
+fn with_clients(
+    repository: InMemoryRepository,
+    clients: usize,
+) -> (InMemoryRepository, Vec<ClientId>) {
+    let client_ids = (0..clients)
+        .map(|index| {
+            let req = CreateClientRequest::new(
+                ClientName::new("Jane Doe").unwrap(),
+                BirthDate::new("1990-01-01").unwrap(),
+                document(index),
+                Country::new("AR").unwrap(),
+            );
+            block_on(repository.create_client(&req))
+                .unwrap()
+                .id()
+                .clone()
+        })
+        .collect();
+    (repository, client_ids)
+}
+
+/// Every thread credits one of the clients, so they share one only if there are fewer of them.
+/// With a global lock, every credit holds it, as when the repository had a single one.
+fn credit_concurrently(
+    repository: &InMemoryRepository,
+    client_ids: &[ClientId],
+    threads: usize,
+    global_lock: Option<&Mutex<()>>,
+) {
+    std::thread::scope(|scope| {
+        for thread in 0..threads {
+            let client_id = &client_ids[thread % client_ids.len()];
+            scope.spawn(move || {
+                let req = CreditTransactionRequest::new(client_id.clone(), Decimal::ONE).unwrap();
+                for _ in 0..CREDITS_PER_THREAD {
+                    let _global_lock = global_lock.map(|lock| lock.lock().unwrap());
+                    black_box(block_on(repository.credit_balance(&req)).unwrap());
+                }
+            });
+        }
+    });
+}
+
+fn concurrent_credits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("concurrent_credits");
+    for threads in THREADS {
+        group.throughput(Throughput::Elements((threads * CREDITS_PER_THREAD) as u64));
+        for (name, clients) in [("distinct_clients", threads), ("same_client", 1)] {
+            group.bench_with_input(BenchmarkId::new(name, threads), &threads, |b, &threads| {
+                // A fresh repository per iteration, so the ledger does not grow across them.
+                b.iter_batched(
+                    || repository_with_clients(clients),
+                    |(repository, client_ids)| {
+                        credit_concurrently(&repository, &client_ids, threads, None)
+                    },
+                    BatchSize::PerIteration,
+                );
+            });
+        }
+        group.bench_with_input(
+            BenchmarkId::new("single_lock_baseline", threads),
+            &threads,
+            |b, &threads| {
+                let global_lock = Mutex::new(());
+                b.iter_batched(
+                    || repository_with_clients(threads),
+                    |(repository, client_ids)| {
+                        credit_concurrently(&repository, &client_ids, threads, Some(&global_lock))
+                    },
+                    BatchSize::PerIteration,
+                );
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("distinct_clients_with_log", threads),
+            &threads,
+            |b, &threads| {
+                b.iter_batched(
+                    || repository_with_log(threads),
+                    |(repository, client_ids, directory)| {
+                        credit_concurrently(&repository, &client_ids, threads, None);
+                        // Dropped once the iteration is measured.
+                        (repository, directory)
+                    },
+                    BatchSize::PerIteration,
+                );
+            },
+        );
+    }
+    group.finish();
+}
+
+fn get_client_by_document(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get_client_by_document");
+    for clients in CLIENTS {
+        let (repository, _) = repository_with_clients(clients);
+        let last_document = document(clients - 1);
+        group.bench_with_input(BenchmarkId::from_parameter(clients), &clients, |b, _| {
+            b.iter(|| {
+                black_box(block_on(repository.get_client_by_document(&last_document)).unwrap())
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, concurrent_credits, get_client_by_document);
+criterion_main!(benches);
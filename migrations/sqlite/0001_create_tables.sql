@@ -0,0 +1,97 @@
+-- Amounts are stored as text, so they keep the exact scale of their `Decimal`.
+
+CREATE TABLE clients (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    birth_date TEXT NOT NULL,
+    document_type TEXT NOT NULL,
+    document TEXT NOT NULL,
+    country TEXT NOT NULL,
+    overdraft_limit TEXT NOT NULL,
+    status TEXT NOT NULL,
+    email TEXT,
+    phone TEXT,
+    version INTEGER NOT NULL
+);
+
+CREATE UNIQUE INDEX clients_document ON clients (document_type, document);
+
+CREATE TABLE accounts (
+    id INTEGER PRIMARY KEY NOT NULL,
+    client_id TEXT NOT NULL REFERENCES clients (id),
+    name TEXT NOT NULL,
+    main INTEGER NOT NULL
+);
+
+CREATE INDEX accounts_client_id ON accounts (client_id);
+
+-- Only one main account per client.
+CREATE UNIQUE INDEX accounts_main ON accounts (client_id) WHERE main = 1;
+
+CREATE TABLE balances (
+    account_id INTEGER NOT NULL REFERENCES accounts (id),
+    currency TEXT NOT NULL,
+    balance TEXT NOT NULL,
+    PRIMARY KEY (account_id, currency)
+);
+
+CREATE TABLE transactions (
+    id INTEGER PRIMARY KEY NOT NULL,
+    client_id TEXT NOT NULL REFERENCES clients (id),
+    account_id INTEGER REFERENCES accounts (id),
+    amount TEXT NOT NULL,
+    currency TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    resulting_balance TEXT NOT NULL,
+    reversal_of INTEGER REFERENCES transactions (id),
+    original_amount TEXT,
+    original_currency TEXT,
+    rate TEXT,
+    spread TEXT,
+    applied_rate TEXT
+);
+
+CREATE INDEX transactions_client_id ON transactions (client_id);
+
+-- A transaction can only be reversed once.
+CREATE UNIQUE INDEX transactions_reversal_of ON transactions (reversal_of) WHERE reversal_of IS NOT NULL;
+
+CREATE TABLE idempotency_records (
+    key TEXT PRIMARY KEY NOT NULL,
+    client_id TEXT NOT NULL REFERENCES clients (id),
+    amount TEXT NOT NULL,
+    currency TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    -- The transaction the request produced, which holds the rest of the returned balance.
+    transaction_id INTEGER NOT NULL REFERENCES transactions (id),
+    held TEXT NOT NULL,
+    version INTEGER NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idempotency_records_created_at ON idempotency_records (created_at);
+
+CREATE TABLE authorizations (
+    id INTEGER PRIMARY KEY NOT NULL,
+    client_id TEXT NOT NULL REFERENCES clients (id),
+    amount TEXT NOT NULL,
+    currency TEXT NOT NULL,
+    status TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    captured_amount TEXT
+);
+
+CREATE INDEX authorizations_client_id ON authorizations (client_id);
+
+CREATE TABLE client_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    client_id TEXT NOT NULL REFERENCES clients (id),
+    field TEXT NOT NULL,
+    old_value TEXT NOT NULL,
+    new_value TEXT NOT NULL,
+    changed_at TEXT NOT NULL
+);
+
+CREATE INDEX client_changes_client_id ON client_changes (client_id);
@@ -0,0 +1,15 @@
+-- The next id of every table with numeric ids. Sequences only grow, so an id is never reused, even
+-- if the row with the greatest one is gone. They start from 0, like the counters in memory, and
+-- continue from the rows stored before this migration.
+
+CREATE TABLE id_sequences (
+    name TEXT PRIMARY KEY NOT NULL,
+    next_id INTEGER NOT NULL
+);
+
+INSERT INTO id_sequences (name, next_id)
+SELECT 'accounts', COALESCE(MAX(id) + 1, 0) FROM accounts
+UNION ALL
+SELECT 'transactions', COALESCE(MAX(id) + 1, 0) FROM transactions
+UNION ALL
+SELECT 'authorizations', COALESCE(MAX(id) + 1, 0) FROM authorizations;
@@ -4,48 +4,112 @@
 */
 
 use anyhow::Context;
+use rust_decimal::Decimal;
 
 use crate::domain::{
     model::{
         dto::{
-            create_client::CreateClientRequest, credit_transaction::CreditTransactionRequest,
+            authorize::AuthorizeRequest, batch_transaction::BatchTransactionRequest,
+            capture_authorization::CaptureAuthorizationRequest,
+            create_account::CreateAccountRequest, create_client::CreateClientRequest,
+            credit_transaction::CreditTransactionRequest,
             debit_transaction::DebitTransactionRequest, get_balance::GetClientRequest,
+            list_clients::ListClientsRequest, reverse_transaction::ReverseTransactionRequest,
+            transfer::TransferRequest, update_client::UpdateClientRequest,
+        },
+        entity::{
+            account::Account, authorization::Authorization, balance::Balance, client::Client,
+            client_change::ClientChange, client_overview::ClientOverview, client_page::ClientPage,
+            conversion::Conversion, transaction::Transaction,
         },
-        entity::{balance::Balance, client::Client},
         error::ClientError,
-        value::{client_id::ClientId, document::Document},
+        value::{
+            authorization_id::AuthorizationId, birth_date::BirthDate,
+            birth_date_rules::BirthDateRules, client_id::ClientId, client_status::ClientStatus,
+            country::Country, currency::Currency, document::Document,
+            transaction_kind::TransactionKind, version::Version,
+        },
     },
     port::{
         inbound::client_balance_service::ClientBalanceService,
         outbound::{
             balance_exporter::BalanceExporter, client_balance_repository::ClientBalanceRepository,
+            clock::Clock, exchange_rate_provider::ExchangeRateProvider,
         },
     },
 };
 
 /// Canonical implementation of the [ClientBalanceService] port, through which the client balance domain API is consumed.
 #[derive(Debug, Clone)]
-pub struct Service<C, E>
+pub struct Service<C, E, R, K>
 where
     C: ClientBalanceRepository,
     E: BalanceExporter,
+    R: ExchangeRateProvider,
+    K: Clock,
 {
     client_repository: C,
     balance_exporter: E,
+    exchange_rate_provider: R,
+    clock: K,
+    birth_date_rules: BirthDateRules,
 }
 
-impl<C, E> Service<C, E>
+impl<C, E, R, K> Service<C, E, R, K>
 where
     C: ClientBalanceRepository,
     E: BalanceExporter,
+    R: ExchangeRateProvider,
+    K: Clock,
 {
-    pub fn new(client_repository: C, balance_exporter: E) -> Self {
+    pub fn new(
+        client_repository: C,
+        balance_exporter: E,
+        exchange_rate_provider: R,
+        clock: K,
+    ) -> Self {
         Self {
             client_repository,
             balance_exporter,
+            exchange_rate_provider,
+            clock,
+            birth_date_rules: BirthDateRules::default(),
         }
     }
 
+    /// Replaces the default [BirthDateRules] the clients are validated with.
+    pub fn with_birth_date_rules(mut self, birth_date_rules: BirthDateRules) -> Self {
+        self.birth_date_rules = birth_date_rules;
+        self
+    }
+
+    fn validate_birth_date(
+        &self,
+        birth_date: &BirthDate,
+        country: &Country,
+    ) -> Result<(), ClientError> {
+        let today = self.clock.now().date_naive();
+        self.birth_date_rules.validate(birth_date, country, today)
+    }
+
+    /// Converts the amount into the balance currency when it differs from the currency of the amount.
+    async fn convert(
+        &self,
+        amount: &Decimal,
+        currency: &Currency,
+        balance_currency: Option<&Currency>,
+    ) -> Result<Option<Conversion>, ClientError> {
+        let Some(balance_currency) = balance_currency.filter(|c| *c != currency) else {
+            return Ok(None);
+        };
+
+        let exchange_rate = self
+            .exchange_rate_provider
+            .get_exchange_rate(currency, balance_currency)
+            .await?;
+        Ok(Some(exchange_rate.convert(amount)?))
+    }
+
     async fn validate_client_exists(&self, client_id: &ClientId) -> Result<(), ClientError> {
         if !self.client_repository.client_id_exists(client_id).await? {
             return Err(ClientError::NotFoundById {
@@ -56,15 +120,18 @@ where
         Ok(())
     }
 
+    /// Fails if a [Client] other than the given owner already has the [Document].
     async fn validate_client_exists_by_document(
         &self,
         document: &Document,
+        owner: Option<&ClientId>,
     ) -> Result<(), ClientError> {
         let result = self
             .client_repository
             .get_client_by_document(document)
             .await;
         match result {
+            Ok(client) if Some(client.id()) == owner => Ok(()),
             Ok(_) => Err(ClientError::Duplicate {
                 document: document.to_string(),
             }),
@@ -76,33 +143,207 @@ where
     }
 }
 
-impl<C, E> ClientBalanceService for Service<C, E>
+impl<C, E, R, K> ClientBalanceService for Service<C, E, R, K>
 where
     C: ClientBalanceRepository,
     E: BalanceExporter,
+    R: ExchangeRateProvider,
+    K: Clock,
 {
     async fn create_client(&self, req: &CreateClientRequest) -> Result<Client, ClientError> {
-        self.validate_client_exists_by_document(req.document())
+        self.validate_birth_date(req.birth_date(), req.country())?;
+        self.validate_client_exists_by_document(req.document(), None)
             .await?;
 
         let client = self.client_repository.create_client(req).await?;
         Ok(client)
     }
 
+    async fn create_account(&self, req: &CreateAccountRequest) -> Result<Account, ClientError> {
+        self.validate_client_exists(req.client_id()).await?;
+
+        let account = self.client_repository.create_account(req).await?;
+        Ok(account)
+    }
+
+    async fn get_client_by_document(&self, document: &Document) -> Result<Client, ClientError> {
+        let client = self
+            .client_repository
+            .get_client_by_document(document)
+            .await?;
+        Ok(client)
+    }
+
+    async fn list_clients(&self, req: &ListClientsRequest) -> Result<ClientPage, ClientError> {
+        let page = self.client_repository.list_clients(req).await?;
+        Ok(page)
+    }
+
+    async fn update_client(&self, req: &UpdateClientRequest) -> Result<Client, ClientError> {
+        self.validate_client_exists(req.client_id()).await?;
+        if req.birth_date().is_some() || req.country().is_some() {
+            // The minimum age depends on the country, so a change of any of them is checked
+            // against the current value of the other one.
+            let client = self
+                .client_repository
+                .get_client(&GetClientRequest::new(req.client_id().clone()))
+                .await?;
+            self.validate_birth_date(
+                req.birth_date().unwrap_or(client.birth_date()),
+                req.country().unwrap_or(client.country()),
+            )?;
+        }
+        if let Some(document) = req.document() {
+            self.validate_client_exists_by_document(document, Some(req.client_id()))
+                .await?;
+        }
+
+        // The repository checks the document again under its lock, so two concurrent updates
+        // cannot take the same document.
+        let client = self.client_repository.update_client(req).await?;
+        Ok(client)
+    }
+
+    async fn get_client_changes(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<ClientChange>, ClientError> {
+        self.validate_client_exists(req.client_id()).await?;
+
+        let changes = self.client_repository.get_client_changes(req).await?;
+        Ok(changes)
+    }
+
     async fn credit_balance(&self, req: &CreditTransactionRequest) -> Result<Balance, ClientError> {
         self.validate_client_exists(req.client_id()).await?;
 
-        let balance = self.client_repository.credit_balance(req).await?;
+        let balance = match self
+            .convert(req.amount(), req.currency(), req.balance_currency())
+            .await?
+        {
+            Some(conversion) => {
+                let req = req.clone().with_conversion(conversion);
+                self.client_repository.credit_balance(&req).await?
+            }
+            None => self.client_repository.credit_balance(req).await?,
+        };
         Ok(balance)
     }
 
     async fn debit_balance(&self, req: &DebitTransactionRequest) -> Result<Balance, ClientError> {
         self.validate_client_exists(req.client_id()).await?;
 
-        let balance = self.client_repository.debit_balance(req).await?;
+        let balance = match self
+            .convert(req.amount(), req.currency(), req.balance_currency())
+            .await?
+        {
+            Some(conversion) => {
+                let req = req.clone().with_conversion(conversion);
+                self.client_repository.debit_balance(&req).await?
+            }
+            None => self.client_repository.debit_balance(req).await?,
+        };
+        Ok(balance)
+    }
+
+    async fn transfer(&self, req: &TransferRequest) -> Result<(Balance, Balance), ClientError> {
+        self.validate_client_exists(req.from_client_id()).await?;
+        self.validate_client_exists(req.to_client_id()).await?;
+
+        let balances = self.client_repository.transfer(req).await?;
+        Ok(balances)
+    }
+
+    async fn apply_batch(
+        &self,
+        req: &BatchTransactionRequest,
+    ) -> Result<Vec<Balance>, ClientError> {
+        // The repository checks that every client exists under its lock, so the index of the
+        // failing item can be reported.
+        let balances = self.client_repository.apply_batch(req).await?;
+        Ok(balances)
+    }
+
+    async fn reverse_transaction(
+        &self,
+        req: &ReverseTransactionRequest,
+    ) -> Result<Balance, ClientError> {
+        let transaction = self
+            .client_repository
+            .get_transaction(req.transaction_id())
+            .await?;
+        let amount = req
+            .amount()
+            .copied()
+            .unwrap_or_else(|| transaction.amount().abs());
+        let client_id = transaction.client_id().clone();
+        let currency = transaction.currency().clone();
+
+        // The repository checks the reversal again under its lock, so two concurrent reversals
+        // of the same transaction cannot both be applied.
+        let balance = match transaction.kind() {
+            TransactionKind::Credit => {
+                let mut debit = DebitTransactionRequest::new(client_id, -amount)?
+                    .with_currency(currency)
+                    .with_reversal_of(transaction.id().clone());
+                if let Some(account_id) = transaction.account_id() {
+                    debit = debit.with_account_id(account_id.clone());
+                }
+                if let Some(expected_version) = req.expected_version() {
+                    debit = debit.with_expected_version(*expected_version);
+                }
+                self.client_repository.debit_balance(&debit).await?
+            }
+            TransactionKind::Debit => {
+                let mut credit = CreditTransactionRequest::new(client_id, amount)?
+                    .with_currency(currency)
+                    .with_reversal_of(transaction.id().clone());
+                if let Some(account_id) = transaction.account_id() {
+                    credit = credit.with_account_id(account_id.clone());
+                }
+                if let Some(expected_version) = req.expected_version() {
+                    credit = credit.with_expected_version(*expected_version);
+                }
+                self.client_repository.credit_balance(&credit).await?
+            }
+            TransactionKind::TransferIn
+            | TransactionKind::TransferOut
+            | TransactionKind::Adjustment => {
+                return Err(ClientError::TransactionNotReversible {
+                    transaction_id: transaction.id().clone(),
+                });
+            }
+        };
+        Ok(balance)
+    }
+
+    async fn authorize(&self, req: &AuthorizeRequest) -> Result<Authorization, ClientError> {
+        self.validate_client_exists(req.client_id()).await?;
+
+        let authorization = self.client_repository.authorize(req).await?;
+        Ok(authorization)
+    }
+
+    async fn capture_authorization(
+        &self,
+        req: &CaptureAuthorizationRequest,
+    ) -> Result<Balance, ClientError> {
+        let balance = self.client_repository.capture_authorization(req).await?;
         Ok(balance)
     }
 
+    async fn void_authorization(
+        &self,
+        authorization_id: &AuthorizationId,
+        expected_version: Option<Version>,
+    ) -> Result<Authorization, ClientError> {
+        let authorization = self
+            .client_repository
+            .void_authorization(authorization_id, expected_version)
+            .await?;
+        Ok(authorization)
+    }
+
     async fn get_balance_by_client_id(
         &self,
         req: &GetClientRequest,
@@ -113,6 +354,32 @@ where
         Ok(balance)
     }
 
+    async fn get_balances_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<Balance>, ClientError> {
+        self.validate_client_exists(req.client_id()).await?;
+
+        let balances = self
+            .client_repository
+            .get_balances_by_client_id(req)
+            .await?;
+        Ok(balances)
+    }
+
+    async fn get_accounts_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<(Account, Vec<Balance>)>, ClientError> {
+        self.validate_client_exists(req.client_id()).await?;
+
+        let accounts = self
+            .client_repository
+            .get_accounts_by_client_id(req)
+            .await?;
+        Ok(accounts)
+    }
+
     async fn get_client_by_id(&self, req: &GetClientRequest) -> Result<Client, ClientError> {
         self.validate_client_exists(req.client_id()).await?;
 
@@ -120,12 +387,84 @@ where
         Ok(client)
     }
 
+    async fn get_client_overview(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<ClientOverview, ClientError> {
+        self.validate_client_exists(req.client_id()).await?;
+
+        let overview = self.client_repository.get_client_overview(req).await?;
+        Ok(overview)
+    }
+
+    async fn get_transactions_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<Transaction>, ClientError> {
+        self.validate_client_exists(req.client_id()).await?;
+
+        let mut transactions = self
+            .client_repository
+            .get_transactions_by_client_id(req)
+            .await?;
+        transactions.sort_by(|a, b| {
+            b.created_at()
+                .cmp(a.created_at())
+                .then_with(|| b.id().cmp(a.id()))
+        });
+        Ok(transactions)
+    }
+
+    async fn block_client(
+        &self,
+        client_id: &ClientId,
+        expected_version: Option<Version>,
+    ) -> Result<Client, ClientError> {
+        self.validate_client_exists(client_id).await?;
+
+        let client = self
+            .client_repository
+            .change_client_status(client_id, ClientStatus::Blocked, expected_version)
+            .await?;
+        Ok(client)
+    }
+
+    async fn unblock_client(
+        &self,
+        client_id: &ClientId,
+        expected_version: Option<Version>,
+    ) -> Result<Client, ClientError> {
+        self.validate_client_exists(client_id).await?;
+
+        let client = self
+            .client_repository
+            .change_client_status(client_id, ClientStatus::Active, expected_version)
+            .await?;
+        Ok(client)
+    }
+
+    async fn close_client(
+        &self,
+        client_id: &ClientId,
+        expected_version: Option<Version>,
+    ) -> Result<Client, ClientError> {
+        self.validate_client_exists(client_id).await?;
+
+        // The repository checks the balances under its lock, so a concurrent credit cannot slip
+        // in between the check and the close.
+        let client = self
+            .client_repository
+            .change_client_status(client_id, ClientStatus::Closed, expected_version)
+            .await?;
+        Ok(client)
+    }
+
     async fn store_balances(&self) -> Result<(), ClientError> {
         if self.client_repository.are_balances_empty().await? {
             return Err(ClientError::BalancesEmpty);
         }
 
-        let old_balance_clients = self
+        let reset = self
             .client_repository
             .reset_all_balances_to_zero()
             .await
@@ -133,7 +472,7 @@ where
 
         if let Err(e) = self
             .balance_exporter
-            .export_balances(&old_balance_clients)
+            .export_balances(reset.old_balances())
             .await
             .with_context(|| "Error exporting balances")
         {
@@ -142,7 +481,7 @@ where
             // Temporarily we are merging the old balances again!
             tracing::warn!("Error exporting balances, merging old balances again...");
             self.client_repository
-                .merge_old_balances(old_balance_clients)
+                .merge_old_balances(reset)
                 .await
                 .with_context(|| "Error merging old balances")?;
             return Err(ClientError::Unknown(e));
@@ -154,6 +493,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::domain::model::value::document_type::DocumentType;
     use std::{
         collections::HashMap,
         sync::{
@@ -166,15 +506,31 @@ mod tests {
 
     use crate::domain::{
         model::value::{
-            birth_date::BirthDate, client_name::ClientName, country::Country, document::Document,
+            account_id::AccountId,
+            account_name::AccountName,
+            authorization_status::AuthorizationStatus,
+            birth_date::BirthDate,
+            client_cursor::ClientCursor,
+            client_name::ClientName,
+            client_sort::{ClientSort, SortDirection},
+            country::Country,
+            currency::Currency,
+            document::Document,
+            email::Email,
+            idempotency_key::IdempotencyKey,
+            overdraft_limit::OverdraftLimit,
+            phone_number::PhoneNumber,
+            transaction_id::TransactionId,
         },
         port::outbound::{
             balance_exporter::MockBalanceExporter,
-            client_balance_repository::MockClientBalanceRepository,
+            client_balance_repository::MockClientBalanceRepository, clock::MockClock,
+            exchange_rate_provider::MockExchangeRateProvider,
         },
     };
 
     use super::*;
+    use crate::domain::model::entity::{balance_reset::BalanceReset, exchange_rate::ExchangeRate};
 
     type ClientsHashMap = Arc<Mutex<HashMap<ClientId, Client>>>;
     type ClientBalancesHashMap = Arc<Mutex<HashMap<ClientId, Balance>>>;
@@ -205,7 +561,10 @@ mod tests {
                     req.birth_date().clone(),
                     req.document().clone(),
                     req.country().clone(),
-                );
+                )
+                .with_overdraft_limit(req.overdraft_limit().clone())
+                .with_email(req.email().cloned())
+                .with_phone(req.phone().cloned());
 
                 arc_mutex_clients_1
                     .lock()
@@ -315,6 +674,32 @@ mod tests {
                 }
             });
 
+        let arc_mutex_client_balances_7 = arc_mutex_client_balances.clone();
+        client_balance_repository
+            .expect_transfer()
+            .returning(move |req| {
+                let mut map = arc_mutex_client_balances_7.lock().unwrap();
+                for client_id in [req.from_client_id(), req.to_client_id()] {
+                    if !map.contains_key(client_id) {
+                        let client_id_clone = client_id.clone();
+                        return Box::pin(async move {
+                            Err(ClientError::NotFoundById {
+                                id_document: client_id_clone,
+                            })
+                        });
+                    }
+                }
+                let from = map.get_mut(req.from_client_id()).unwrap();
+                let new_balance = from.balance() - req.amount();
+                from.set_balance(new_balance);
+                let from = from.clone();
+                let to = map.get_mut(req.to_client_id()).unwrap();
+                let new_balance = to.balance() + req.amount();
+                to.set_balance(new_balance);
+                let to = to.clone();
+                Box::pin(async move { Ok((from, to)) })
+            });
+
         client_balance_repository
             .expect_are_balances_empty()
             .returning(move || Box::pin(async move { Ok(false) }));
@@ -350,7 +735,7 @@ mod tests {
                     let old_balance = balance.set_balance(Decimal::ZERO);
                     old_balances.push(Balance::new(balance.client_id().clone(), old_balance));
                 });
-                Box::pin(async move { Ok(old_balances) })
+                Box::pin(async move { Ok(BalanceReset::new(old_balances, Vec::new())) })
             });
 
         balance_exporter
@@ -360,9 +745,9 @@ mod tests {
         let arc_mutex_client_balances_6 = arc_mutex_client_balances.clone();
         client_balance_repository
             .expect_merge_old_balances()
-            .returning(move |old_balances| {
+            .returning(move |reset| {
                 let mut map = arc_mutex_client_balances_6.lock().unwrap();
-                old_balances.iter().for_each(|old_balance| {
+                reset.old_balances().iter().for_each(|old_balance| {
                     let actual_balance = map.get_mut(old_balance.client_id()).unwrap();
                     let new_balance_recorded = actual_balance.balance() + old_balance.balance();
                     actual_balance.set_balance(new_balance_recorded);
@@ -373,17 +758,33 @@ mod tests {
         (client_balance_repository, balance_exporter)
     }
 
+    /// A [Clock] stopped at 2026-06-15, so the age of the clients does not depend on when the tests run.
+    fn fixed_clock() -> MockClock {
+        let mut clock = MockClock::new();
+        clock.expect_now().returning(|| {
+            chrono::DateTime::parse_from_rfc3339("2026-06-15T12:00:00Z")
+                .unwrap()
+                .to_utc()
+        });
+        clock
+    }
+
     #[tokio::test]
     async fn test_01_given_a_client_when_creating_it_then_it_should_return_the_client_id_created() {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req_create = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
 
@@ -400,20 +801,25 @@ mod tests {
      {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
-        let document = "1234567890";
+        let document = "12345678";
         let req_create_1 = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new(document).unwrap(),
+            Document::new(DocumentType::Dni, document).unwrap(),
             Country::new("US").unwrap(),
         );
         let req_create_2 = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new(document).unwrap(),
+            Document::new(DocumentType::Dni, document).unwrap(),
             Country::new("US").unwrap(),
         );
 
@@ -427,7 +833,9 @@ mod tests {
         assert_eq!(
             result_create_2.err().unwrap(),
             ClientError::Duplicate {
-                document: document.to_string(),
+                document: Document::new(DocumentType::Dni, document)
+                    .unwrap()
+                    .to_string(),
             }
         );
     }
@@ -437,13 +845,18 @@ mod tests {
      {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req_create = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
         let result_create = client_balance_service.create_client(&req_create).await;
@@ -466,17 +879,22 @@ mod tests {
      {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let client_name = "John Doe";
         let birth_date = "1990-01-01";
-        let document = "1234567890";
+        let document = "12345678";
         let country = "US";
         let req_create = CreateClientRequest::new(
             ClientName::new(client_name).unwrap(),
             BirthDate::new(birth_date).unwrap(),
-            Document::new(document).unwrap(),
+            Document::new(DocumentType::Dni, document).unwrap(),
             Country::new(country).unwrap(),
         );
         let result_create = client_balance_service.create_client(&req_create).await;
@@ -491,7 +909,10 @@ mod tests {
         let client = result_get.unwrap();
         assert_eq!(client.name(), &ClientName::new(client_name).unwrap());
         assert_eq!(client.birth_date(), &BirthDate::new(birth_date).unwrap());
-        assert_eq!(client.document(), &Document::new(document).unwrap());
+        assert_eq!(
+            client.document(),
+            &Document::new(DocumentType::Dni, document).unwrap()
+        );
         assert_eq!(client.country(), &Country::new(country).unwrap());
     }
 
@@ -505,7 +926,7 @@ mod tests {
             .returning(|_| {
                 Box::pin(async {
                     Err(ClientError::NotFoundByDocument {
-                        document: Document::new("1234567890").unwrap(),
+                        document: Document::new(DocumentType::Dni, "12345678").unwrap(),
                     })
                 })
             });
@@ -522,13 +943,18 @@ mod tests {
             )),
             None,
         );
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req_create = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
         // WHEN
@@ -560,13 +986,18 @@ mod tests {
             )),
             None,
         );
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req_create = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
         // WHEN
@@ -585,13 +1016,18 @@ mod tests {
      {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
 
@@ -630,7 +1066,12 @@ mod tests {
     async fn test_08_given_nonexistent_client_when_credit_balance_then_should_return_not_found() {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let client_id = ClientId::new("1").unwrap();
@@ -669,7 +1110,12 @@ mod tests {
             )),
             None,
         );
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let client_id = ClientId::new("1").unwrap();
@@ -690,7 +1136,12 @@ mod tests {
     async fn test_10_given_nonexistent_client_when_debit_balance_then_should_return_not_found() {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let client_id = ClientId::new("1").unwrap();
@@ -729,7 +1180,12 @@ mod tests {
             )),
             None,
         );
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let client_id = ClientId::new("1").unwrap();
@@ -750,7 +1206,12 @@ mod tests {
     async fn test_12_given_nonexistent_client_when_get_balance_then_should_return_not_found() {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let client_id = ClientId::new("1").unwrap();
@@ -789,7 +1250,12 @@ mod tests {
             )),
             None,
         );
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let client_id = ClientId::new("1").unwrap();
@@ -810,7 +1276,12 @@ mod tests {
     async fn test_14_given_nonexistent_client_when_get_client_then_should_return_not_found() {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let client_id = ClientId::new("1").unwrap();
@@ -849,7 +1320,12 @@ mod tests {
             )),
             None,
         );
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let client_id = ClientId::new("1").unwrap();
@@ -870,13 +1346,18 @@ mod tests {
     async fn test_16_given_one_client_when_store_balances_then_balances_are_zero_and_exported() {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req_create = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
         let client = client_balance_service
@@ -909,19 +1390,24 @@ mod tests {
      {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN: crear dos clientes usando el servicio
         let req_create_1 = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
         let req_create_2 = CreateClientRequest::new(
             ClientName::new("Jane Roe").unwrap(),
             BirthDate::new("1992-02-02").unwrap(),
-            Document::new("9876543210").unwrap(),
+            Document::new(DocumentType::Dni, "98765432").unwrap(),
             Country::new("AR").unwrap(),
         );
         let client_1 = client_balance_service
@@ -970,25 +1456,30 @@ mod tests {
     async fn test_18_given_balances_negative_and_positive_when_store_balances_then_all_zero() {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req_create_1 = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
         let req_create_2 = CreateClientRequest::new(
             ClientName::new("Jane Roe").unwrap(),
             BirthDate::new("1992-02-02").unwrap(),
-            Document::new("9876543210").unwrap(),
+            Document::new(DocumentType::Dni, "98765432").unwrap(),
             Country::new("AR").unwrap(),
         );
         let req_create_3 = CreateClientRequest::new(
             ClientName::new("Foo Bar").unwrap(),
             BirthDate::new("1980-03-03").unwrap(),
-            Document::new("5555555555").unwrap(),
+            Document::new(DocumentType::Dni, "55555555").unwrap(),
             Country::new("BR").unwrap(),
         );
         let client_1 = client_balance_service
@@ -1054,13 +1545,18 @@ mod tests {
     async fn test_19_given_balances_already_zero_when_store_balances_then_exporter_receives_zero() {
         // SETUP
         let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req_create = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
         let client = client_balance_service
@@ -1097,7 +1593,12 @@ mod tests {
             )),
             None,
         );
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // WHEN
         let result = client_balance_service.store_balances().await;
@@ -1125,19 +1626,24 @@ mod tests {
             )),
             None,
         );
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req_create_1 = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
         let req_create_2 = CreateClientRequest::new(
             ClientName::new("Jane Roe").unwrap(),
             BirthDate::new("1992-02-02").unwrap(),
-            Document::new("9876543210").unwrap(),
+            Document::new(DocumentType::Dni, "98765432").unwrap(),
             Country::new("AR").unwrap(),
         );
         let client_1 = client_balance_service
@@ -1200,19 +1706,24 @@ mod tests {
         });
         let (client_balance_repository, balance_exporter) =
             setup_general_mocks(None, Some(balance_exporter));
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req_create_1 = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
         let req_create_2 = CreateClientRequest::new(
             ClientName::new("Jane Roe").unwrap(),
             BirthDate::new("1992-02-02").unwrap(),
-            Document::new("9876543210").unwrap(),
+            Document::new(DocumentType::Dni, "98765432").unwrap(),
             Country::new("AR").unwrap(),
         );
         let client_1 = client_balance_service
@@ -1288,19 +1799,24 @@ mod tests {
             )),
             Some(balance_exporter),
         );
-        let client_balance_service = Service::new(client_balance_repository, balance_exporter);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
 
         // GIVEN
         let req_create_1 = CreateClientRequest::new(
             ClientName::new("John Doe").unwrap(),
             BirthDate::new("1990-01-01").unwrap(),
-            Document::new("1234567890").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
             Country::new("US").unwrap(),
         );
         let req_create_2 = CreateClientRequest::new(
             ClientName::new("Jane Roe").unwrap(),
             BirthDate::new("1992-02-02").unwrap(),
-            Document::new("9876543210").unwrap(),
+            Document::new(DocumentType::Dni, "98765432").unwrap(),
             Country::new("AR").unwrap(),
         );
         let client_1 = client_balance_service
@@ -1352,4 +1868,1786 @@ mod tests {
         assert_eq!(balance_1.balance(), &Decimal::ZERO);
         assert_eq!(balance_2.balance(), &Decimal::ZERO);
     }
+
+    #[tokio::test]
+    async fn test_24_given_unordered_transactions_in_repository_when_getting_transactions_then_they_should_be_newest_first()
+     {
+        // SETUP
+        let client_id = ClientId::new("1").unwrap();
+        let now = chrono::Utc::now();
+        let oldest = Transaction::new(
+            TransactionId::new("0").unwrap(),
+            client_id.clone(),
+            Decimal::from(100),
+            TransactionKind::Credit,
+            now - chrono::Duration::seconds(10),
+            Decimal::from(100),
+        );
+        let same_instant_first = Transaction::new(
+            TransactionId::new("1").unwrap(),
+            client_id.clone(),
+            Decimal::from(-30),
+            TransactionKind::Debit,
+            now,
+            Decimal::from(70),
+        );
+        let same_instant_second = Transaction::new(
+            TransactionId::new("2").unwrap(),
+            client_id.clone(),
+            Decimal::from(5),
+            TransactionKind::Credit,
+            now,
+            Decimal::from(75),
+        );
+        let stored = vec![
+            same_instant_first.clone(),
+            oldest.clone(),
+            same_instant_second.clone(),
+        ];
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_get_transactions_by_client_id()
+            .returning(move |_| {
+                let stored = stored.clone();
+                Box::pin(async move { Ok(stored) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // WHEN
+        let result = client_balance_service
+            .get_transactions_by_client_id(&GetClientRequest::new(client_id))
+            .await;
+
+        // THEN
+        assert_eq!(
+            result.unwrap(),
+            vec![same_instant_second, same_instant_first, oldest]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_25_given_nonexistent_client_when_getting_transactions_then_should_return_not_found()
+     {
+        // SETUP
+        let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+        let req = GetClientRequest::new(client_id.clone());
+
+        // WHEN
+        let result = client_balance_service
+            .get_transactions_by_client_id(&req)
+            .await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::NotFoundById {
+                id_document: client_id
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_26_given_error_in_repository_when_getting_transactions_then_should_return_error()
+    {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_get_transactions_by_client_id()
+            .returning(|_| {
+                Box::pin(async { Err(ClientError::Unknown(anyhow::anyhow!("ka boom!"))) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // WHEN
+        let result = client_balance_service
+            .get_transactions_by_client_id(&GetClientRequest::new(ClientId::new("1").unwrap()))
+            .await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::Unknown(anyhow::anyhow!("ka boom!"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_27_given_an_idempotency_key_reused_with_a_different_payload_when_debit_balance_then_should_return_conflict()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_debit_balance()
+            .withf(|req| req.idempotency_key().is_some())
+            .returning(|req| {
+                let key = req.idempotency_key().unwrap().clone();
+                Box::pin(async move { Err(ClientError::IdempotencyKeyConflict { key }) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let key = IdempotencyKey::new("retry-1").unwrap();
+        let req = DebitTransactionRequest::new(ClientId::new("1").unwrap(), Decimal::from(-10))
+            .unwrap()
+            .with_idempotency_key(key.clone());
+
+        // WHEN
+        let result = client_balance_service.debit_balance(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::IdempotencyKeyConflict { key }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_28_given_two_clients_when_transfer_then_both_balances_should_be_updated() {
+        // SETUP
+        let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_1 = client_balance_service
+            .create_client(&CreateClientRequest::new(
+                ClientName::new("John Doe").unwrap(),
+                BirthDate::new("1990-01-01").unwrap(),
+                Document::new(DocumentType::Dni, "12345678").unwrap(),
+                Country::new("US").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let client_2 = client_balance_service
+            .create_client(&CreateClientRequest::new(
+                ClientName::new("Jane Roe").unwrap(),
+                BirthDate::new("1992-02-02").unwrap(),
+                Document::new(DocumentType::Dni, "98765432").unwrap(),
+                Country::new("AR").unwrap(),
+            ))
+            .await
+            .unwrap();
+        client_balance_service
+            .credit_balance(
+                &CreditTransactionRequest::new(client_1.id().clone(), Decimal::from(100)).unwrap(),
+            )
+            .await
+            .unwrap();
+        let req = TransferRequest::new(
+            client_1.id().clone(),
+            client_2.id().clone(),
+            Decimal::from(40),
+        )
+        .unwrap();
+
+        // WHEN
+        let (from, to) = client_balance_service.transfer(&req).await.unwrap();
+
+        // THEN
+        assert_eq!(from.client_id(), client_1.id());
+        assert_eq!(from.balance(), &Decimal::from(60));
+        assert_eq!(to.client_id(), client_2.id());
+        assert_eq!(to.balance(), &Decimal::from(40));
+    }
+
+    #[tokio::test]
+    async fn test_29_given_nonexistent_destination_client_when_transfer_then_should_return_not_found_and_origin_remains_unchanged()
+     {
+        // SETUP
+        let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client = client_balance_service
+            .create_client(&CreateClientRequest::new(
+                ClientName::new("John Doe").unwrap(),
+                BirthDate::new("1990-01-01").unwrap(),
+                Document::new(DocumentType::Dni, "12345678").unwrap(),
+                Country::new("US").unwrap(),
+            ))
+            .await
+            .unwrap();
+        client_balance_service
+            .credit_balance(
+                &CreditTransactionRequest::new(client.id().clone(), Decimal::from(100)).unwrap(),
+            )
+            .await
+            .unwrap();
+        let nonexistent_client_id = ClientId::new("99").unwrap();
+        let req = TransferRequest::new(
+            client.id().clone(),
+            nonexistent_client_id.clone(),
+            Decimal::from(40),
+        )
+        .unwrap();
+
+        // WHEN
+        let result = client_balance_service.transfer(&req).await;
+        let balance = client_balance_service
+            .get_balance_by_client_id(&GetClientRequest::new(client.id().clone()))
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::NotFoundById {
+                id_document: nonexistent_client_id
+            }
+        );
+        assert_eq!(balance.balance(), &Decimal::from(100));
+    }
+
+    #[tokio::test]
+    async fn test_30_given_error_in_repository_when_transfer_then_should_return_error() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository.expect_transfer().returning(|_| {
+            Box::pin(async { Err(ClientError::Unknown(anyhow::anyhow!("ka boom!"))) })
+        });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = TransferRequest::new(
+            ClientId::new("1").unwrap(),
+            ClientId::new("2").unwrap(),
+            Decimal::from(40),
+        )
+        .unwrap();
+
+        // WHEN
+        let result = client_balance_service.transfer(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::Unknown(anyhow::anyhow!("ka boom!"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_31_given_insufficient_funds_in_repository_when_debit_balance_then_should_return_insufficient_funds()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_debit_balance()
+            .returning(|req| {
+                let client_id = req.client_id().clone();
+                Box::pin(async move { Err(ClientError::InsufficientFunds { client_id }) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+        let req = DebitTransactionRequest::new(client_id.clone(), Decimal::from(-10)).unwrap();
+
+        // WHEN
+        let result = client_balance_service.debit_balance(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::InsufficientFunds { client_id }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_32_given_a_client_with_overdraft_limit_when_creating_it_then_the_limit_should_be_stored()
+     {
+        // SETUP
+        let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let limit = OverdraftLimit::new(Decimal::from(500)).unwrap();
+        let req_create = CreateClientRequest::new(
+            ClientName::new("John Doe").unwrap(),
+            BirthDate::new("1990-01-01").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
+            Country::new("US").unwrap(),
+        )
+        .with_overdraft_limit(limit.clone());
+
+        // WHEN
+        let client = client_balance_service
+            .create_client(&req_create)
+            .await
+            .unwrap();
+        let stored = client_balance_service
+            .get_client_by_id(&GetClientRequest::new(client.id().clone()))
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(stored.overdraft_limit(), &limit);
+    }
+
+    fn transaction_of(kind: TransactionKind, amount: i64) -> Transaction {
+        Transaction::new(
+            TransactionId::new("5").unwrap(),
+            ClientId::new("1").unwrap(),
+            Decimal::from(amount),
+            kind,
+            chrono::Utc::now(),
+            Decimal::from(100),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_33_given_a_credit_when_reversing_it_without_amount_then_should_debit_the_whole_amount()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_get_transaction()
+            .returning(|_| Box::pin(async { Ok(transaction_of(TransactionKind::Credit, 50)) }));
+        client_balance_repository
+            .expect_debit_balance()
+            .withf(|req| {
+                req.amount() == &Decimal::from(-50)
+                    && req.reversal_of() == Some(&TransactionId::new("5").unwrap())
+            })
+            .times(1)
+            .returning(|req| {
+                let client_id = req.client_id().clone();
+                Box::pin(async move { Ok(Balance::new(client_id, Decimal::from(50))) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = ReverseTransactionRequest::new(TransactionId::new("5").unwrap(), None).unwrap();
+
+        // WHEN
+        let balance = client_balance_service
+            .reverse_transaction(&req)
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(balance.client_id(), &ClientId::new("1").unwrap());
+        assert_eq!(balance.balance(), &Decimal::from(50));
+    }
+
+    #[tokio::test]
+    async fn test_34_given_a_debit_when_reversing_it_partially_then_should_credit_the_given_amount()
+    {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_get_transaction()
+            .returning(|_| Box::pin(async { Ok(transaction_of(TransactionKind::Debit, -30)) }));
+        client_balance_repository
+            .expect_credit_balance()
+            .withf(|req| {
+                req.amount() == &Decimal::from(10)
+                    && req.reversal_of() == Some(&TransactionId::new("5").unwrap())
+            })
+            .times(1)
+            .returning(|req| {
+                let client_id = req.client_id().clone();
+                Box::pin(async move { Ok(Balance::new(client_id, Decimal::from(110))) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = ReverseTransactionRequest::new(
+            TransactionId::new("5").unwrap(),
+            Some(Decimal::from(10)),
+        )
+        .unwrap();
+
+        // WHEN
+        let balance = client_balance_service
+            .reverse_transaction(&req)
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(balance.balance(), &Decimal::from(110));
+    }
+
+    #[tokio::test]
+    async fn test_35_given_a_transfer_when_reversing_it_then_should_return_not_reversible() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_get_transaction()
+            .returning(|_| {
+                Box::pin(async { Ok(transaction_of(TransactionKind::TransferOut, -30)) })
+            });
+        client_balance_repository.expect_credit_balance().never();
+        client_balance_repository.expect_debit_balance().never();
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let transaction_id = TransactionId::new("5").unwrap();
+        let req = ReverseTransactionRequest::new(transaction_id.clone(), None).unwrap();
+
+        // WHEN
+        let result = client_balance_service.reverse_transaction(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::TransactionNotReversible { transaction_id }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_36_given_a_missing_transaction_when_reversing_it_then_should_return_not_found() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_get_transaction()
+            .returning(|transaction_id| {
+                let transaction_id = transaction_id.clone();
+                Box::pin(async move { Err(ClientError::TransactionNotFound { transaction_id }) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let transaction_id = TransactionId::new("99").unwrap();
+        let req = ReverseTransactionRequest::new(transaction_id.clone(), None).unwrap();
+
+        // WHEN
+        let result = client_balance_service.reverse_transaction(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::TransactionNotFound { transaction_id }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_37_given_an_already_reversed_transaction_when_reversing_it_again_then_should_return_already_reversed()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_get_transaction()
+            .returning(|_| Box::pin(async { Ok(transaction_of(TransactionKind::Debit, -30)) }));
+        client_balance_repository
+            .expect_credit_balance()
+            .returning(|req| {
+                let transaction_id = req.reversal_of().unwrap().clone();
+                Box::pin(
+                    async move { Err(ClientError::TransactionAlreadyReversed { transaction_id }) },
+                )
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let transaction_id = TransactionId::new("5").unwrap();
+        let req = ReverseTransactionRequest::new(transaction_id.clone(), None).unwrap();
+
+        // WHEN
+        let result = client_balance_service.reverse_transaction(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::TransactionAlreadyReversed { transaction_id }
+        );
+    }
+
+    fn authorization_of(client_id: &ClientId, amount: &Decimal) -> Authorization {
+        let now = chrono::Utc::now();
+        Authorization::new(
+            AuthorizationId::new("1").unwrap(),
+            client_id.clone(),
+            *amount,
+            now,
+            now + chrono::Duration::minutes(10),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_38_given_an_existing_client_when_authorizing_then_should_return_pending_authorization()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_authorize()
+            .times(1)
+            .returning(|req| {
+                let authorization = authorization_of(req.client_id(), req.amount());
+                Box::pin(async move { Ok(authorization) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+        let req = AuthorizeRequest::new(client_id.clone(), Decimal::from(40)).unwrap();
+
+        // WHEN
+        let authorization = client_balance_service.authorize(&req).await.unwrap();
+
+        // THEN
+        assert_eq!(authorization.client_id(), &client_id);
+        assert_eq!(authorization.amount(), &Decimal::from(40));
+        assert_eq!(authorization.status(), &AuthorizationStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_39_given_a_missing_client_when_authorizing_then_should_return_not_found() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(false) }));
+        client_balance_repository.expect_authorize().never();
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("9").unwrap();
+        let req = AuthorizeRequest::new(client_id.clone(), Decimal::from(40)).unwrap();
+
+        // WHEN
+        let result = client_balance_service.authorize(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::NotFoundById {
+                id_document: client_id
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_40_given_a_pending_authorization_when_capturing_then_should_return_the_balance() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_capture_authorization()
+            .withf(|req| req.amount() == Some(&Decimal::from(25)))
+            .times(1)
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(Balance::new(ClientId::new("1").unwrap(), Decimal::from(75))
+                        .with_held(Decimal::from(10)))
+                })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = CaptureAuthorizationRequest::new(
+            AuthorizationId::new("1").unwrap(),
+            Some(Decimal::from(25)),
+        )
+        .unwrap();
+
+        // WHEN
+        let balance = client_balance_service
+            .capture_authorization(&req)
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(balance.balance(), &Decimal::from(75));
+        assert_eq!(balance.available().unwrap(), Decimal::from(65));
+    }
+
+    #[tokio::test]
+    async fn test_41_given_an_expired_authorization_when_voiding_then_should_return_expired() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_void_authorization()
+            .returning(|authorization_id, _| {
+                let authorization_id = authorization_id.clone();
+                Box::pin(async move { Err(ClientError::AuthorizationExpired { authorization_id }) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let authorization_id = AuthorizationId::new("1").unwrap();
+
+        // WHEN
+        let result = client_balance_service
+            .void_authorization(&authorization_id, None)
+            .await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::AuthorizationExpired { authorization_id }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_42_given_a_client_with_several_currencies_when_getting_balances_then_should_return_all()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_get_balances_by_client_id()
+            .returning(|req| {
+                let client_id = req.client_id().clone();
+                Box::pin(async move {
+                    Ok(vec![
+                        Balance::new(client_id.clone(), Decimal::from(100)),
+                        Balance::new(client_id, Decimal::from(5))
+                            .with_currency(Currency::new("USD").unwrap()),
+                    ])
+                })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = GetClientRequest::new(ClientId::new("1").unwrap());
+
+        // WHEN
+        let balances = client_balance_service
+            .get_balances_by_client_id(&req)
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].currency(), &Currency::default());
+        assert_eq!(balances[1].currency(), &Currency::new("USD").unwrap());
+        assert_eq!(balances[1].balance(), &Decimal::from(5));
+    }
+
+    #[tokio::test]
+    async fn test_43_given_a_credit_in_another_currency_when_reversing_it_then_should_debit_the_same_currency()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_get_transaction()
+            .returning(|_| {
+                let transaction = transaction_of(TransactionKind::Credit, 50)
+                    .with_currency(Currency::new("USD").unwrap());
+                Box::pin(async move { Ok(transaction) })
+            });
+        client_balance_repository
+            .expect_debit_balance()
+            .withf(|req| req.currency() == &Currency::new("USD").unwrap())
+            .times(1)
+            .returning(|req| {
+                let balance = Balance::new(req.client_id().clone(), Decimal::ZERO)
+                    .with_currency(req.currency().clone());
+                Box::pin(async move { Ok(balance) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = ReverseTransactionRequest::new(TransactionId::new("5").unwrap(), None).unwrap();
+
+        // WHEN
+        let balance = client_balance_service
+            .reverse_transaction(&req)
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(balance.currency(), &Currency::new("USD").unwrap());
+    }
+
+    fn usd_to_ars_provider() -> MockExchangeRateProvider {
+        let mut exchange_rate_provider = MockExchangeRateProvider::default();
+        exchange_rate_provider
+            .expect_get_exchange_rate()
+            .withf(|from, to| from.to_string() == "USD" && to.to_string() == "ARS")
+            .times(1)
+            .returning(|from, to| {
+                let exchange_rate = ExchangeRate::new(
+                    from.clone(),
+                    to.clone(),
+                    Decimal::from(1000),
+                    Decimal::new(2, 2),
+                );
+                Box::pin(async move { exchange_rate })
+            });
+        exchange_rate_provider
+    }
+
+    #[tokio::test]
+    async fn test_44_given_a_debit_in_another_currency_when_debiting_then_should_apply_the_converted_amount()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_debit_balance()
+            .withf(|req| {
+                req.applied_amount() == &Decimal::from(-10200)
+                    && req.applied_currency() == &Currency::default()
+            })
+            .times(1)
+            .returning(|req| {
+                let balance = Balance::new(req.client_id().clone(), *req.applied_amount())
+                    .with_conversion(req.conversion().unwrap().clone());
+                Box::pin(async move { Ok(balance) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            usd_to_ars_provider(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = DebitTransactionRequest::new(ClientId::new("1").unwrap(), Decimal::from(-10))
+            .unwrap()
+            .with_currency(Currency::new("USD").unwrap())
+            .with_balance_currency(Currency::default());
+
+        // WHEN
+        let balance = client_balance_service.debit_balance(&req).await.unwrap();
+
+        // THEN
+        let conversion = balance.conversion().unwrap();
+        assert_eq!(conversion.applied_rate(), &Decimal::from(1020));
+        assert_eq!(conversion.converted_amount(), &Decimal::from(-10200));
+        assert_eq!(balance.balance(), &Decimal::from(-10200));
+    }
+
+    #[tokio::test]
+    async fn test_45_given_no_exchange_rate_when_crediting_in_another_currency_then_should_fail_without_crediting()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository.expect_credit_balance().never();
+        let mut exchange_rate_provider = MockExchangeRateProvider::default();
+        exchange_rate_provider
+            .expect_get_exchange_rate()
+            .returning(|from, to| {
+                let err = ClientError::ExchangeRateNotFound {
+                    from: from.clone(),
+                    to: to.clone(),
+                };
+                Box::pin(async move { Err(err) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            exchange_rate_provider,
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = CreditTransactionRequest::new(ClientId::new("1").unwrap(), Decimal::from(10))
+            .unwrap()
+            .with_currency(Currency::new("EUR").unwrap())
+            .with_balance_currency(Currency::default());
+
+        // WHEN
+        let result = client_balance_service.credit_balance(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::ExchangeRateNotFound {
+                from: Currency::new("EUR").unwrap(),
+                to: Currency::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_46_given_the_balance_currency_equal_to_the_amount_currency_when_crediting_then_should_not_convert()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_credit_balance()
+            .withf(|req| req.conversion().is_none())
+            .times(1)
+            .returning(|req| {
+                let balance = Balance::new(req.client_id().clone(), *req.amount());
+                Box::pin(async move { Ok(balance) })
+            });
+        let mut exchange_rate_provider = MockExchangeRateProvider::default();
+        exchange_rate_provider.expect_get_exchange_rate().never();
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            exchange_rate_provider,
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = CreditTransactionRequest::new(ClientId::new("1").unwrap(), Decimal::from(10))
+            .unwrap()
+            .with_balance_currency(Currency::default());
+
+        // WHEN
+        let balance = client_balance_service.credit_balance(&req).await.unwrap();
+
+        // THEN
+        assert_eq!(balance.conversion(), None);
+        assert_eq!(balance.balance(), &Decimal::from(10));
+    }
+
+    #[tokio::test]
+    async fn test_47_given_a_batch_when_applying_it_then_should_return_the_balance_left_by_each_item()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_apply_batch()
+            .times(1)
+            .returning(|req| {
+                let mut balance = Decimal::ZERO;
+                let balances = req
+                    .items()
+                    .iter()
+                    .map(|item| {
+                        balance += item.amount();
+                        Balance::new(item.client_id().clone(), balance)
+                    })
+                    .collect();
+                Box::pin(async move { Ok(balances) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+        let req = BatchTransactionRequest::new(vec![
+            CreditTransactionRequest::new(client_id.clone(), Decimal::from(100))
+                .unwrap()
+                .into(),
+            DebitTransactionRequest::new(client_id, Decimal::from(-40))
+                .unwrap()
+                .into(),
+        ])
+        .unwrap();
+
+        // WHEN
+        let balances = client_balance_service.apply_batch(&req).await.unwrap();
+
+        // THEN
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].balance(), &Decimal::from(100));
+        assert_eq!(balances[1].balance(), &Decimal::from(60));
+    }
+
+    #[tokio::test]
+    async fn test_48_given_a_batch_with_an_item_without_funds_when_applying_it_then_should_return_the_failing_index()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_apply_batch()
+            .returning(|req| {
+                let err = ClientError::BatchItemRejected {
+                    index: 1,
+                    reason: Box::new(ClientError::InsufficientFunds {
+                        client_id: req.items()[1].client_id().clone(),
+                    }),
+                };
+                Box::pin(async move { Err(err) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+        let req = BatchTransactionRequest::new(vec![
+            CreditTransactionRequest::new(client_id.clone(), Decimal::from(10))
+                .unwrap()
+                .into(),
+            DebitTransactionRequest::new(client_id.clone(), Decimal::from(-40))
+                .unwrap()
+                .into(),
+        ])
+        .unwrap();
+
+        // WHEN
+        let result = client_balance_service.apply_batch(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::BatchItemRejected {
+                index: 1,
+                reason: Box::new(ClientError::InsufficientFunds { client_id })
+            }
+        );
+    }
+
+    fn client_with_status(client_id: &ClientId, status: ClientStatus) -> Client {
+        let mut client = Client::new(
+            client_id.clone(),
+            ClientName::new("John Doe").unwrap(),
+            BirthDate::new("1990-01-01").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
+            Country::new("Argentina").unwrap(),
+        );
+        if status != ClientStatus::Active {
+            client.change_status(status).unwrap();
+        }
+        client
+    }
+
+    #[tokio::test]
+    async fn test_49_given_an_active_client_when_blocking_and_unblocking_it_then_should_change_its_status()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_change_client_status()
+            .withf(|_, status, _| *status == ClientStatus::Blocked)
+            .times(1)
+            .returning(|client_id, status, _| {
+                let client = client_with_status(client_id, status);
+                Box::pin(async move { Ok(client) })
+            });
+        client_balance_repository
+            .expect_change_client_status()
+            .withf(|_, status, _| *status == ClientStatus::Active)
+            .times(1)
+            .returning(|client_id, status, _| {
+                let client = client_with_status(client_id, status);
+                Box::pin(async move { Ok(client) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+
+        // WHEN
+        let blocked = client_balance_service
+            .block_client(&client_id, None)
+            .await
+            .unwrap();
+        let unblocked = client_balance_service
+            .unblock_client(&client_id, None)
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(blocked.status(), ClientStatus::Blocked);
+        assert_eq!(unblocked.status(), ClientStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn test_50_given_a_client_that_does_not_exist_when_blocking_it_then_should_fail_without_changing_any_status()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(false) }));
+        client_balance_repository
+            .expect_change_client_status()
+            .never();
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+
+        // WHEN
+        let result = client_balance_service.block_client(&client_id, None).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::NotFoundById {
+                id_document: client_id
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_51_given_a_client_with_balance_when_closing_it_then_should_fail() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_change_client_status()
+            .withf(|_, status, _| *status == ClientStatus::Closed)
+            .times(1)
+            .returning(|client_id, _, _| {
+                let err = ClientError::BalanceNotZero {
+                    client_id: client_id.clone(),
+                };
+                Box::pin(async move { Err(err) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+
+        // WHEN
+        let result = client_balance_service.close_client(&client_id, None).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::BalanceNotZero { client_id }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_52_given_a_blocked_client_when_debiting_then_should_fail_with_client_blocked() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_debit_balance()
+            .returning(|req| {
+                let err = ClientError::ClientBlocked {
+                    client_id: req.client_id().clone(),
+                };
+                Box::pin(async move { Err(err) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+        let req = DebitTransactionRequest::new(client_id.clone(), Decimal::from(-10)).unwrap();
+
+        // WHEN
+        let result = client_balance_service.debit_balance(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::ClientBlocked { client_id }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_53_given_a_document_of_another_client_when_updating_then_should_fail_without_updating()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_get_client_by_document()
+            .returning(|_| {
+                let client = client_with_status(&ClientId::new("2").unwrap(), ClientStatus::Active);
+                Box::pin(async move { Ok(client) })
+            });
+        client_balance_repository.expect_update_client().never();
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = UpdateClientRequest::new(ClientId::new("1").unwrap())
+            .with_document(Document::new(DocumentType::Dni, "12345678").unwrap());
+
+        // WHEN
+        let result = client_balance_service.update_client(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::Duplicate {
+                document: "dni 12345678".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_54_given_the_current_document_of_the_client_when_updating_then_should_be_ok() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_get_client_by_document()
+            .returning(|_| {
+                let client = client_with_status(&ClientId::new("1").unwrap(), ClientStatus::Active);
+                Box::pin(async move { Ok(client) })
+            });
+        client_balance_repository
+            .expect_update_client()
+            .times(1)
+            .returning(|req| {
+                let mut client = client_with_status(req.client_id(), ClientStatus::Active);
+                client.update(req, chrono::Utc::now());
+                Box::pin(async move { Ok(client) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = UpdateClientRequest::new(ClientId::new("1").unwrap())
+            .with_name(ClientName::new("Jane Doe").unwrap())
+            .with_document(Document::new(DocumentType::Dni, "12345678").unwrap());
+
+        // WHEN
+        let client = client_balance_service.update_client(&req).await.unwrap();
+
+        // THEN
+        assert_eq!(client.name(), &ClientName::new("Jane Doe").unwrap());
+        assert_eq!(
+            client.document(),
+            &Document::new(DocumentType::Dni, "12345678").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_55_given_a_client_that_does_not_exist_when_updating_then_should_fail_without_updating()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(false) }));
+        client_balance_repository.expect_update_client().never();
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+        let req = UpdateClientRequest::new(client_id.clone())
+            .with_country(Country::new("Uruguay").unwrap());
+
+        // WHEN
+        let result = client_balance_service.update_client(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::NotFoundById {
+                id_document: client_id
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_56_given_a_list_request_when_listing_clients_then_should_return_the_page_of_the_repository()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_list_clients()
+            .withf(|req| req.sort() == ClientSort::Balance && req.limit() == 1)
+            .times(1)
+            .returning(|_| {
+                let client_id = ClientId::new("3").unwrap();
+                let client = client_with_status(&client_id, ClientStatus::Active);
+                let balance = Balance::new(client_id.clone(), Decimal::from(10));
+                let next_cursor = ClientCursor::after_balance(Decimal::from(10), client_id);
+                let page = ClientPage::new(vec![(client, balance)], Some(next_cursor));
+                Box::pin(async move { Ok(page) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = ListClientsRequest::new(ClientSort::Balance, SortDirection::Descending, None, 1)
+            .unwrap();
+
+        // WHEN
+        let page = client_balance_service.list_clients(&req).await.unwrap();
+
+        // THEN
+        assert_eq!(page.clients().len(), 1);
+        assert_eq!(
+            page.next_cursor().map(ToString::to_string),
+            Some("10:3".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_57_given_the_document_of_a_client_when_getting_it_by_document_then_should_return_it()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_get_client_by_document()
+            .withf(|document| document == &Document::new(DocumentType::Dni, "12345678").unwrap())
+            .times(1)
+            .returning(|_| {
+                let client = client_with_status(&ClientId::new("4").unwrap(), ClientStatus::Active);
+                Box::pin(async move { Ok(client) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let document = Document::new(DocumentType::Dni, "12345678").unwrap();
+
+        // WHEN
+        let client = client_balance_service
+            .get_client_by_document(&document)
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(client.id(), &ClientId::new("4").unwrap());
+        assert_eq!(client.document(), &document);
+    }
+
+    #[tokio::test]
+    async fn test_58_given_an_unknown_document_when_getting_a_client_by_document_then_should_fail()
+    {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_get_client_by_document()
+            .returning(|document| {
+                let err = ClientError::NotFoundByDocument {
+                    document: document.clone(),
+                };
+                Box::pin(async move { Err(err) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let document = Document::new(DocumentType::Dni, "99999999").unwrap();
+
+        // WHEN
+        let result = client_balance_service
+            .get_client_by_document(&document)
+            .await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::NotFoundByDocument { document }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_59_given_a_birth_date_in_the_future_when_creating_a_client_then_should_fail_without_creating()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository.expect_create_client().never();
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let birth_date = BirthDate::new("2026-06-16").unwrap();
+        let req = CreateClientRequest::new(
+            ClientName::new("John Doe").unwrap(),
+            birth_date.clone(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
+            Country::new("AR").unwrap(),
+        );
+
+        // WHEN
+        let result = client_balance_service.create_client(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::BirthDateInFuture { birth_date }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_60_given_a_country_with_a_higher_min_age_when_moving_a_client_to_it_then_should_fail_without_updating()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_get_client()
+            .returning(|req| {
+                let client = Client::new(
+                    req.client_id().clone(),
+                    ClientName::new("John Doe").unwrap(),
+                    BirthDate::new("2006-01-01").unwrap(),
+                    Document::new(DocumentType::Dni, "12345678").unwrap(),
+                    Country::new("AR").unwrap(),
+                );
+                Box::pin(async move { Ok(client) })
+            });
+        client_balance_repository.expect_update_client().never();
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        )
+        .with_birth_date_rules(
+            BirthDateRules::default().with_min_age(Country::new("US").unwrap(), 21),
+        );
+
+        // GIVEN
+        let req = UpdateClientRequest::new(ClientId::new("1").unwrap())
+            .with_country(Country::new("US").unwrap());
+
+        // WHEN
+        let result = client_balance_service.update_client(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::UnderMinimumAge {
+                country: Country::new("US").unwrap(),
+                min_age: 21
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_61_given_contact_details_when_creating_a_client_then_they_should_be_kept() {
+        // SETUP
+        let (client_balance_repository, balance_exporter) = setup_general_mocks(None, None);
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            balance_exporter,
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let email = Email::new("john@example.com").unwrap();
+        let phone = PhoneNumber::new("+54 9 11 2345-6789").unwrap();
+        let req = CreateClientRequest::new(
+            ClientName::new("John Doe").unwrap(),
+            BirthDate::new("1990-01-01").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
+            Country::new("AR").unwrap(),
+        )
+        .with_email(email.clone())
+        .with_phone(phone.clone());
+
+        // WHEN
+        let client = client_balance_service.create_client(&req).await.unwrap();
+
+        // THEN
+        assert_eq!(client.email(), Some(&email));
+        assert_eq!(client.phone(), Some(&phone));
+    }
+
+    #[tokio::test]
+    async fn test_62_given_a_client_when_creating_an_account_then_it_should_return_the_account() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_create_account()
+            .times(1)
+            .returning(|req| {
+                let account = Account::new(
+                    AccountId::new("3").unwrap(),
+                    req.client_id().clone(),
+                    req.name().clone(),
+                );
+                Box::pin(async move { Ok(account) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = CreateAccountRequest::new(
+            ClientId::new("1").unwrap(),
+            AccountName::new("savings").unwrap(),
+        );
+
+        // WHEN
+        let account = client_balance_service.create_account(&req).await.unwrap();
+
+        // THEN
+        assert_eq!(account.id(), &AccountId::new("3").unwrap());
+        assert_eq!(account.client_id(), &ClientId::new("1").unwrap());
+        assert_eq!(account.name().to_string(), "savings");
+        assert!(!account.is_main());
+    }
+
+    #[tokio::test]
+    async fn test_63_given_nonexistent_client_when_creating_an_account_then_should_return_not_found()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(false) }));
+        client_balance_repository.expect_create_account().never();
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = CreateAccountRequest::new(
+            ClientId::new("9").unwrap(),
+            AccountName::new("savings").unwrap(),
+        );
+
+        // WHEN
+        let result = client_balance_service.create_account(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::NotFoundById {
+                id_document: ClientId::new("9").unwrap()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_64_given_a_debit_of_another_account_when_reversing_it_then_should_credit_the_same_account()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_get_transaction()
+            .returning(|_| {
+                let transaction = transaction_of(TransactionKind::Debit, -30)
+                    .with_account_id(AccountId::new("2").unwrap());
+                Box::pin(async move { Ok(transaction) })
+            });
+        client_balance_repository
+            .expect_credit_balance()
+            .withf(|req| {
+                req.amount() == &Decimal::from(30)
+                    && req.account_id() == Some(&AccountId::new("2").unwrap())
+            })
+            .times(1)
+            .returning(|req| {
+                let balance = Balance::new(req.client_id().clone(), Decimal::from(30))
+                    .with_account_id(req.account_id().unwrap().clone());
+                Box::pin(async move { Ok(balance) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = ReverseTransactionRequest::new(TransactionId::new("5").unwrap(), None).unwrap();
+
+        // WHEN
+        let balance = client_balance_service
+            .reverse_transaction(&req)
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(balance.account_id(), Some(&AccountId::new("2").unwrap()));
+        assert_eq!(balance.balance(), &Decimal::from(30));
+    }
+
+    #[tokio::test]
+    async fn test_65_given_nonexistent_client_when_getting_its_accounts_then_should_return_not_found()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(false) }));
+        client_balance_repository
+            .expect_get_accounts_by_client_id()
+            .never();
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = GetClientRequest::new(ClientId::new("9").unwrap());
+
+        // WHEN
+        let result = client_balance_service.get_accounts_by_client_id(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::NotFoundById {
+                id_document: ClientId::new("9").unwrap()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_66_given_a_stale_version_when_crediting_then_should_return_version_conflict() {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_credit_balance()
+            .withf(|req| req.expected_version() == Some(&Version::new("3").unwrap()))
+            .times(1)
+            .returning(|req| {
+                let err = ClientError::VersionConflict {
+                    client_id: req.client_id().clone(),
+                    expected: Version::new("3").unwrap(),
+                    actual: Version::new("4").unwrap(),
+                };
+                Box::pin(async move { Err(err) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let req = CreditTransactionRequest::new(ClientId::new("1").unwrap(), Decimal::from(10))
+            .unwrap()
+            .with_expected_version(Version::new("3").unwrap());
+
+        // WHEN
+        let result = client_balance_service.credit_balance(&req).await;
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::VersionConflict {
+                client_id: ClientId::new("1").unwrap(),
+                expected: Version::new("3").unwrap(),
+                actual: Version::new("4").unwrap()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_67_given_an_expected_version_when_closing_a_client_then_should_pass_it_to_the_repository()
+     {
+        // SETUP
+        let mut client_balance_repository = MockClientBalanceRepository::default();
+        client_balance_repository
+            .expect_client_id_exists()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        client_balance_repository
+            .expect_change_client_status()
+            .withf(|_, status, expected_version| {
+                *status == ClientStatus::Closed
+                    && *expected_version == Some(Version::new("2").unwrap())
+            })
+            .times(1)
+            .returning(|client_id, status, _| {
+                let client = client_with_status(client_id, status);
+                Box::pin(async move { Ok(client) })
+            });
+        let client_balance_service = Service::new(
+            client_balance_repository,
+            MockBalanceExporter::default(),
+            MockExchangeRateProvider::default(),
+            fixed_clock(),
+        );
+
+        // GIVEN
+        let client_id = ClientId::new("1").unwrap();
+
+        // WHEN
+        let closed = client_balance_service
+            .close_client(&client_id, Some(Version::new("2").unwrap()))
+            .await
+            .unwrap();
+
+        // THEN
+        assert_eq!(closed.status(), ClientStatus::Closed);
+    }
 }
@@ -0,0 +1,99 @@
+use derive_more::From;
+use rust_decimal::Decimal;
+
+use crate::domain::model::{
+    error::ClientError,
+    value::{client_id::ClientId, currency::Currency, version::Version},
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// The fields required by the domain to reserve funds of a [Client] without moving them.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+pub struct AuthorizeRequest {
+    client_id: ClientId,
+    /// The amount to hold from the [Client] balance. Always positive.
+    amount: Decimal,
+    /// The currency of the balance to be held.
+    currency: Currency,
+    /// The [Version] of the [Client] the caller last read, to reject the request if it changed since.
+    expected_version: Option<Version>,
+}
+
+impl AuthorizeRequest {
+    pub fn new(client_id: ClientId, amount: Decimal) -> Result<Self, ClientError> {
+        if amount < Decimal::ZERO {
+            return Err(ClientError::NegativeAmount);
+        }
+
+        if amount == Decimal::ZERO {
+            return Err(ClientError::ZeroAmount);
+        }
+
+        Ok(Self {
+            client_id,
+            amount,
+            currency: Currency::default(),
+            expected_version: None,
+        })
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn amount(&self) -> &Decimal {
+        &self.amount
+    }
+
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn with_expected_version(mut self, expected_version: Version) -> Self {
+        self.expected_version = Some(expected_version);
+        self
+    }
+
+    pub fn expected_version(&self) -> Option<&Version> {
+        self.expected_version.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_positive_amount_when_creating_authorize_request_then_should_be_ok() {
+        let client_id = ClientId::new("1").unwrap();
+        let req = AuthorizeRequest::new(client_id.clone(), Decimal::from(100)).unwrap();
+        assert_eq!(req.client_id(), &client_id);
+        assert_eq!(req.amount(), &Decimal::from(100));
+    }
+
+    #[test]
+    fn test_02_given_negative_or_zero_amount_when_creating_authorize_request_then_should_fail() {
+        let client_id = ClientId::new("1").unwrap();
+        let req = AuthorizeRequest::new(client_id.clone(), Decimal::from(-100));
+        assert_eq!(req.err().unwrap(), ClientError::NegativeAmount);
+        let req = AuthorizeRequest::new(client_id, Decimal::ZERO);
+        assert_eq!(req.err().unwrap(), ClientError::ZeroAmount);
+    }
+
+    #[test]
+    fn test_03_given_an_expected_version_when_creating_authorize_request_then_it_should_be_accessible()
+     {
+        let req = AuthorizeRequest::new(ClientId::new("1").unwrap(), Decimal::from(100)).unwrap();
+        assert_eq!(req.expected_version(), None);
+        let version = Version::new("3").unwrap();
+        let req = req.with_expected_version(version);
+        assert_eq!(req.expected_version(), Some(&version));
+    }
+}
@@ -0,0 +1,100 @@
+use derive_more::From;
+use rust_decimal::Decimal;
+
+use crate::domain::model::{
+    dto::{
+        credit_transaction::CreditTransactionRequest, debit_transaction::DebitTransactionRequest,
+    },
+    error::ClientError,
+    value::{client_id::ClientId, currency::Currency, transaction_kind::TransactionKind},
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// A credit or a debit inside a [BatchTransactionRequest].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+pub enum BatchTransactionItem {
+    Credit(CreditTransactionRequest),
+    Debit(DebitTransactionRequest),
+}
+
+impl BatchTransactionItem {
+    pub fn client_id(&self) -> &ClientId {
+        match self {
+            BatchTransactionItem::Credit(req) => req.client_id(),
+            BatchTransactionItem::Debit(req) => req.client_id(),
+        }
+    }
+
+    /// The signed amount: positive for credits and negative for debits.
+    pub fn amount(&self) -> &Decimal {
+        match self {
+            BatchTransactionItem::Credit(req) => req.amount(),
+            BatchTransactionItem::Debit(req) => req.amount(),
+        }
+    }
+
+    pub fn currency(&self) -> &Currency {
+        match self {
+            BatchTransactionItem::Credit(req) => req.currency(),
+            BatchTransactionItem::Debit(req) => req.currency(),
+        }
+    }
+
+    pub fn kind(&self) -> TransactionKind {
+        match self {
+            BatchTransactionItem::Credit(_) => TransactionKind::Credit,
+            BatchTransactionItem::Debit(_) => TransactionKind::Debit,
+        }
+    }
+}
+
+/// The fields required by the domain to apply several credits and debits at once: either all of
+/// them are applied, in order, or none is. Only the [Client], amount and currency of each item are
+/// applied; idempotency keys, reversals and conversions are not supported inside a batch.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BatchTransactionRequest {
+    items: Vec<BatchTransactionItem>,
+}
+
+impl BatchTransactionRequest {
+    pub fn new(items: Vec<BatchTransactionItem>) -> Result<Self, ClientError> {
+        if items.is_empty() {
+            return Err(ClientError::BatchEmpty);
+        }
+
+        Ok(Self { items })
+    }
+
+    pub fn items(&self) -> &[BatchTransactionItem] {
+        &self.items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_no_items_when_creating_batch_then_should_fail() {
+        let req = BatchTransactionRequest::new(vec![]);
+        assert_eq!(req.err().unwrap(), ClientError::BatchEmpty);
+    }
+
+    #[test]
+    fn test_02_given_credits_and_debits_when_creating_batch_then_items_should_keep_their_order() {
+        let client_id = ClientId::new("1").unwrap();
+        let credit = CreditTransactionRequest::new(client_id.clone(), Decimal::from(100)).unwrap();
+        let debit = DebitTransactionRequest::new(client_id.clone(), Decimal::from(-30)).unwrap();
+        let req = BatchTransactionRequest::new(vec![credit.into(), debit.into()]).unwrap();
+        let items = req.items();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].kind(), TransactionKind::Credit);
+        assert_eq!(items[0].amount(), &Decimal::from(100));
+        assert_eq!(items[1].kind(), TransactionKind::Debit);
+        assert_eq!(items[1].amount(), &Decimal::from(-30));
+        assert_eq!(items[1].client_id(), &client_id);
+        assert_eq!(items[1].currency(), &Currency::default());
+    }
+}
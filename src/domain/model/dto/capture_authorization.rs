@@ -0,0 +1,97 @@
+use derive_more::From;
+use rust_decimal::Decimal;
+
+use crate::domain::model::{
+    error::ClientError,
+    value::{authorization_id::AuthorizationId, version::Version},
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::{authorization::Authorization, client::Client};
+
+/// The fields required by the domain to turn an [Authorization] into a real debit.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+pub struct CaptureAuthorizationRequest {
+    authorization_id: AuthorizationId,
+    /// The amount to debit. Always positive. The whole reserved amount if not set.
+    amount: Option<Decimal>,
+    /// The [Version] of the [Client] the caller last read, to reject the request if it changed since.
+    expected_version: Option<Version>,
+}
+
+impl CaptureAuthorizationRequest {
+    pub fn new(
+        authorization_id: AuthorizationId,
+        amount: Option<Decimal>,
+    ) -> Result<Self, ClientError> {
+        if let Some(amount) = amount {
+            if amount < Decimal::ZERO {
+                return Err(ClientError::NegativeAmount);
+            }
+
+            if amount == Decimal::ZERO {
+                return Err(ClientError::ZeroAmount);
+            }
+        }
+
+        Ok(Self {
+            authorization_id,
+            amount,
+            expected_version: None,
+        })
+    }
+
+    pub fn with_expected_version(mut self, expected_version: Version) -> Self {
+        self.expected_version = Some(expected_version);
+        self
+    }
+
+    pub fn authorization_id(&self) -> &AuthorizationId {
+        &self.authorization_id
+    }
+
+    pub fn amount(&self) -> Option<&Decimal> {
+        self.amount.as_ref()
+    }
+
+    pub fn expected_version(&self) -> Option<&Version> {
+        self.expected_version.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_an_optional_amount_when_creating_capture_request_then_should_be_ok() {
+        let authorization_id = AuthorizationId::new("1").unwrap();
+        let req = CaptureAuthorizationRequest::new(authorization_id.clone(), None).unwrap();
+        assert_eq!(req.authorization_id(), &authorization_id);
+        assert_eq!(req.amount(), None);
+        let req =
+            CaptureAuthorizationRequest::new(authorization_id, Some(Decimal::from(5))).unwrap();
+        assert_eq!(req.amount(), Some(&Decimal::from(5)));
+    }
+
+    #[test]
+    fn test_02_given_negative_or_zero_amount_when_creating_capture_request_then_should_fail() {
+        let authorization_id = AuthorizationId::new("1").unwrap();
+        let req =
+            CaptureAuthorizationRequest::new(authorization_id.clone(), Some(Decimal::from(-5)));
+        assert_eq!(req.err().unwrap(), ClientError::NegativeAmount);
+        let req = CaptureAuthorizationRequest::new(authorization_id, Some(Decimal::ZERO));
+        assert_eq!(req.err().unwrap(), ClientError::ZeroAmount);
+    }
+
+    #[test]
+    fn test_03_given_an_expected_version_when_creating_capture_request_then_it_should_be_accessible()
+     {
+        let req =
+            CaptureAuthorizationRequest::new(AuthorizationId::new("1").unwrap(), None).unwrap();
+        assert_eq!(req.expected_version(), None);
+        let version = Version::new("3").unwrap();
+        let req = req.with_expected_version(version);
+        assert_eq!(req.expected_version(), Some(&version));
+    }
+}
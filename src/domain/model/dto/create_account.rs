@@ -0,0 +1,44 @@
+use derive_more::From;
+
+use crate::domain::model::value::{
+    account_name::AccountName, client_id::ClientId, version::Version,
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::{account::Account, client::Client};
+
+/// The fields required by the domain to open a new [Account] for a [Client].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+pub struct CreateAccountRequest {
+    client_id: ClientId,
+    name: AccountName,
+    /// The [Version] of the [Client] the caller last read, to reject the request if it changed since.
+    expected_version: Option<Version>,
+}
+
+impl CreateAccountRequest {
+    pub fn new(client_id: ClientId, name: AccountName) -> Self {
+        Self {
+            client_id,
+            name,
+            expected_version: None,
+        }
+    }
+
+    pub fn with_expected_version(mut self, expected_version: Version) -> Self {
+        self.expected_version = Some(expected_version);
+        self
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn name(&self) -> &AccountName {
+        &self.name
+    }
+
+    pub fn expected_version(&self) -> Option<&Version> {
+        self.expected_version.as_ref()
+    }
+}
@@ -2,6 +2,7 @@ use derive_more::From;
 
 use crate::domain::model::value::{
     birth_date::BirthDate, client_name::ClientName, country::Country, document::Document,
+    email::Email, overdraft_limit::OverdraftLimit, phone_number::PhoneNumber,
 };
 
 #[allow(unused_imports)]
@@ -14,6 +15,9 @@ pub struct CreateClientRequest {
     birth_date: BirthDate,
     document: Document,
     country: Country,
+    overdraft_limit: OverdraftLimit,
+    email: Option<Email>,
+    phone: Option<PhoneNumber>,
 }
 
 impl CreateClientRequest {
@@ -28,9 +32,27 @@ impl CreateClientRequest {
             birth_date,
             document,
             country,
+            overdraft_limit: OverdraftLimit::default(),
+            email: None,
+            phone: None,
         }
     }
 
+    pub fn with_overdraft_limit(mut self, overdraft_limit: OverdraftLimit) -> Self {
+        self.overdraft_limit = overdraft_limit;
+        self
+    }
+
+    pub fn with_email(mut self, email: Email) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    pub fn with_phone(mut self, phone: PhoneNumber) -> Self {
+        self.phone = Some(phone);
+        self
+    }
+
     pub fn name(&self) -> &ClientName {
         &self.name
     }
@@ -46,11 +68,24 @@ impl CreateClientRequest {
     pub fn country(&self) -> &Country {
         &self.country
     }
+
+    pub fn overdraft_limit(&self) -> &OverdraftLimit {
+        &self.overdraft_limit
+    }
+
+    pub fn email(&self) -> Option<&Email> {
+        self.email.as_ref()
+    }
+
+    pub fn phone(&self) -> Option<&PhoneNumber> {
+        self.phone.as_ref()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::model::value::document_type::DocumentType;
     use crate::domain::model::value::{
         birth_date::BirthDate, client_name::ClientName, country::Country, document::Document,
     };
@@ -60,7 +95,7 @@ mod tests {
      {
         let name = ClientName::new("John Doe").unwrap();
         let birth_date = BirthDate::new("1990-01-01").unwrap();
-        let document = Document::new("1234567890").unwrap();
+        let document = Document::new(DocumentType::Dni, "12345678").unwrap();
         let country = Country::new("Argentina").unwrap();
         let req = CreateClientRequest::new(
             name.clone(),
@@ -72,5 +107,22 @@ mod tests {
         assert_eq!(req.birth_date(), &birth_date);
         assert_eq!(req.document(), &document);
         assert_eq!(req.country(), &country);
+        assert_eq!(req.overdraft_limit(), &OverdraftLimit::default());
+        assert_eq!(req.email(), None);
+        assert_eq!(req.phone(), None);
+    }
+
+    #[test]
+    fn test_02_given_an_overdraft_limit_when_creating_create_client_request_then_it_should_be_accessible()
+     {
+        let limit = OverdraftLimit::new(rust_decimal::Decimal::from(500)).unwrap();
+        let req = CreateClientRequest::new(
+            ClientName::new("John Doe").unwrap(),
+            BirthDate::new("1990-01-01").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
+            Country::new("Argentina").unwrap(),
+        )
+        .with_overdraft_limit(limit.clone());
+        assert_eq!(req.overdraft_limit(), &limit);
     }
 }
@@ -1,7 +1,14 @@
 use derive_more::From;
 use rust_decimal::Decimal;
 
-use crate::domain::model::{error::ClientError, value::client_id::ClientId};
+use crate::domain::model::{
+    entity::conversion::Conversion,
+    error::ClientError,
+    value::{
+        account_id::AccountId, client_id::ClientId, currency::Currency,
+        idempotency_key::IdempotencyKey, transaction_id::TransactionId, version::Version,
+    },
+};
 
 #[allow(unused_imports)]
 use crate::domain::model::entity::client::Client;
@@ -10,8 +17,22 @@ use crate::domain::model::entity::client::Client;
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
 pub struct DebitTransactionRequest {
     client_id: ClientId,
+    /// The account of the [Client] to debit, its main account if unset.
+    account_id: Option<AccountId>,
     /// The amount to debit from the [Client] balance. Always negative.
     amount: Decimal,
+    /// The currency of the balance to be debited.
+    currency: Currency,
+    /// Optional key to safely retry the request without applying the amount twice.
+    idempotency_key: Option<IdempotencyKey>,
+    /// The transaction this one compensates, if it is a reversal.
+    reversal_of: Option<TransactionId>,
+    /// The currency of the balance to be debited, when it is not the one of the amount.
+    balance_currency: Option<Currency>,
+    /// The conversion of the amount into the balance currency, resolved before applying it.
+    conversion: Option<Conversion>,
+    /// The [Version] of the [Client] the caller last read, to reject the request if it changed since.
+    expected_version: Option<Version>,
 }
 
 impl DebitTransactionRequest {
@@ -24,16 +45,103 @@ impl DebitTransactionRequest {
             return Err(ClientError::ZeroAmount);
         }
 
-        Ok(Self { client_id, amount })
+        Ok(Self {
+            client_id,
+            account_id: None,
+            amount,
+            currency: Currency::default(),
+            idempotency_key: None,
+            reversal_of: None,
+            balance_currency: None,
+            conversion: None,
+            expected_version: None,
+        })
+    }
+
+    pub fn with_idempotency_key(mut self, idempotency_key: IdempotencyKey) -> Self {
+        self.idempotency_key = Some(idempotency_key);
+        self
+    }
+
+    pub fn with_reversal_of(mut self, transaction_id: TransactionId) -> Self {
+        self.reversal_of = Some(transaction_id);
+        self
+    }
+
+    pub fn with_balance_currency(mut self, balance_currency: Currency) -> Self {
+        self.balance_currency = Some(balance_currency);
+        self
+    }
+
+    pub fn with_conversion(mut self, conversion: Conversion) -> Self {
+        self.conversion = Some(conversion);
+        self
+    }
+
+    pub fn with_account_id(mut self, account_id: AccountId) -> Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    pub fn with_expected_version(mut self, expected_version: Version) -> Self {
+        self.expected_version = Some(expected_version);
+        self
     }
 
     pub fn client_id(&self) -> &ClientId {
         &self.client_id
     }
 
+    pub fn account_id(&self) -> Option<&AccountId> {
+        self.account_id.as_ref()
+    }
+
     pub fn amount(&self) -> &Decimal {
         &self.amount
     }
+
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn idempotency_key(&self) -> Option<&IdempotencyKey> {
+        self.idempotency_key.as_ref()
+    }
+
+    pub fn reversal_of(&self) -> Option<&TransactionId> {
+        self.reversal_of.as_ref()
+    }
+
+    pub fn expected_version(&self) -> Option<&Version> {
+        self.expected_version.as_ref()
+    }
+
+    pub fn balance_currency(&self) -> Option<&Currency> {
+        self.balance_currency.as_ref()
+    }
+
+    pub fn conversion(&self) -> Option<&Conversion> {
+        self.conversion.as_ref()
+    }
+
+    /// The amount applied to the balance: the converted one if there is a [Conversion].
+    pub fn applied_amount(&self) -> &Decimal {
+        self.conversion
+            .as_ref()
+            .map_or(&self.amount, Conversion::converted_amount)
+    }
+
+    /// The currency of the balance the amount is applied to.
+    pub fn applied_currency(&self) -> &Currency {
+        self.conversion
+            .as_ref()
+            .map_or(&self.currency, Conversion::converted_currency)
+    }
 }
 
 #[cfg(test)]
@@ -67,4 +175,63 @@ mod tests {
         assert!(req.is_err());
         assert_eq!(req.err().unwrap(), ClientError::ZeroAmount);
     }
+
+    #[test]
+    fn test_04_given_an_idempotency_key_when_creating_debit_transaction_then_it_should_be_accessible()
+     {
+        let client_id = ClientId::new("1").unwrap();
+        let req = DebitTransactionRequest::new(client_id.clone(), Decimal::from(-100)).unwrap();
+        assert_eq!(req.idempotency_key(), None);
+        let key = IdempotencyKey::new("retry-1").unwrap();
+        let req = req.with_idempotency_key(key.clone());
+        assert_eq!(req.idempotency_key(), Some(&key));
+    }
+
+    #[test]
+    fn test_05_given_a_reversed_transaction_when_creating_debit_transaction_then_it_should_be_accessible()
+     {
+        let client_id = ClientId::new("1").unwrap();
+        let req = DebitTransactionRequest::new(client_id, Decimal::from(-100)).unwrap();
+        assert_eq!(req.reversal_of(), None);
+        let transaction_id = TransactionId::new("7").unwrap();
+        let req = req.with_reversal_of(transaction_id.clone());
+        assert_eq!(req.reversal_of(), Some(&transaction_id));
+    }
+
+    #[test]
+    fn test_06_given_a_conversion_when_creating_debit_transaction_then_it_should_apply_the_converted_amount()
+     {
+        let client_id = ClientId::new("1").unwrap();
+        let usd = Currency::new("USD").unwrap();
+        let req = DebitTransactionRequest::new(client_id, Decimal::from(-10))
+            .unwrap()
+            .with_currency(usd.clone())
+            .with_balance_currency(Currency::default());
+        assert_eq!(req.applied_amount(), &Decimal::from(-10));
+        assert_eq!(req.applied_currency(), &usd);
+        let conversion = Conversion::new(
+            Decimal::from(-10),
+            usd,
+            Decimal::from(1000),
+            Decimal::ZERO,
+            Decimal::from(1000),
+            Decimal::from(-10000),
+            Currency::default(),
+        );
+        let req = req.with_conversion(conversion.clone());
+        assert_eq!(req.conversion(), Some(&conversion));
+        assert_eq!(req.applied_amount(), &Decimal::from(-10000));
+        assert_eq!(req.applied_currency(), &Currency::default());
+    }
+
+    #[test]
+    fn test_07_given_an_expected_version_when_creating_debit_transaction_then_it_should_be_accessible()
+     {
+        let client_id = ClientId::new("1").unwrap();
+        let req = DebitTransactionRequest::new(client_id, Decimal::from(-100)).unwrap();
+        assert_eq!(req.expected_version(), None);
+        let version = Version::new("3").unwrap();
+        let req = req.with_expected_version(version);
+        assert_eq!(req.expected_version(), Some(&version));
+    }
 }
@@ -1,6 +1,6 @@
 use derive_more::From;
 
-use crate::domain::model::value::client_id::ClientId;
+use crate::domain::model::value::{client_id::ClientId, currency::Currency};
 
 #[allow(unused_imports)]
 use crate::domain::model::entity::client::Client;
@@ -9,16 +9,30 @@ use crate::domain::model::entity::client::Client;
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
 pub struct GetClientRequest {
     client_id: ClientId,
+    /// The currency of the balance to get. Ignored when getting the [Client] or all its balances.
+    currency: Currency,
 }
 
 impl GetClientRequest {
     pub fn new(client_id: ClientId) -> Self {
-        Self { client_id }
+        Self {
+            client_id,
+            currency: Currency::default(),
+        }
+    }
+
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
     }
 
     pub fn client_id(&self) -> &ClientId {
         &self.client_id
     }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
 }
 
 #[cfg(test)]
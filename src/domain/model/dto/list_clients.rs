@@ -0,0 +1,186 @@
+use crate::domain::model::{
+    error::ClientError,
+    value::{
+        balance_range::BalanceRange,
+        client_cursor::ClientCursor,
+        client_name::ClientName,
+        client_sort::{ClientSort, SortDirection},
+        country::Country,
+        currency::Currency,
+    },
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// How many [Client]s a page has when no limit is given.
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+/// The largest page that can be requested.
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// The fields required by the domain to list a page of [Client]s. Every filter that is set must match.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ListClientsRequest {
+    sort: ClientSort,
+    direction: SortDirection,
+    /// The last [Client] of the previous page. The first page if not set.
+    cursor: Option<ClientCursor>,
+    limit: usize,
+    country: Option<Country>,
+    document_prefix: Option<String>,
+    /// Matched ignoring the case. Normalized like the names, so any encoding of the same name matches.
+    name_contains: Option<ClientName>,
+    balance_range: BalanceRange,
+    /// The currency of the balance that is filtered, sorted and returned.
+    currency: Currency,
+}
+
+impl ListClientsRequest {
+    pub fn new(
+        sort: ClientSort,
+        direction: SortDirection,
+        cursor: Option<ClientCursor>,
+        limit: usize,
+    ) -> Result<Self, ClientError> {
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            return Err(ClientError::FieldInvalid {
+                field_name: "limit".to_string(),
+                value: limit.to_string(),
+            });
+        }
+
+        // A cursor only makes sense for the order of the page it was taken from.
+        if let Some(cursor) = &cursor
+            && cursor.balance().is_some() != (sort == ClientSort::Balance)
+        {
+            return Err(ClientError::FieldInvalid {
+                field_name: "cursor".to_string(),
+                value: cursor.to_string(),
+            });
+        }
+
+        Ok(Self {
+            sort,
+            direction,
+            cursor,
+            limit,
+            country: None,
+            document_prefix: None,
+            name_contains: None,
+            balance_range: BalanceRange::default(),
+            currency: Currency::default(),
+        })
+    }
+
+    pub fn with_country(mut self, country: Country) -> Self {
+        self.country = Some(country);
+        self
+    }
+
+    pub fn with_document_prefix(mut self, document_prefix: String) -> Self {
+        self.document_prefix = Some(document_prefix);
+        self
+    }
+
+    pub fn with_name_contains(mut self, name_contains: ClientName) -> Self {
+        self.name_contains = Some(name_contains);
+        self
+    }
+
+    pub fn with_balance_range(mut self, balance_range: BalanceRange) -> Self {
+        self.balance_range = balance_range;
+        self
+    }
+
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    pub fn sort(&self) -> ClientSort {
+        self.sort
+    }
+
+    pub fn direction(&self) -> SortDirection {
+        self.direction
+    }
+
+    pub fn cursor(&self) -> Option<&ClientCursor> {
+        self.cursor.as_ref()
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn country(&self) -> Option<&Country> {
+        self.country.as_ref()
+    }
+
+    pub fn document_prefix(&self) -> Option<&str> {
+        self.document_prefix.as_deref()
+    }
+
+    pub fn name_contains(&self) -> Option<&ClientName> {
+        self.name_contains.as_ref()
+    }
+
+    pub fn balance_range(&self) -> &BalanceRange {
+        &self.balance_range
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::value::client_id::ClientId;
+
+    #[test]
+    fn test_01_given_a_limit_out_of_range_when_creating_list_request_then_should_fail() {
+        for limit in [0, MAX_PAGE_SIZE + 1] {
+            let req =
+                ListClientsRequest::new(ClientSort::Id, SortDirection::Ascending, None, limit);
+            assert_eq!(
+                req.err().unwrap(),
+                ClientError::FieldInvalid {
+                    field_name: "limit".to_string(),
+                    value: limit.to_string()
+                }
+            );
+        }
+        assert!(
+            ListClientsRequest::new(
+                ClientSort::Id,
+                SortDirection::Ascending,
+                None,
+                MAX_PAGE_SIZE
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_02_given_a_cursor_of_another_sort_when_creating_list_request_then_should_fail() {
+        let by_id = ClientCursor::after_id(ClientId::new("1").unwrap());
+        let req = ListClientsRequest::new(
+            ClientSort::Balance,
+            SortDirection::Ascending,
+            Some(by_id.clone()),
+            DEFAULT_PAGE_SIZE,
+        );
+        assert!(req.is_err());
+        let req = ListClientsRequest::new(
+            ClientSort::Id,
+            SortDirection::Descending,
+            Some(by_id.clone()),
+            DEFAULT_PAGE_SIZE,
+        )
+        .unwrap();
+        assert_eq!(req.cursor(), Some(&by_id));
+        assert_eq!(req.currency(), &Currency::default());
+    }
+}
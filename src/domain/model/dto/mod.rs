@@ -1,4 +1,12 @@
+pub mod authorize;
+pub mod batch_transaction;
+pub mod capture_authorization;
+pub mod create_account;
 pub mod create_client;
 pub mod credit_transaction;
 pub mod debit_transaction;
 pub mod get_balance;
+pub mod list_clients;
+pub mod reverse_transaction;
+pub mod transfer;
+pub mod update_client;
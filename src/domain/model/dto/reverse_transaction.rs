@@ -0,0 +1,109 @@
+use derive_more::From;
+use rust_decimal::Decimal;
+
+use crate::domain::model::{
+    error::ClientError,
+    value::{transaction_id::TransactionId, version::Version},
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::{client::Client, transaction::Transaction};
+
+/// The fields required by the domain to reverse a credit or debit [Transaction].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+pub struct ReverseTransactionRequest {
+    transaction_id: TransactionId,
+    /// The amount to reverse. Always positive. The whole amount of the [Transaction] if not set.
+    amount: Option<Decimal>,
+    /// The [Version] of the [Client] the caller last read, to reject the request if it changed since.
+    expected_version: Option<Version>,
+}
+
+impl ReverseTransactionRequest {
+    pub fn new(
+        transaction_id: TransactionId,
+        amount: Option<Decimal>,
+    ) -> Result<Self, ClientError> {
+        if let Some(amount) = amount {
+            if amount < Decimal::ZERO {
+                return Err(ClientError::NegativeAmount);
+            }
+
+            if amount == Decimal::ZERO {
+                return Err(ClientError::ZeroAmount);
+            }
+        }
+
+        Ok(Self {
+            transaction_id,
+            amount,
+            expected_version: None,
+        })
+    }
+
+    pub fn with_expected_version(mut self, expected_version: Version) -> Self {
+        self.expected_version = Some(expected_version);
+        self
+    }
+
+    pub fn transaction_id(&self) -> &TransactionId {
+        &self.transaction_id
+    }
+
+    pub fn amount(&self) -> Option<&Decimal> {
+        self.amount.as_ref()
+    }
+
+    pub fn expected_version(&self) -> Option<&Version> {
+        self.expected_version.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_no_amount_when_creating_reverse_transaction_then_should_be_ok() {
+        let transaction_id = TransactionId::new("1").unwrap();
+        let req = ReverseTransactionRequest::new(transaction_id.clone(), None).unwrap();
+        assert_eq!(req.transaction_id(), &transaction_id);
+        assert_eq!(req.amount(), None);
+    }
+
+    #[test]
+    fn test_02_given_positive_amount_when_creating_reverse_transaction_then_should_be_ok() {
+        let req = ReverseTransactionRequest::new(
+            TransactionId::new("1").unwrap(),
+            Some(Decimal::from(5)),
+        )
+        .unwrap();
+        assert_eq!(req.amount(), Some(&Decimal::from(5)));
+    }
+
+    #[test]
+    fn test_03_given_negative_amount_when_creating_reverse_transaction_then_should_fail() {
+        let req = ReverseTransactionRequest::new(
+            TransactionId::new("1").unwrap(),
+            Some(Decimal::from(-5)),
+        );
+        assert_eq!(req.err().unwrap(), ClientError::NegativeAmount);
+    }
+
+    #[test]
+    fn test_04_given_zero_amount_when_creating_reverse_transaction_then_should_fail() {
+        let req =
+            ReverseTransactionRequest::new(TransactionId::new("1").unwrap(), Some(Decimal::ZERO));
+        assert_eq!(req.err().unwrap(), ClientError::ZeroAmount);
+    }
+
+    #[test]
+    fn test_05_given_an_expected_version_when_creating_reverse_transaction_then_it_should_be_accessible()
+     {
+        let req = ReverseTransactionRequest::new(TransactionId::new("1").unwrap(), None).unwrap();
+        assert_eq!(req.expected_version(), None);
+        let version = Version::new("3").unwrap();
+        let req = req.with_expected_version(version);
+        assert_eq!(req.expected_version(), Some(&version));
+    }
+}
@@ -0,0 +1,115 @@
+use derive_more::From;
+use rust_decimal::Decimal;
+
+use crate::domain::model::{
+    error::ClientError,
+    value::{client_id::ClientId, currency::Currency},
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// The fields required by the domain to transfer balance from one [Client] to another.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+pub struct TransferRequest {
+    from_client_id: ClientId,
+    to_client_id: ClientId,
+    /// The amount to move between the [Client] balances. Always positive.
+    amount: Decimal,
+    /// The currency of the balance to be transferred.
+    currency: Currency,
+}
+
+impl TransferRequest {
+    pub fn new(
+        from_client_id: ClientId,
+        to_client_id: ClientId,
+        amount: Decimal,
+    ) -> Result<Self, ClientError> {
+        if from_client_id == to_client_id {
+            return Err(ClientError::SelfTransfer {
+                client_id: from_client_id,
+            });
+        }
+
+        if amount < Decimal::ZERO {
+            return Err(ClientError::NegativeAmount);
+        }
+
+        if amount == Decimal::ZERO {
+            return Err(ClientError::ZeroAmount);
+        }
+
+        Ok(Self {
+            from_client_id,
+            to_client_id,
+            amount,
+            currency: Currency::default(),
+        })
+    }
+
+    pub fn from_client_id(&self) -> &ClientId {
+        &self.from_client_id
+    }
+
+    pub fn to_client_id(&self) -> &ClientId {
+        &self.to_client_id
+    }
+
+    pub fn amount(&self) -> &Decimal {
+        &self.amount
+    }
+
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::value::client_id::ClientId;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_01_given_two_clients_and_positive_amount_when_creating_transfer_then_should_be_ok() {
+        let from = ClientId::new("1").unwrap();
+        let to = ClientId::new("2").unwrap();
+        let req = TransferRequest::new(from.clone(), to.clone(), Decimal::from(100)).unwrap();
+        assert_eq!(req.from_client_id(), &from);
+        assert_eq!(req.to_client_id(), &to);
+        assert_eq!(req.amount(), &Decimal::from(100));
+    }
+
+    #[test]
+    fn test_02_given_the_same_client_when_creating_transfer_then_should_fail() {
+        let client_id = ClientId::new("1").unwrap();
+        let req = TransferRequest::new(client_id.clone(), client_id.clone(), Decimal::from(100));
+        assert_eq!(req.err().unwrap(), ClientError::SelfTransfer { client_id });
+    }
+
+    #[test]
+    fn test_03_given_negative_amount_when_creating_transfer_then_should_fail() {
+        let req = TransferRequest::new(
+            ClientId::new("1").unwrap(),
+            ClientId::new("2").unwrap(),
+            Decimal::from(-100),
+        );
+        assert_eq!(req.err().unwrap(), ClientError::NegativeAmount);
+    }
+
+    #[test]
+    fn test_04_given_zero_amount_when_creating_transfer_then_should_fail() {
+        let req = TransferRequest::new(
+            ClientId::new("1").unwrap(),
+            ClientId::new("2").unwrap(),
+            Decimal::ZERO,
+        );
+        assert_eq!(req.err().unwrap(), ClientError::ZeroAmount);
+    }
+}
@@ -0,0 +1,151 @@
+use derive_more::From;
+
+use crate::domain::model::value::{
+    birth_date::BirthDate, client_id::ClientId, client_name::ClientName, country::Country,
+    document::Document, email::Email, phone_number::PhoneNumber, version::Version,
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// The fields required by the domain to correct the details of a [Client]. Only the fields that
+/// are set are changed.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+pub struct UpdateClientRequest {
+    client_id: ClientId,
+    name: Option<ClientName>,
+    birth_date: Option<BirthDate>,
+    document: Option<Document>,
+    country: Option<Country>,
+    /// `None` leaves the email as it is, `Some(None)` removes it.
+    email: Option<Option<Email>>,
+    /// `None` leaves the phone as it is, `Some(None)` removes it.
+    phone: Option<Option<PhoneNumber>>,
+    /// The [Version] of the [Client] the caller last read, to reject the request if it changed since.
+    expected_version: Option<Version>,
+}
+
+impl UpdateClientRequest {
+    pub fn new(client_id: ClientId) -> Self {
+        Self {
+            client_id,
+            name: None,
+            birth_date: None,
+            document: None,
+            country: None,
+            email: None,
+            phone: None,
+            expected_version: None,
+        }
+    }
+
+    pub fn with_expected_version(mut self, expected_version: Version) -> Self {
+        self.expected_version = Some(expected_version);
+        self
+    }
+
+    pub fn with_name(mut self, name: ClientName) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_birth_date(mut self, birth_date: BirthDate) -> Self {
+        self.birth_date = Some(birth_date);
+        self
+    }
+
+    pub fn with_document(mut self, document: Document) -> Self {
+        self.document = Some(document);
+        self
+    }
+
+    pub fn with_country(mut self, country: Country) -> Self {
+        self.country = Some(country);
+        self
+    }
+
+    pub fn with_email(mut self, email: Email) -> Self {
+        self.email = Some(Some(email));
+        self
+    }
+
+    /// Removes the email of the [Client].
+    pub fn without_email(mut self) -> Self {
+        self.email = Some(None);
+        self
+    }
+
+    pub fn with_phone(mut self, phone: PhoneNumber) -> Self {
+        self.phone = Some(Some(phone));
+        self
+    }
+
+    /// Removes the phone of the [Client].
+    pub fn without_phone(mut self) -> Self {
+        self.phone = Some(None);
+        self
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn name(&self) -> Option<&ClientName> {
+        self.name.as_ref()
+    }
+
+    pub fn birth_date(&self) -> Option<&BirthDate> {
+        self.birth_date.as_ref()
+    }
+
+    pub fn document(&self) -> Option<&Document> {
+        self.document.as_ref()
+    }
+
+    pub fn country(&self) -> Option<&Country> {
+        self.country.as_ref()
+    }
+
+    pub fn email(&self) -> Option<Option<&Email>> {
+        self.email.as_ref().map(Option::as_ref)
+    }
+
+    pub fn phone(&self) -> Option<Option<&PhoneNumber>> {
+        self.phone.as_ref().map(Option::as_ref)
+    }
+
+    pub fn expected_version(&self) -> Option<&Version> {
+        self.expected_version.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_only_some_fields_when_creating_update_client_request_then_the_rest_should_be_unset()
+     {
+        let client_id = ClientId::new("1").unwrap();
+        let name = ClientName::new("Jane Doe").unwrap();
+        let req = UpdateClientRequest::new(client_id.clone()).with_name(name.clone());
+        assert_eq!(req.client_id(), &client_id);
+        assert_eq!(req.name(), Some(&name));
+        assert_eq!(req.birth_date(), None);
+        assert_eq!(req.document(), None);
+        assert_eq!(req.country(), None);
+        assert_eq!(req.expected_version(), None);
+        assert_eq!(req.email(), None);
+        assert_eq!(req.phone(), None);
+    }
+
+    #[test]
+    fn test_02_given_removed_contacts_when_creating_update_client_request_then_they_should_be_set_to_none()
+     {
+        let req = UpdateClientRequest::new(ClientId::new("1").unwrap())
+            .without_email()
+            .without_phone();
+        assert_eq!(req.email(), Some(None));
+        assert_eq!(req.phone(), Some(None));
+    }
+}
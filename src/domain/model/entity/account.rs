@@ -0,0 +1,141 @@
+use rust_decimal::Decimal;
+
+use crate::domain::model::{
+    entity::client::Client,
+    error::ClientError,
+    value::{account_id::AccountId, account_name::AccountName, client_id::ClientId, money::Money},
+};
+
+/// An account of a [Client], with its own balance per currency. Every [Client] has a main account,
+/// the one its overdraft limit, transfers and authorizations apply to, and can open others, such as
+/// a savings pocket, that can never go below zero.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Account {
+    id: AccountId,
+    client_id: ClientId,
+    name: AccountName,
+    main: bool,
+}
+
+impl Account {
+    pub fn new(id: AccountId, client_id: ClientId, name: AccountName) -> Self {
+        Self {
+            id,
+            client_id,
+            name,
+            main: false,
+        }
+    }
+
+    /// The account a [Client] is created with.
+    pub fn main(id: AccountId, client_id: ClientId) -> Self {
+        Self {
+            id,
+            client_id,
+            name: AccountName::default(),
+            main: true,
+        }
+    }
+
+    pub fn id(&self) -> &AccountId {
+        &self.id
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn name(&self) -> &AccountName {
+        &self.name
+    }
+
+    pub fn is_main(&self) -> bool {
+        self.main
+    }
+
+    /// Checks that applying the amount to the given balance of this account keeps it above its floor:
+    /// the overdraft limit of the [Client] for the main account, zero for the others.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::InsufficientFunds] if a debit would leave the balance below the floor.
+    /// - [ClientError::AmountOverflow] if the resulting balance is out of range.
+    pub fn ensure_funds_for(
+        &self,
+        client: &Client,
+        balance: &Money,
+        amount: &Money,
+    ) -> Result<(), ClientError> {
+        if self.main {
+            return client.ensure_funds_for(balance, amount);
+        }
+        let resulting_balance = balance.checked_add(amount)?;
+        if *amount.amount() < Decimal::ZERO && *resulting_balance.amount() < Decimal::ZERO {
+            return Err(ClientError::InsufficientFunds {
+                client_id: self.client_id.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::value::{
+        birth_date::BirthDate, client_name::ClientName, country::Country, currency::Currency,
+        document::Document, document_type::DocumentType, overdraft_limit::OverdraftLimit,
+    };
+
+    fn client() -> Client {
+        Client::new(
+            ClientId::new("1").unwrap(),
+            ClientName::new("John Doe").unwrap(),
+            BirthDate::new("1990-01-01").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
+            Country::new("AR").unwrap(),
+        )
+        .with_overdraft_limit(OverdraftLimit::new(Decimal::from(100)).unwrap())
+    }
+
+    fn money(amount: i64) -> Money {
+        Money::new(Decimal::from(amount), Currency::default()).unwrap()
+    }
+
+    #[test]
+    fn test_01_given_the_main_account_when_debiting_within_the_overdraft_then_it_should_be_allowed()
+    {
+        let account = Account::main(AccountId::new("1").unwrap(), ClientId::new("1").unwrap());
+        assert!(account.is_main());
+        assert_eq!(account.name(), &AccountName::default());
+        assert!(
+            account
+                .ensure_funds_for(&client(), &money(10), &money(-60))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_02_given_another_account_when_debiting_below_zero_then_it_should_fail() {
+        let account = Account::new(
+            AccountId::new("2").unwrap(),
+            ClientId::new("1").unwrap(),
+            AccountName::new("savings").unwrap(),
+        );
+        assert!(!account.is_main());
+        assert!(
+            account
+                .ensure_funds_for(&client(), &money(10), &money(-10))
+                .is_ok()
+        );
+        assert_eq!(
+            account
+                .ensure_funds_for(&client(), &money(10), &money(-11))
+                .err()
+                .unwrap(),
+            ClientError::InsufficientFunds {
+                client_id: ClientId::new("1").unwrap()
+            }
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::domain::model::{
+    error::ClientError,
+    value::{
+        authorization_id::AuthorizationId, authorization_status::AuthorizationStatus,
+        client_id::ClientId, currency::Currency, version::Version,
+    },
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// A hold on the funds of a [Client]. It reserves the amount without moving it until it is
+/// captured as a real debit, voided, or it expires.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Authorization {
+    id: AuthorizationId,
+    client_id: ClientId,
+    /// The reserved amount. Always positive.
+    amount: Decimal,
+    status: AuthorizationStatus,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    /// The amount finally debited, once captured. It can be lower than the reserved amount.
+    captured_amount: Option<Decimal>,
+    currency: Currency,
+    /// The [Version] of the [Client] once the operation that returned this hold was applied. It is
+    /// not stored with the hold.
+    version: Version,
+}
+
+impl Authorization {
+    pub fn new(
+        id: AuthorizationId,
+        client_id: ClientId,
+        amount: Decimal,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            client_id,
+            amount,
+            status: AuthorizationStatus::Pending,
+            created_at,
+            expires_at,
+            captured_amount: None,
+            currency: Currency::default(),
+            version: Version::default(),
+        }
+    }
+
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Restores the [AuthorizationStatus] of a stored [Authorization], along with the amount it
+    /// was captured for, if it was.
+    pub fn with_status(
+        mut self,
+        status: AuthorizationStatus,
+        captured_amount: Option<Decimal>,
+    ) -> Self {
+        self.status = status;
+        self.captured_amount = captured_amount;
+        self
+    }
+
+    pub fn id(&self) -> &AuthorizationId {
+        &self.id
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn amount(&self) -> &Decimal {
+        &self.amount
+    }
+
+    pub fn status(&self) -> &AuthorizationStatus {
+        &self.status
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+
+    pub fn captured_amount(&self) -> Option<&Decimal> {
+        self.captured_amount.as_ref()
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Returns if the hold still reserves funds at the given time.
+    pub fn is_holding(&self, now: DateTime<Utc>) -> bool {
+        self.status == AuthorizationStatus::Pending && now < self.expires_at
+    }
+
+    /// Marks a pending hold as expired once its timeout has passed.
+    pub fn expire_if_due(&mut self, now: DateTime<Utc>) {
+        if self.status == AuthorizationStatus::Pending && now >= self.expires_at {
+            self.status = AuthorizationStatus::Expired;
+        }
+    }
+
+    /// Captures the hold for the given amount, up to the reserved one.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::AuthorizationExpired] if the hold has expired.
+    /// - [ClientError::AuthorizationNotPending] if the hold was already captured or voided.
+    /// - [ClientError::CaptureExceedsAuthorization] if the amount exceeds the reserved one.
+    pub fn capture(&mut self, amount: Decimal, now: DateTime<Utc>) -> Result<(), ClientError> {
+        self.ensure_pending(now)?;
+        if amount > self.amount {
+            return Err(ClientError::CaptureExceedsAuthorization {
+                authorization_id: self.id.clone(),
+            });
+        }
+        self.status = AuthorizationStatus::Captured;
+        self.captured_amount = Some(amount);
+        Ok(())
+    }
+
+    /// Voids the hold, releasing the reserved amount.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::AuthorizationExpired] if the hold has expired.
+    /// - [ClientError::AuthorizationNotPending] if the hold was already captured or voided.
+    pub fn void(&mut self, now: DateTime<Utc>) -> Result<(), ClientError> {
+        self.ensure_pending(now)?;
+        self.status = AuthorizationStatus::Voided;
+        Ok(())
+    }
+
+    fn ensure_pending(&mut self, now: DateTime<Utc>) -> Result<(), ClientError> {
+        self.expire_if_due(now);
+        match self.status {
+            AuthorizationStatus::Pending => Ok(()),
+            AuthorizationStatus::Expired => Err(ClientError::AuthorizationExpired {
+                authorization_id: self.id.clone(),
+            }),
+            AuthorizationStatus::Captured | AuthorizationStatus::Voided => {
+                Err(ClientError::AuthorizationNotPending {
+                    authorization_id: self.id.clone(),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    fn pending_authorization(now: DateTime<Utc>) -> Authorization {
+        Authorization::new(
+            AuthorizationId::new("1").unwrap(),
+            ClientId::new("7").unwrap(),
+            Decimal::from(50),
+            now,
+            now + Duration::minutes(10),
+        )
+    }
+
+    #[test]
+    fn test_01_given_a_new_authorization_then_it_should_be_pending_and_holding() {
+        let now = Utc::now();
+        let authorization = pending_authorization(now);
+        assert_eq!(authorization.status(), &AuthorizationStatus::Pending);
+        assert_eq!(authorization.amount(), &Decimal::from(50));
+        assert_eq!(authorization.captured_amount(), None);
+        assert!(authorization.is_holding(now));
+    }
+
+    #[test]
+    fn test_02_given_a_pending_authorization_when_capturing_a_lower_amount_then_it_should_be_captured()
+     {
+        let now = Utc::now();
+        let mut authorization = pending_authorization(now);
+        authorization.capture(Decimal::from(20), now).unwrap();
+        assert_eq!(authorization.status(), &AuthorizationStatus::Captured);
+        assert_eq!(authorization.captured_amount(), Some(&Decimal::from(20)));
+        assert!(!authorization.is_holding(now));
+    }
+
+    #[test]
+    fn test_03_given_a_pending_authorization_when_capturing_more_than_reserved_then_it_should_fail()
+    {
+        let now = Utc::now();
+        let mut authorization = pending_authorization(now);
+        let result = authorization.capture(Decimal::from(51), now);
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::CaptureExceedsAuthorization {
+                authorization_id: AuthorizationId::new("1").unwrap()
+            }
+        );
+        assert_eq!(authorization.status(), &AuthorizationStatus::Pending);
+    }
+
+    #[test]
+    fn test_04_given_a_voided_authorization_when_capturing_or_voiding_then_it_should_fail() {
+        let now = Utc::now();
+        let mut authorization = pending_authorization(now);
+        authorization.void(now).unwrap();
+        assert_eq!(authorization.status(), &AuthorizationStatus::Voided);
+        let not_pending = ClientError::AuthorizationNotPending {
+            authorization_id: AuthorizationId::new("1").unwrap(),
+        };
+        assert_eq!(
+            authorization.capture(Decimal::from(10), now).err().unwrap(),
+            not_pending
+        );
+        assert_eq!(authorization.void(now).err().unwrap(), not_pending);
+    }
+
+    #[test]
+    fn test_05_given_an_authorization_past_its_timeout_then_it_should_expire_and_release_the_funds()
+    {
+        let now = Utc::now();
+        let mut authorization = pending_authorization(now);
+        let later = now + Duration::minutes(10);
+        assert!(!authorization.is_holding(later));
+        assert_eq!(
+            authorization
+                .capture(Decimal::from(10), later)
+                .err()
+                .unwrap(),
+            ClientError::AuthorizationExpired {
+                authorization_id: AuthorizationId::new("1").unwrap()
+            }
+        );
+        assert_eq!(authorization.status(), &AuthorizationStatus::Expired);
+    }
+
+    #[test]
+    fn test_06_given_a_stored_captured_authorization_when_restoring_it_then_it_should_not_hold_funds()
+     {
+        let now = Utc::now();
+        let authorization = pending_authorization(now)
+            .with_status(AuthorizationStatus::Captured, Some(Decimal::from(30)));
+        assert_eq!(authorization.status(), &AuthorizationStatus::Captured);
+        assert_eq!(authorization.captured_amount(), Some(&Decimal::from(30)));
+        assert!(!authorization.is_holding(now));
+    }
+}
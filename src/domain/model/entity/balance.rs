@@ -1,26 +1,107 @@
 use rust_decimal::Decimal;
 
-use crate::domain::model::value::client_id::ClientId;
+use crate::domain::model::{
+    entity::conversion::Conversion,
+    error::ClientError,
+    value::{account_id::AccountId, client_id::ClientId, currency::Currency, version::Version},
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::{client::Client, transaction::Transaction};
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Balance {
     id: ClientId,
+    /// The account of the [Client] it belongs to, unset when it adds up all of them.
+    account_id: Option<AccountId>,
+    /// The ledger balance: the sum of every applied [Transaction].
     balance: Decimal,
+    /// The amount reserved by pending authorizations, not yet debited from the ledger balance.
+    held: Decimal,
+    currency: Currency,
+    /// The conversion applied by the operation that produced this balance, if it came in another currency.
+    conversion: Option<Conversion>,
+    /// The [Version] of the [Client] right after the operation that produced this balance, or when it was read.
+    version: Version,
 }
 
 impl Balance {
     pub fn new(id: ClientId, balance: Decimal) -> Self {
-        Self { id, balance }
+        Self {
+            id,
+            account_id: None,
+            balance,
+            held: Decimal::ZERO,
+            currency: Currency::default(),
+            conversion: None,
+            version: Version::default(),
+        }
+    }
+
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    pub fn with_conversion(mut self, conversion: Conversion) -> Self {
+        self.conversion = Some(conversion);
+        self
+    }
+
+    pub fn with_held(mut self, held: Decimal) -> Self {
+        self.held = held;
+        self
+    }
+
+    pub fn with_account_id(mut self, account_id: AccountId) -> Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
     }
 
     pub fn client_id(&self) -> &ClientId {
         &self.id
     }
 
+    pub fn account_id(&self) -> Option<&AccountId> {
+        self.account_id.as_ref()
+    }
+
     pub fn balance(&self) -> &Decimal {
         &self.balance
     }
 
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn conversion(&self) -> Option<&Conversion> {
+        self.conversion.as_ref()
+    }
+
+    pub fn held(&self) -> &Decimal {
+        &self.held
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// The balance that can still be spent: the ledger balance minus the held amount.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::AmountOverflow] if the difference is out of range.
+    pub fn available(&self) -> Result<Decimal, ClientError> {
+        self.balance
+            .checked_sub(self.held)
+            .ok_or(ClientError::AmountOverflow)
+    }
+
     /// Sets the balance of the [Balance] and returns the old balance.
     pub fn set_balance(&mut self, balance: Decimal) -> Decimal {
         let old_balance = self.balance;
@@ -96,4 +177,41 @@ mod tests {
         balance.set_balance(Decimal::from(-5));
         assert_eq!(balance.balance(), &Decimal::from(-5));
     }
+
+    #[test]
+    fn test_10_given_a_balance_with_held_amount_then_available_should_exclude_it() {
+        let balance = Balance::new(ClientId::new("1").unwrap(), Decimal::from(100));
+        assert_eq!(balance.held(), &Decimal::ZERO);
+        assert_eq!(balance.available().unwrap(), Decimal::from(100));
+        let balance = balance.with_held(Decimal::from(30));
+        assert_eq!(balance.balance(), &Decimal::from(100));
+        assert_eq!(balance.available().unwrap(), Decimal::from(70));
+    }
+
+    #[test]
+    fn test_11_given_a_balance_without_currency_then_it_should_use_the_default_currency() {
+        let balance = Balance::new(ClientId::new("1").unwrap(), Decimal::from(100));
+        assert_eq!(balance.currency(), &Currency::default());
+        let balance = balance.with_currency(Currency::new("USD").unwrap());
+        assert_eq!(balance.currency().to_string(), "USD");
+    }
+
+    #[test]
+    fn test_12_given_a_balance_without_version_then_it_should_be_at_the_first_version() {
+        let balance = Balance::new(ClientId::new("1").unwrap(), Decimal::from(100));
+        assert_eq!(balance.version(), &Version::default());
+        let balance = balance.with_version(Version::new("5").unwrap());
+        assert_eq!(balance.version().to_string(), "5");
+    }
+
+    #[test]
+    fn test_13_given_a_held_amount_that_overflows_when_getting_the_available_balance_then_should_fail()
+     {
+        let balance =
+            Balance::new(ClientId::new("1").unwrap(), Decimal::MIN).with_held(Decimal::from(1));
+        assert_eq!(
+            balance.available().err().unwrap(),
+            ClientError::AmountOverflow
+        );
+    }
 }
@@ -0,0 +1,29 @@
+use crate::domain::model::{entity::balance::Balance, value::authorization_id::AuthorizationId};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::authorization::Authorization;
+
+/// What resetting the balances to zero changed, so it can be merged back if the export fails: the
+/// previous [Balance]s and the pending [Authorization]s voided along with them.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BalanceReset {
+    old_balances: Vec<Balance>,
+    voided_authorizations: Vec<AuthorizationId>,
+}
+
+impl BalanceReset {
+    pub fn new(old_balances: Vec<Balance>, voided_authorizations: Vec<AuthorizationId>) -> Self {
+        Self {
+            old_balances,
+            voided_authorizations,
+        }
+    }
+
+    pub fn old_balances(&self) -> &[Balance] {
+        &self.old_balances
+    }
+
+    pub fn voided_authorizations(&self) -> &[AuthorizationId] {
+        &self.voided_authorizations
+    }
+}
@@ -1,6 +1,18 @@
-use crate::domain::model::value::{
-    birth_date::BirthDate, client_id::ClientId, client_name::ClientName, country::Country,
-    document::Document,
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::domain::model::{
+    dto::update_client::UpdateClientRequest,
+    entity::client_change::ClientChange,
+    error::ClientError,
+    value::{
+        birth_date::BirthDate, client_field::ClientField, client_id::ClientId,
+        client_name::ClientName, client_status::ClientStatus, country::Country, currency::Currency,
+        document::Document, email::Email, money::Money, overdraft_limit::OverdraftLimit,
+        phone_number::PhoneNumber, version::Version,
+    },
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -10,6 +22,12 @@ pub struct Client {
     birth_date: BirthDate,
     document: Document,
     country: Country,
+    overdraft_limit: OverdraftLimit,
+    status: ClientStatus,
+    email: Option<Email>,
+    phone: Option<PhoneNumber>,
+    /// Increased by the repository on every change to the [Client] or to its balances.
+    version: Version,
 }
 
 impl Client {
@@ -26,9 +44,41 @@ impl Client {
             birth_date,
             document,
             country,
+            overdraft_limit: OverdraftLimit::default(),
+            status: ClientStatus::default(),
+            email: None,
+            phone: None,
+            version: Version::default(),
         }
     }
 
+    pub fn with_overdraft_limit(mut self, overdraft_limit: OverdraftLimit) -> Self {
+        self.overdraft_limit = overdraft_limit;
+        self
+    }
+
+    pub fn with_email(mut self, email: Option<Email>) -> Self {
+        self.email = email;
+        self
+    }
+
+    pub fn with_phone(mut self, phone: Option<PhoneNumber>) -> Self {
+        self.phone = phone;
+        self
+    }
+
+    /// Restores the [ClientStatus] of a stored [Client], without checking the transition to it.
+    pub fn with_status(mut self, status: ClientStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Restores the [Version] of a stored [Client].
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
+    }
+
     pub fn id(&self) -> &ClientId {
         &self.id
     }
@@ -48,22 +98,174 @@ impl Client {
     pub fn country(&self) -> &Country {
         &self.country
     }
+
+    pub fn overdraft_limit(&self) -> &OverdraftLimit {
+        &self.overdraft_limit
+    }
+
+    pub fn status(&self) -> ClientStatus {
+        self.status
+    }
+
+    pub fn email(&self) -> Option<&Email> {
+        self.email.as_ref()
+    }
+
+    pub fn phone(&self) -> Option<&PhoneNumber> {
+        self.phone.as_ref()
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Moves the [Client] to its next [Version], after a change to it or to its balances.
+    pub fn increase_version(&mut self) {
+        self.version = self.version.next();
+    }
+
+    /// Checks that the [Client] is still at the version the caller expects, if it expects one.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::VersionConflict] if the [Client] changed since the caller read it.
+    pub fn ensure_version(&self, expected: Option<&Version>) -> Result<(), ClientError> {
+        match expected {
+            Some(expected) if *expected != self.version => Err(ClientError::VersionConflict {
+                client_id: self.id.clone(),
+                expected: *expected,
+                actual: self.version,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Moves the [Client] to the given [ClientStatus]. The balance requirements to close it are
+    /// checked by whoever owns the balances.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::InvalidStatusTransition] if the current status cannot change to the given one.
+    pub fn change_status(&mut self, to: ClientStatus) -> Result<(), ClientError> {
+        if !self.status.can_change_to(to) {
+            return Err(ClientError::InvalidStatusTransition {
+                client_id: self.id.clone(),
+                from: self.status,
+                to,
+            });
+        }
+        self.status = to;
+        Ok(())
+    }
+
+    /// Replaces the details set in the request. Returns one [ClientChange] per detail that actually
+    /// changed, so setting a field to its current value leaves no trace.
+    pub fn update(
+        &mut self,
+        req: &UpdateClientRequest,
+        changed_at: DateTime<Utc>,
+    ) -> Vec<ClientChange> {
+        [
+            replace_field(&mut self.name, req.name(), ClientField::Name),
+            replace_field(
+                &mut self.birth_date,
+                req.birth_date(),
+                ClientField::BirthDate,
+            ),
+            replace_field(&mut self.document, req.document(), ClientField::Document),
+            replace_field(&mut self.country, req.country(), ClientField::Country),
+            replace_optional_field(&mut self.email, req.email(), ClientField::Email),
+            replace_optional_field(&mut self.phone, req.phone(), ClientField::Phone),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|(field, old_value, new_value)| {
+            ClientChange::new(self.id.clone(), field, old_value, new_value, changed_at)
+        })
+        .collect()
+    }
+
+    /// Checks that the balances of the [Client] can be credited or debited.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::ClientBlocked] if the client is blocked.
+    /// - [ClientError::ClientClosed] if the client is closed.
+    pub fn ensure_operable(&self) -> Result<(), ClientError> {
+        match self.status {
+            ClientStatus::Active => Ok(()),
+            ClientStatus::Blocked => Err(ClientError::ClientBlocked {
+                client_id: self.id.clone(),
+            }),
+            ClientStatus::Closed => Err(ClientError::ClientClosed {
+                client_id: self.id.clone(),
+            }),
+        }
+    }
+
+    /// Checks that applying the amount to the current balance of the [Client] keeps it within its
+    /// [OverdraftLimit]. The limit is set in the default [Currency], so balances in any other one
+    /// cannot go below zero. Credits are always allowed, even if the balance is still below the
+    /// floor.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::InsufficientFunds] if a debit would leave the balance below the floor.
+    /// - [ClientError::AmountOverflow] if the resulting balance is out of range.
+    pub fn ensure_funds_for(&self, balance: &Money, amount: &Money) -> Result<(), ClientError> {
+        let resulting_balance = balance.checked_add(amount)?;
+        let allowed = if resulting_balance.currency() == &Currency::default() {
+            self.overdraft_limit.allows(resulting_balance.amount())
+        } else {
+            *resulting_balance.amount() >= Decimal::ZERO
+        };
+        if *amount.amount() < Decimal::ZERO && !allowed {
+            return Err(ClientError::InsufficientFunds {
+                client_id: self.id.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Replaces the current value if a different one is given. Returns the field with its old and new values.
+fn replace_field<T: Clone + PartialEq + Display>(
+    current: &mut T,
+    new: Option<&T>,
+    field: ClientField,
+) -> Option<(ClientField, String, String)> {
+    let new = new.filter(|new| *new != current)?;
+    let old = std::mem::replace(current, new.clone());
+    Some((field, old.to_string(), new.to_string()))
+}
+
+/// Like [replace_field], for a field that may be unset. `Some(None)` removes it. An unset value
+/// is recorded as empty.
+fn replace_optional_field<T: Clone + PartialEq + Display>(
+    current: &mut Option<T>,
+    new: Option<Option<&T>>,
+    field: ClientField,
+) -> Option<(ClientField, String, String)> {
+    let new = new.filter(|new| current.as_ref() != *new)?;
+    let old = std::mem::replace(current, new.cloned());
+    Some((
+        field,
+        old.map(|old| old.to_string()).unwrap_or_default(),
+        new.map(ToString::to_string).unwrap_or_default(),
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::model::value::{
-        birth_date::BirthDate, client_id::ClientId, client_name::ClientName, country::Country,
-        document::Document,
-    };
+    use crate::domain::model::value::document_type::DocumentType;
 
     #[test]
     fn test_01_given_valid_data_when_creating_client_then_fields_should_be_accessible() {
         let id = ClientId::new("1").unwrap();
         let name = ClientName::new("John Doe").unwrap();
         let birth_date = BirthDate::new("1990-01-01").unwrap();
-        let document = Document::new("1234567890").unwrap();
+        let document = Document::new(DocumentType::Dni, "12345678").unwrap();
         let country = Country::new("Argentina").unwrap();
         let client = Client::new(
             id.clone(),
@@ -77,5 +279,200 @@ mod tests {
         assert_eq!(client.birth_date(), &birth_date);
         assert_eq!(client.document(), &document);
         assert_eq!(client.country(), &country);
+        assert_eq!(client.overdraft_limit(), &OverdraftLimit::default());
+        assert_eq!(client.status(), ClientStatus::Active);
+    }
+
+    fn ars(amount: i64) -> Money {
+        Money::new(Decimal::from(amount), Default::default()).unwrap()
+    }
+
+    fn client_with_limit(limit: i64) -> Client {
+        Client::new(
+            ClientId::new("1").unwrap(),
+            ClientName::new("John Doe").unwrap(),
+            BirthDate::new("1990-01-01").unwrap(),
+            Document::new(DocumentType::Dni, "12345678").unwrap(),
+            Country::new("Argentina").unwrap(),
+        )
+        .with_overdraft_limit(OverdraftLimit::new(Decimal::from(limit)).unwrap())
+    }
+
+    #[test]
+    fn test_02_given_a_client_without_overdraft_when_debiting_more_than_its_balance_then_should_fail()
+     {
+        let client = client_with_limit(0);
+        assert_eq!(
+            client.ensure_funds_for(&ars(10), &ars(-11)).err().unwrap(),
+            ClientError::InsufficientFunds {
+                client_id: ClientId::new("1").unwrap()
+            }
+        );
+        assert!(client.ensure_funds_for(&ars(10), &ars(-10)).is_ok());
+    }
+
+    #[test]
+    fn test_03_given_a_client_with_overdraft_when_debiting_up_to_the_limit_then_should_be_ok() {
+        let client = client_with_limit(50);
+        assert!(client.ensure_funds_for(&ars(10), &ars(-60)).is_ok());
+        assert!(client.ensure_funds_for(&ars(10), &ars(-61)).is_err());
+    }
+
+    #[test]
+    fn test_04_given_a_balance_below_the_floor_when_crediting_then_should_be_ok() {
+        let client = client_with_limit(0);
+        assert!(client.ensure_funds_for(&ars(-20), &ars(5)).is_ok());
+    }
+
+    #[test]
+    fn test_05_given_a_blocked_client_when_checking_if_operable_then_should_fail_until_unblocked() {
+        let mut client = client_with_limit(0);
+        assert!(client.ensure_operable().is_ok());
+        client.change_status(ClientStatus::Blocked).unwrap();
+        assert_eq!(
+            client.ensure_operable().err().unwrap(),
+            ClientError::ClientBlocked {
+                client_id: ClientId::new("1").unwrap()
+            }
+        );
+        client.change_status(ClientStatus::Active).unwrap();
+        assert!(client.ensure_operable().is_ok());
+    }
+
+    #[test]
+    fn test_06_given_a_closed_client_when_changing_its_status_then_should_fail() {
+        let mut client = client_with_limit(0);
+        client.change_status(ClientStatus::Closed).unwrap();
+        assert_eq!(
+            client.ensure_operable().err().unwrap(),
+            ClientError::ClientClosed {
+                client_id: ClientId::new("1").unwrap()
+            }
+        );
+        assert_eq!(
+            client.change_status(ClientStatus::Active).err().unwrap(),
+            ClientError::InvalidStatusTransition {
+                client_id: ClientId::new("1").unwrap(),
+                from: ClientStatus::Closed,
+                to: ClientStatus::Active
+            }
+        );
+        assert_eq!(client.status(), ClientStatus::Closed);
+    }
+
+    #[test]
+    fn test_07_given_some_new_details_when_updating_then_only_the_changed_ones_should_be_recorded()
+    {
+        let mut client = client_with_limit(0);
+        let now = Utc::now();
+        let req = UpdateClientRequest::new(ClientId::new("1").unwrap())
+            .with_name(ClientName::new("Jane Doe").unwrap())
+            .with_country(Country::new("Argentina").unwrap());
+        let changes = client.update(&req, now);
+        assert_eq!(
+            changes,
+            vec![ClientChange::new(
+                ClientId::new("1").unwrap(),
+                ClientField::Name,
+                "John Doe".to_string(),
+                "Jane Doe".to_string(),
+                now
+            )]
+        );
+        assert_eq!(client.name(), &ClientName::new("Jane Doe").unwrap());
+        assert_eq!(
+            client.document(),
+            &Document::new(DocumentType::Dni, "12345678").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_08_given_a_client_without_email_when_setting_one_then_the_change_should_have_an_empty_old_value()
+     {
+        let mut client = client_with_limit(0);
+        let email = Email::new("john@example.com").unwrap();
+        let req = UpdateClientRequest::new(ClientId::new("1").unwrap()).with_email(email.clone());
+
+        let changes = client.update(&req, Utc::now());
+        let again = client.update(&req, Utc::now());
+
+        assert_eq!(client.email(), Some(&email));
+        assert_eq!(client.phone(), None);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field(), ClientField::Email);
+        assert_eq!(changes[0].old_value(), "");
+        assert_eq!(changes[0].new_value(), "john@example.com");
+        assert!(again.is_empty());
+    }
+
+    #[test]
+    fn test_09_given_a_changed_client_when_checking_its_old_version_then_should_fail() {
+        let mut client = client_with_limit(0);
+        let read_version = *client.version();
+        assert!(client.ensure_version(Some(&read_version)).is_ok());
+
+        client.increase_version();
+
+        assert_eq!(client.version(), &read_version.next());
+        assert_eq!(
+            client.ensure_version(Some(&read_version)).err().unwrap(),
+            ClientError::VersionConflict {
+                client_id: ClientId::new("1").unwrap(),
+                expected: read_version,
+                actual: read_version.next()
+            }
+        );
+        assert!(client.ensure_version(None).is_ok());
+    }
+
+    #[test]
+    fn test_10_given_a_stored_client_when_restoring_it_then_it_should_keep_its_status_and_version()
+    {
+        let version = Version::new("7").unwrap();
+        let client = client_with_limit(0)
+            .with_status(ClientStatus::Closed)
+            .with_version(version);
+        assert_eq!(client.status(), ClientStatus::Closed);
+        assert_eq!(client.version(), &version);
+    }
+
+    #[test]
+    fn test_11_given_a_client_with_overdraft_when_debiting_another_currency_below_zero_then_should_fail()
+     {
+        let client = client_with_limit(50);
+        let usd =
+            |amount: i64| Money::new(Decimal::from(amount), Currency::new("USD").unwrap()).unwrap();
+        assert!(client.ensure_funds_for(&usd(10), &usd(-10)).is_ok());
+        assert_eq!(
+            client.ensure_funds_for(&usd(10), &usd(-11)).err().unwrap(),
+            ClientError::InsufficientFunds {
+                client_id: ClientId::new("1").unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn test_12_given_a_client_with_contacts_when_removing_them_then_the_changes_should_have_an_empty_new_value()
+     {
+        let mut client = client_with_limit(0)
+            .with_email(Some(Email::new("john@example.com").unwrap()))
+            .with_phone(Some(PhoneNumber::new("+5491123456789").unwrap()));
+        let req = UpdateClientRequest::new(ClientId::new("1").unwrap())
+            .without_email()
+            .without_phone();
+
+        let changes = client.update(&req, Utc::now());
+        let again = client.update(&req, Utc::now());
+
+        assert_eq!(client.email(), None);
+        assert_eq!(client.phone(), None);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].field(), ClientField::Email);
+        assert_eq!(changes[0].old_value(), "john@example.com");
+        assert_eq!(changes[0].new_value(), "");
+        assert_eq!(changes[1].field(), ClientField::Phone);
+        assert_eq!(changes[1].old_value(), "+5491123456789");
+        assert_eq!(changes[1].new_value(), "");
+        assert!(again.is_empty());
     }
 }
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::model::value::{client_field::ClientField, client_id::ClientId};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// An immutable entry of the audit trail of a [Client]. Every corrected detail appends one.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClientChange {
+    client_id: ClientId,
+    field: ClientField,
+    old_value: String,
+    new_value: String,
+    changed_at: DateTime<Utc>,
+}
+
+impl ClientChange {
+    pub fn new(
+        client_id: ClientId,
+        field: ClientField,
+        old_value: String,
+        new_value: String,
+        changed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            client_id,
+            field,
+            old_value,
+            new_value,
+            changed_at,
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn field(&self) -> ClientField {
+        self.field
+    }
+
+    pub fn old_value(&self) -> &str {
+        &self.old_value
+    }
+
+    pub fn new_value(&self) -> &str {
+        &self.new_value
+    }
+
+    pub fn changed_at(&self) -> &DateTime<Utc> {
+        &self.changed_at
+    }
+}
@@ -0,0 +1,36 @@
+use crate::domain::model::entity::{account::Account, balance::Balance, client::Client};
+
+/// A [Client] along with its [Balance]s, added up across its [Account]s, and each [Account] with its
+/// own. Everything is read at once, so the [Balance]s belong to the version of the [Client].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClientOverview {
+    client: Client,
+    balances: Vec<Balance>,
+    accounts: Vec<(Account, Vec<Balance>)>,
+}
+
+impl ClientOverview {
+    pub fn new(
+        client: Client,
+        balances: Vec<Balance>,
+        accounts: Vec<(Account, Vec<Balance>)>,
+    ) -> Self {
+        Self {
+            client,
+            balances,
+            accounts,
+        }
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    pub fn balances(&self) -> &[Balance] {
+        &self.balances
+    }
+
+    pub fn accounts(&self) -> &[(Account, Vec<Balance>)] {
+        &self.accounts
+    }
+}
@@ -0,0 +1,33 @@
+use crate::domain::model::{
+    entity::{balance::Balance, client::Client},
+    value::client_cursor::ClientCursor,
+};
+
+/// A page of [Client]s, each one with its [Balance] in the currency of the listing.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClientPage {
+    clients: Vec<(Client, Balance)>,
+    /// Where the next page starts. There are no more [Client]s if not set.
+    next_cursor: Option<ClientCursor>,
+}
+
+impl ClientPage {
+    pub fn new(clients: Vec<(Client, Balance)>, next_cursor: Option<ClientCursor>) -> Self {
+        Self {
+            clients,
+            next_cursor,
+        }
+    }
+
+    pub fn clients(&self) -> &[(Client, Balance)] {
+        &self.clients
+    }
+
+    pub fn next_cursor(&self) -> Option<&ClientCursor> {
+        self.next_cursor.as_ref()
+    }
+
+    pub fn into_parts(self) -> (Vec<(Client, Balance)>, Option<ClientCursor>) {
+        (self.clients, self.next_cursor)
+    }
+}
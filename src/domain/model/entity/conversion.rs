@@ -0,0 +1,73 @@
+use rust_decimal::Decimal;
+
+use crate::domain::model::value::currency::Currency;
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::{exchange_rate::ExchangeRate, transaction::Transaction};
+
+/// The record of an amount converted with an [ExchangeRate] before being applied to a balance in
+/// another [Currency]. It is kept in the [Transaction] so the applied rate can be audited later.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Conversion {
+    /// The signed amount as it was requested.
+    original_amount: Decimal,
+    original_currency: Currency,
+    /// The market rate, before the spread.
+    rate: Decimal,
+    spread: Decimal,
+    /// The rate actually used to convert, spread included.
+    applied_rate: Decimal,
+    /// The signed amount applied to the balance.
+    converted_amount: Decimal,
+    converted_currency: Currency,
+}
+
+impl Conversion {
+    pub fn new(
+        original_amount: Decimal,
+        original_currency: Currency,
+        rate: Decimal,
+        spread: Decimal,
+        applied_rate: Decimal,
+        converted_amount: Decimal,
+        converted_currency: Currency,
+    ) -> Self {
+        Self {
+            original_amount,
+            original_currency,
+            rate,
+            spread,
+            applied_rate,
+            converted_amount,
+            converted_currency,
+        }
+    }
+
+    pub fn original_amount(&self) -> &Decimal {
+        &self.original_amount
+    }
+
+    pub fn original_currency(&self) -> &Currency {
+        &self.original_currency
+    }
+
+    pub fn rate(&self) -> &Decimal {
+        &self.rate
+    }
+
+    pub fn spread(&self) -> &Decimal {
+        &self.spread
+    }
+
+    pub fn applied_rate(&self) -> &Decimal {
+        &self.applied_rate
+    }
+
+    pub fn converted_amount(&self) -> &Decimal {
+        &self.converted_amount
+    }
+
+    pub fn converted_currency(&self) -> &Currency {
+        &self.converted_currency
+    }
+}
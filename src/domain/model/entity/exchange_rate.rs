@@ -0,0 +1,222 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::domain::model::{
+    entity::conversion::Conversion,
+    error::ClientError,
+    value::{currency::Currency, money::Money},
+};
+
+/// The price of one unit of a [Currency] in another one, and the spread charged over it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExchangeRate {
+    from: Currency,
+    to: Currency,
+    /// How many units of `to` one unit of `from` is worth. Always positive.
+    rate: Decimal,
+    /// The fraction of the rate charged on every conversion, between 0 (inclusive) and 1 (exclusive).
+    spread: Decimal,
+}
+
+impl ExchangeRate {
+    pub fn new(
+        from: Currency,
+        to: Currency,
+        rate: Decimal,
+        spread: Decimal,
+    ) -> Result<Self, ClientError> {
+        if rate <= Decimal::ZERO {
+            return Err(ClientError::FieldInvalid {
+                field_name: "rate".to_string(),
+                value: rate.to_string(),
+            });
+        }
+
+        if spread < Decimal::ZERO || spread >= Decimal::ONE {
+            return Err(ClientError::FieldInvalid {
+                field_name: "spread".to_string(),
+                value: spread.to_string(),
+            });
+        }
+
+        Ok(Self {
+            from,
+            to,
+            rate,
+            spread,
+        })
+    }
+
+    pub fn from(&self) -> &Currency {
+        &self.from
+    }
+
+    pub fn to(&self) -> &Currency {
+        &self.to
+    }
+
+    pub fn rate(&self) -> &Decimal {
+        &self.rate
+    }
+
+    pub fn spread(&self) -> &Decimal {
+        &self.spread
+    }
+
+    /// Converts a signed amount in `from` into `to`. The spread always plays against the client:
+    /// a debit (negative amount) is charged at `rate * (1 + spread)` and a credit (positive
+    /// amount) is paid at `rate * (1 - spread)`. The result is rounded to the decimal places of `to`,
+    /// also against the client: credits are rounded down and debits up, in absolute value.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::FieldInvalid] if the amount has more decimal places than `from` allows.
+    /// - [ClientError::ZeroAmount] if a credit is so small that it rounds to zero in `to`.
+    /// - [ClientError::AmountOverflow] if the converted amount is out of range.
+    pub fn convert(&self, amount: &Decimal) -> Result<Conversion, ClientError> {
+        Money::new(*amount, self.from.clone())?;
+        let spread = if amount.is_sign_negative() {
+            Decimal::ONE + self.spread
+        } else {
+            Decimal::ONE - self.spread
+        };
+        let applied_rate = self
+            .rate
+            .checked_mul(spread)
+            .ok_or(ClientError::AmountOverflow)?;
+        let converted_amount = amount
+            .checked_mul(applied_rate)
+            .ok_or(ClientError::AmountOverflow)?
+            .round_dp_with_strategy(self.to.max_scale(), RoundingStrategy::ToNegativeInfinity);
+        if converted_amount.is_zero() {
+            return Err(ClientError::ZeroAmount);
+        }
+        Ok(Conversion::new(
+            *amount,
+            self.from.clone(),
+            self.rate,
+            self.spread,
+            applied_rate,
+            converted_amount,
+            self.to.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd_to_ars() -> ExchangeRate {
+        ExchangeRate::new(
+            Currency::new("USD").unwrap(),
+            Currency::new("ARS").unwrap(),
+            Decimal::from(1000),
+            Decimal::new(2, 2),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_01_given_a_non_positive_rate_or_an_invalid_spread_when_creating_exchange_rate_then_it_should_fail()
+     {
+        let usd = Currency::new("USD").unwrap();
+        let ars = Currency::new("ARS").unwrap();
+        let result = ExchangeRate::new(usd.clone(), ars.clone(), Decimal::ZERO, Decimal::ZERO);
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "rate".to_string(),
+                value: "0".to_string()
+            }
+        );
+        let result = ExchangeRate::new(usd, ars, Decimal::ONE, Decimal::ONE);
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "spread".to_string(),
+                value: "1".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_02_given_a_debit_when_converting_then_it_should_charge_the_spread_over_the_rate() {
+        let conversion = usd_to_ars().convert(&Decimal::from(-10)).unwrap();
+        assert_eq!(conversion.applied_rate(), &Decimal::from(1020));
+        assert_eq!(conversion.converted_amount(), &Decimal::from(-10200));
+        assert_eq!(conversion.original_amount(), &Decimal::from(-10));
+        assert_eq!(
+            conversion.original_currency(),
+            &Currency::new("USD").unwrap()
+        );
+        assert_eq!(
+            conversion.converted_currency(),
+            &Currency::new("ARS").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_03_given_a_credit_when_converting_then_it_should_discount_the_spread_from_the_rate() {
+        let conversion = usd_to_ars().convert(&Decimal::from(10)).unwrap();
+        assert_eq!(conversion.applied_rate(), &Decimal::from(980));
+        assert_eq!(conversion.converted_amount(), &Decimal::from(9800));
+        assert_eq!(conversion.rate(), &Decimal::from(1000));
+        assert_eq!(conversion.spread(), &Decimal::new(2, 2));
+    }
+
+    #[test]
+    fn test_04_given_a_converted_amount_with_more_decimals_than_the_target_when_converting_then_it_should_be_rounded()
+     {
+        let usd_to_jpy = ExchangeRate::new(
+            Currency::new("USD").unwrap(),
+            Currency::new("JPY").unwrap(),
+            Decimal::new(15025, 2),
+            Decimal::ZERO,
+        )
+        .unwrap();
+        let conversion = usd_to_jpy.convert(&Decimal::new(1001, 2)).unwrap();
+        assert_eq!(conversion.converted_amount(), &Decimal::from(1504));
+        assert!(usd_to_jpy.convert(&Decimal::new(1, 3)).is_err());
+    }
+
+    #[test]
+    fn test_05_given_a_huge_amount_when_converting_then_it_should_overflow_without_panicking() {
+        assert_eq!(
+            usd_to_ars().convert(&Decimal::MAX).err().unwrap(),
+            ClientError::AmountOverflow
+        );
+    }
+
+    #[test]
+    fn test_06_given_an_amount_with_more_decimals_than_the_target_when_converting_then_it_should_round_against_the_client()
+     {
+        let usd_to_jpy = ExchangeRate::new(
+            Currency::new("USD").unwrap(),
+            Currency::new("JPY").unwrap(),
+            Decimal::new(15025, 2),
+            Decimal::ZERO,
+        )
+        .unwrap();
+        let credit = usd_to_jpy.convert(&Decimal::new(1001, 2)).unwrap();
+        assert_eq!(credit.converted_amount(), &Decimal::from(1504));
+        let debit = usd_to_jpy.convert(&Decimal::new(-1001, 2)).unwrap();
+        assert_eq!(debit.converted_amount(), &Decimal::from(-1505));
+    }
+
+    #[test]
+    fn test_07_given_a_credit_that_rounds_to_zero_when_converting_then_it_should_fail() {
+        let ars_to_usd = ExchangeRate::new(
+            Currency::new("ARS").unwrap(),
+            Currency::new("USD").unwrap(),
+            Decimal::new(1, 3),
+            Decimal::ZERO,
+        )
+        .unwrap();
+        assert_eq!(
+            ars_to_usd.convert(&Decimal::new(1, 2)).err().unwrap(),
+            ClientError::ZeroAmount
+        );
+        let debit = ars_to_usd.convert(&Decimal::new(-1, 2)).unwrap();
+        assert_eq!(debit.converted_amount(), &Decimal::new(-1, 2));
+    }
+}
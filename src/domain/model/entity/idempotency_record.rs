@@ -0,0 +1,167 @@
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+
+use crate::domain::model::{
+    entity::balance::Balance,
+    value::{
+        client_id::ClientId, currency::Currency, idempotency_key::IdempotencyKey,
+        transaction_kind::TransactionKind,
+    },
+};
+
+/// The outcome of a transaction applied with an [IdempotencyKey], kept to answer retries of the same request.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IdempotencyRecord {
+    key: IdempotencyKey,
+    client_id: ClientId,
+    amount: Decimal,
+    /// The currency of the requested amount, which is not the one of the [Balance] if it was converted.
+    currency: Currency,
+    kind: TransactionKind,
+    /// The [Balance] returned the first time the request was applied.
+    balance: Balance,
+    created_at: DateTime<Utc>,
+}
+
+impl IdempotencyRecord {
+    pub fn new(
+        key: IdempotencyKey,
+        client_id: ClientId,
+        amount: Decimal,
+        currency: Currency,
+        kind: TransactionKind,
+        balance: Balance,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            key,
+            client_id,
+            amount,
+            currency,
+            kind,
+            balance,
+            created_at,
+        }
+    }
+
+    pub fn key(&self) -> &IdempotencyKey {
+        &self.key
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn amount(&self) -> &Decimal {
+        &self.amount
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn kind(&self) -> TransactionKind {
+        self.kind
+    }
+
+    pub fn balance(&self) -> &Balance {
+        &self.balance
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns if the given payload is the same one that was recorded with the key.
+    pub fn matches(
+        &self,
+        client_id: &ClientId,
+        amount: &Decimal,
+        currency: &Currency,
+        kind: TransactionKind,
+    ) -> bool {
+        &self.client_id == client_id
+            && &self.amount == amount
+            && &self.currency == currency
+            && self.kind == kind
+    }
+
+    /// Returns if the record is older than the given window, so the key can be used again.
+    pub fn is_expired(&self, now: DateTime<Utc>, window: Duration) -> bool {
+        now - self.created_at >= window
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(created_at: DateTime<Utc>) -> IdempotencyRecord {
+        let client_id = ClientId::new("1").unwrap();
+        IdempotencyRecord::new(
+            IdempotencyKey::new("retry-1").unwrap(),
+            client_id.clone(),
+            Decimal::from(-100),
+            Currency::default(),
+            TransactionKind::Debit,
+            Balance::new(client_id, Decimal::from(-100)),
+            created_at,
+        )
+    }
+
+    #[test]
+    fn test_01_given_the_same_payload_when_matching_then_it_should_match() {
+        let record = record(Utc::now());
+        assert!(record.matches(
+            &ClientId::new("1").unwrap(),
+            &Decimal::from(-100),
+            &Currency::default(),
+            TransactionKind::Debit
+        ));
+    }
+
+    #[test]
+    fn test_02_given_a_different_payload_when_matching_then_it_should_not_match() {
+        let record = record(Utc::now());
+        assert!(!record.matches(
+            &ClientId::new("2").unwrap(),
+            &Decimal::from(-100),
+            &Currency::default(),
+            TransactionKind::Debit
+        ));
+        assert!(!record.matches(
+            &ClientId::new("1").unwrap(),
+            &Decimal::from(-50),
+            &Currency::default(),
+            TransactionKind::Debit
+        ));
+        assert!(!record.matches(
+            &ClientId::new("1").unwrap(),
+            &Decimal::from(-100),
+            &Currency::default(),
+            TransactionKind::Credit
+        ));
+        assert!(!record.matches(
+            &ClientId::new("1").unwrap(),
+            &Decimal::from(-100),
+            &Currency::new("USD").unwrap(),
+            TransactionKind::Debit
+        ));
+    }
+
+    #[test]
+    fn test_03_given_a_record_inside_the_window_when_checking_expiration_then_it_should_not_be_expired()
+     {
+        let now = Utc::now();
+        let record = record(now - Duration::seconds(59));
+        assert!(!record.is_expired(now, Duration::seconds(60)));
+    }
+
+    #[test]
+    fn test_04_given_a_record_outside_the_window_when_checking_expiration_then_it_should_be_expired()
+     {
+        let now = Utc::now();
+        let record = record(now - Duration::seconds(60));
+        assert!(record.is_expired(now, Duration::seconds(60)));
+    }
+}
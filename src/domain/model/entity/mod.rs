@@ -1,2 +1,12 @@
+pub mod account;
+pub mod authorization;
 pub mod balance;
+pub mod balance_reset;
 pub mod client;
+pub mod client_change;
+pub mod client_overview;
+pub mod client_page;
+pub mod conversion;
+pub mod exchange_rate;
+pub mod idempotency_record;
+pub mod transaction;
@@ -0,0 +1,283 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::domain::model::{
+    entity::conversion::Conversion,
+    error::ClientError,
+    value::{
+        account_id::AccountId, client_id::ClientId, currency::Currency,
+        transaction_id::TransactionId, transaction_kind::TransactionKind,
+    },
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// An immutable entry of the ledger of a [Client]. Every credit and debit appends one.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Transaction {
+    id: TransactionId,
+    client_id: ClientId,
+    /// The account of the [Client] the amount was applied to.
+    account_id: Option<AccountId>,
+    /// The signed amount applied to the balance. Positive for credits and negative for debits.
+    amount: Decimal,
+    kind: TransactionKind,
+    created_at: DateTime<Utc>,
+    /// The balance of the [Client] right after applying the amount.
+    resulting_balance: Decimal,
+    /// The [Transaction] this one compensates, if it is a reversal.
+    reversal_of: Option<TransactionId>,
+    currency: Currency,
+    /// How the amount was converted, if it was requested in another [Currency].
+    conversion: Option<Conversion>,
+}
+
+impl Transaction {
+    pub fn new(
+        id: TransactionId,
+        client_id: ClientId,
+        amount: Decimal,
+        kind: TransactionKind,
+        created_at: DateTime<Utc>,
+        resulting_balance: Decimal,
+    ) -> Self {
+        Self {
+            id,
+            client_id,
+            account_id: None,
+            amount,
+            kind,
+            created_at,
+            resulting_balance,
+            reversal_of: None,
+            currency: Currency::default(),
+            conversion: None,
+        }
+    }
+
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    pub fn with_conversion(mut self, conversion: Conversion) -> Self {
+        self.conversion = Some(conversion);
+        self
+    }
+
+    pub fn with_reversal_of(mut self, transaction_id: TransactionId) -> Self {
+        self.reversal_of = Some(transaction_id);
+        self
+    }
+
+    pub fn with_account_id(mut self, account_id: AccountId) -> Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Checks that the signed amount can compensate this [Transaction]: only plain credits and
+    /// debits of the same [Client] and [Currency] can be reversed, with the opposite sign and up to
+    /// the original amount. Whether it was already reversed depends on the ledger, so it is not
+    /// checked here.
+    pub fn ensure_reversible_by(
+        &self,
+        client_id: &ClientId,
+        amount: &Decimal,
+        currency: &Currency,
+    ) -> Result<(), ClientError> {
+        let reversible_kind = matches!(self.kind, TransactionKind::Credit | TransactionKind::Debit);
+        let opposite_sign = amount.is_sign_negative() != self.amount.is_sign_negative();
+        if !reversible_kind
+            || self.reversal_of.is_some()
+            || &self.client_id != client_id
+            || &self.currency != currency
+            || !opposite_sign
+        {
+            return Err(ClientError::TransactionNotReversible {
+                transaction_id: self.id.clone(),
+            });
+        }
+
+        if amount.abs() > self.amount.abs() {
+            return Err(ClientError::ReversalExceedsOriginal {
+                transaction_id: self.id.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn id(&self) -> &TransactionId {
+        &self.id
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn account_id(&self) -> Option<&AccountId> {
+        self.account_id.as_ref()
+    }
+
+    pub fn amount(&self) -> &Decimal {
+        &self.amount
+    }
+
+    pub fn kind(&self) -> &TransactionKind {
+        &self.kind
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn resulting_balance(&self) -> &Decimal {
+        &self.resulting_balance
+    }
+
+    pub fn reversal_of(&self) -> Option<&TransactionId> {
+        self.reversal_of.as_ref()
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn conversion(&self) -> Option<&Conversion> {
+        self.conversion.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_valid_data_when_creating_transaction_then_fields_should_be_accessible() {
+        let id = TransactionId::new("1").unwrap();
+        let client_id = ClientId::new("7").unwrap();
+        let created_at = Utc::now();
+        let transaction = Transaction::new(
+            id.clone(),
+            client_id.clone(),
+            Decimal::from(-30),
+            TransactionKind::Debit,
+            created_at,
+            Decimal::from(70),
+        );
+        assert_eq!(transaction.id(), &id);
+        assert_eq!(transaction.client_id(), &client_id);
+        assert_eq!(transaction.amount(), &Decimal::from(-30));
+        assert_eq!(transaction.kind(), &TransactionKind::Debit);
+        assert_eq!(transaction.created_at(), &created_at);
+        assert_eq!(transaction.resulting_balance(), &Decimal::from(70));
+        assert_eq!(transaction.reversal_of(), None);
+    }
+
+    fn debit_of_30() -> Transaction {
+        Transaction::new(
+            TransactionId::new("1").unwrap(),
+            ClientId::new("7").unwrap(),
+            Decimal::from(-30),
+            TransactionKind::Debit,
+            Utc::now(),
+            Decimal::from(70),
+        )
+    }
+
+    #[test]
+    fn test_02_given_a_debit_when_reversing_it_partially_or_fully_then_it_should_be_ok() {
+        let transaction = debit_of_30();
+        let client_id = ClientId::new("7").unwrap();
+        assert!(
+            transaction
+                .ensure_reversible_by(&client_id, &Decimal::from(10), &Currency::default())
+                .is_ok()
+        );
+        assert!(
+            transaction
+                .ensure_reversible_by(&client_id, &Decimal::from(30), &Currency::default())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_03_given_a_debit_when_reversing_it_with_more_than_its_amount_then_it_should_fail() {
+        let transaction = debit_of_30();
+        let result = transaction.ensure_reversible_by(
+            &ClientId::new("7").unwrap(),
+            &Decimal::from(31),
+            &Currency::default(),
+        );
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::ReversalExceedsOriginal {
+                transaction_id: TransactionId::new("1").unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn test_04_given_a_debit_when_reversing_it_with_the_same_sign_or_another_client_or_currency_then_it_should_fail()
+     {
+        let transaction = debit_of_30();
+        let not_reversible = ClientError::TransactionNotReversible {
+            transaction_id: TransactionId::new("1").unwrap(),
+        };
+        let result = transaction.ensure_reversible_by(
+            &ClientId::new("7").unwrap(),
+            &Decimal::from(-10),
+            &Currency::default(),
+        );
+        assert_eq!(result.err().unwrap(), not_reversible);
+        let result = transaction.ensure_reversible_by(
+            &ClientId::new("8").unwrap(),
+            &Decimal::from(10),
+            &Currency::default(),
+        );
+        assert_eq!(result.err().unwrap(), not_reversible);
+        let result = transaction.ensure_reversible_by(
+            &ClientId::new("7").unwrap(),
+            &Decimal::from(10),
+            &Currency::new("USD").unwrap(),
+        );
+        assert_eq!(result.err().unwrap(), not_reversible);
+    }
+
+    #[test]
+    fn test_05_given_a_reversal_or_a_transfer_when_reversing_it_then_it_should_fail() {
+        let client_id = ClientId::new("7").unwrap();
+        let reversal = Transaction::new(
+            TransactionId::new("2").unwrap(),
+            client_id.clone(),
+            Decimal::from(30),
+            TransactionKind::Credit,
+            Utc::now(),
+            Decimal::from(100),
+        )
+        .with_reversal_of(TransactionId::new("1").unwrap());
+        assert_eq!(
+            reversal.reversal_of(),
+            Some(&TransactionId::new("1").unwrap())
+        );
+        assert!(
+            reversal
+                .ensure_reversible_by(&client_id, &Decimal::from(-30), &Currency::default())
+                .is_err()
+        );
+        let transfer = Transaction::new(
+            TransactionId::new("3").unwrap(),
+            client_id.clone(),
+            Decimal::from(-30),
+            TransactionKind::TransferOut,
+            Utc::now(),
+            Decimal::from(70),
+        );
+        assert!(
+            transfer
+                .ensure_reversible_by(&client_id, &Decimal::from(30), &Currency::default())
+                .is_err()
+        );
+    }
+}
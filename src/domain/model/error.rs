@@ -1,6 +1,11 @@
 use thiserror::Error;
 
-use crate::domain::model::value::{client_id::ClientId, document::Document};
+use crate::domain::model::value::{
+    account_id::AccountId, authorization_id::AuthorizationId, birth_date::BirthDate,
+    client_id::ClientId, client_status::ClientStatus, country::Country, currency::Currency,
+    document::Document, idempotency_key::IdempotencyKey, transaction_id::TransactionId,
+    version::Version,
+};
 
 #[derive(Debug, Error)]
 pub enum ClientError {
@@ -37,6 +42,92 @@ pub enum ClientError {
     #[error("balances are empty")]
     BalancesEmpty,
 
+    #[error("client {client_id} cannot transfer to itself")]
+    SelfTransfer { client_id: ClientId },
+
+    #[error("idempotency key {key} was already used with a different request")]
+    IdempotencyKeyConflict { key: IdempotencyKey },
+
+    #[error("client {client_id} has insufficient funds")]
+    InsufficientFunds { client_id: ClientId },
+
+    #[error("transaction {transaction_id} not found")]
+    TransactionNotFound { transaction_id: TransactionId },
+
+    #[error("transaction {transaction_id} was already reversed")]
+    TransactionAlreadyReversed { transaction_id: TransactionId },
+
+    #[error("transaction {transaction_id} cannot be reversed")]
+    TransactionNotReversible { transaction_id: TransactionId },
+
+    #[error("reversal amount exceeds the amount of transaction {transaction_id}")]
+    ReversalExceedsOriginal { transaction_id: TransactionId },
+
+    #[error("authorization {authorization_id} not found")]
+    AuthorizationNotFound { authorization_id: AuthorizationId },
+
+    #[error("authorization {authorization_id} is no longer pending")]
+    AuthorizationNotPending { authorization_id: AuthorizationId },
+
+    #[error("authorization {authorization_id} has expired")]
+    AuthorizationExpired { authorization_id: AuthorizationId },
+
+    #[error("capture amount exceeds the amount of authorization {authorization_id}")]
+    CaptureExceedsAuthorization { authorization_id: AuthorizationId },
+
+    #[error("exchange rate from {from} to {to} not found")]
+    ExchangeRateNotFound { from: Currency, to: Currency },
+
+    #[error("batch cannot be empty")]
+    BatchEmpty,
+
+    #[error("batch item {index} was rejected: {reason}")]
+    BatchItemRejected {
+        index: usize,
+        reason: Box<ClientError>,
+    },
+
+    #[error("amount is out of the supported range")]
+    AmountOverflow,
+
+    #[error("client {client_id} is blocked")]
+    ClientBlocked { client_id: ClientId },
+
+    #[error("client {client_id} is closed")]
+    ClientClosed { client_id: ClientId },
+
+    #[error("client {client_id} cannot change from {from} to {to}")]
+    InvalidStatusTransition {
+        client_id: ClientId,
+        from: ClientStatus,
+        to: ClientStatus,
+    },
+
+    #[error("client {client_id} cannot be closed with a non-zero balance")]
+    BalanceNotZero { client_id: ClientId },
+
+    #[error("client birth_date {birth_date} is in the future")]
+    BirthDateInFuture { birth_date: BirthDate },
+
+    #[error("client birth_date {birth_date} is older than the maximum age of {max_age}")]
+    BirthDateImplausible { birth_date: BirthDate, max_age: u32 },
+
+    #[error("client is younger than the minimum age of {min_age} for {country}")]
+    UnderMinimumAge { country: Country, min_age: u32 },
+
+    #[error("client with {field_name} {value} already exists")]
+    DuplicateContact { field_name: String, value: String },
+
+    #[error("account {account_id} not found")]
+    AccountNotFound { account_id: AccountId },
+
+    #[error("client {client_id} is at version {actual}, not {expected}")]
+    VersionConflict {
+        client_id: ClientId,
+        expected: Version,
+        actual: Version,
+    },
+
     #[error(transparent)]
     Unknown(#[from] anyhow::Error),
 }
@@ -83,6 +174,156 @@ impl PartialEq for ClientError {
             (ClientError::PositiveAmount, ClientError::PositiveAmount) => true,
             (ClientError::ZeroAmount, ClientError::ZeroAmount) => true,
             (ClientError::BalancesEmpty, ClientError::BalancesEmpty) => true,
+            (
+                ClientError::SelfTransfer { client_id: id1 },
+                ClientError::SelfTransfer { client_id: id2 },
+            ) => id1 == id2,
+            (
+                ClientError::IdempotencyKeyConflict { key: k1 },
+                ClientError::IdempotencyKeyConflict { key: k2 },
+            ) => k1 == k2,
+            (
+                ClientError::InsufficientFunds { client_id: c1 },
+                ClientError::InsufficientFunds { client_id: c2 },
+            ) => c1 == c2,
+            (
+                ClientError::TransactionNotFound { transaction_id: t1 },
+                ClientError::TransactionNotFound { transaction_id: t2 },
+            ) => t1 == t2,
+            (
+                ClientError::TransactionAlreadyReversed { transaction_id: t1 },
+                ClientError::TransactionAlreadyReversed { transaction_id: t2 },
+            ) => t1 == t2,
+            (
+                ClientError::TransactionNotReversible { transaction_id: t1 },
+                ClientError::TransactionNotReversible { transaction_id: t2 },
+            ) => t1 == t2,
+            (
+                ClientError::ReversalExceedsOriginal { transaction_id: t1 },
+                ClientError::ReversalExceedsOriginal { transaction_id: t2 },
+            ) => t1 == t2,
+            (
+                ClientError::AuthorizationNotFound {
+                    authorization_id: a1,
+                },
+                ClientError::AuthorizationNotFound {
+                    authorization_id: a2,
+                },
+            ) => a1 == a2,
+            (
+                ClientError::AuthorizationNotPending {
+                    authorization_id: a1,
+                },
+                ClientError::AuthorizationNotPending {
+                    authorization_id: a2,
+                },
+            ) => a1 == a2,
+            (
+                ClientError::AuthorizationExpired {
+                    authorization_id: a1,
+                },
+                ClientError::AuthorizationExpired {
+                    authorization_id: a2,
+                },
+            ) => a1 == a2,
+            (
+                ClientError::CaptureExceedsAuthorization {
+                    authorization_id: a1,
+                },
+                ClientError::CaptureExceedsAuthorization {
+                    authorization_id: a2,
+                },
+            ) => a1 == a2,
+            (
+                ClientError::ExchangeRateNotFound { from: f1, to: t1 },
+                ClientError::ExchangeRateNotFound { from: f2, to: t2 },
+            ) => f1 == f2 && t1 == t2,
+            (ClientError::BatchEmpty, ClientError::BatchEmpty) => true,
+            (
+                ClientError::BatchItemRejected {
+                    index: i1,
+                    reason: r1,
+                },
+                ClientError::BatchItemRejected {
+                    index: i2,
+                    reason: r2,
+                },
+            ) => i1 == i2 && r1 == r2,
+            (ClientError::AmountOverflow, ClientError::AmountOverflow) => true,
+            (
+                ClientError::ClientBlocked { client_id: c1 },
+                ClientError::ClientBlocked { client_id: c2 },
+            ) => c1 == c2,
+            (
+                ClientError::ClientClosed { client_id: c1 },
+                ClientError::ClientClosed { client_id: c2 },
+            ) => c1 == c2,
+            (
+                ClientError::InvalidStatusTransition {
+                    client_id: c1,
+                    from: f1,
+                    to: t1,
+                },
+                ClientError::InvalidStatusTransition {
+                    client_id: c2,
+                    from: f2,
+                    to: t2,
+                },
+            ) => c1 == c2 && f1 == f2 && t1 == t2,
+            (
+                ClientError::BalanceNotZero { client_id: c1 },
+                ClientError::BalanceNotZero { client_id: c2 },
+            ) => c1 == c2,
+            (
+                ClientError::BirthDateInFuture { birth_date: b1 },
+                ClientError::BirthDateInFuture { birth_date: b2 },
+            ) => b1 == b2,
+            (
+                ClientError::BirthDateImplausible {
+                    birth_date: b1,
+                    max_age: m1,
+                },
+                ClientError::BirthDateImplausible {
+                    birth_date: b2,
+                    max_age: m2,
+                },
+            ) => b1 == b2 && m1 == m2,
+            (
+                ClientError::UnderMinimumAge {
+                    country: c1,
+                    min_age: m1,
+                },
+                ClientError::UnderMinimumAge {
+                    country: c2,
+                    min_age: m2,
+                },
+            ) => c1 == c2 && m1 == m2,
+            (
+                ClientError::DuplicateContact {
+                    field_name: f1,
+                    value: v1,
+                },
+                ClientError::DuplicateContact {
+                    field_name: f2,
+                    value: v2,
+                },
+            ) => f1 == f2 && v1 == v2,
+            (
+                ClientError::AccountNotFound { account_id: a1 },
+                ClientError::AccountNotFound { account_id: a2 },
+            ) => a1 == a2,
+            (
+                ClientError::VersionConflict {
+                    client_id: c1,
+                    expected: e1,
+                    actual: a1,
+                },
+                ClientError::VersionConflict {
+                    client_id: c2,
+                    expected: e2,
+                    actual: a2,
+                },
+            ) => c1 == c2 && e1 == e2 && a1 == a2,
             (ClientError::Unknown(_), ClientError::Unknown(_)) => true,
             _ => false,
         }
@@ -111,6 +352,55 @@ impl ClientError {
             ClientError::PositiveAmount => "CLIENT_POSITIVE_BALANCE".to_string(),
             ClientError::ZeroAmount => "CLIENT_ZERO_BALANCE".to_string(),
             ClientError::BalancesEmpty => "CLIENT_BALANCES_EMPTY".to_string(),
+            ClientError::SelfTransfer { .. } => "CLIENT_SELF_TRANSFER".to_string(),
+            ClientError::IdempotencyKeyConflict { .. } => {
+                "CLIENT_IDEMPOTENCY_KEY_CONFLICT".to_string()
+            }
+            ClientError::InsufficientFunds { .. } => "CLIENT_INSUFFICIENT_FUNDS".to_string(),
+            ClientError::TransactionNotFound { .. } => "CLIENT_TRANSACTION_NOT_FOUND".to_string(),
+            ClientError::TransactionAlreadyReversed { .. } => {
+                "CLIENT_TRANSACTION_ALREADY_REVERSED".to_string()
+            }
+            ClientError::TransactionNotReversible { .. } => {
+                "CLIENT_TRANSACTION_NOT_REVERSIBLE".to_string()
+            }
+            ClientError::ReversalExceedsOriginal { .. } => {
+                "CLIENT_REVERSAL_EXCEEDS_ORIGINAL".to_string()
+            }
+            ClientError::AuthorizationNotFound { .. } => {
+                "CLIENT_AUTHORIZATION_NOT_FOUND".to_string()
+            }
+            ClientError::AuthorizationNotPending { .. } => {
+                "CLIENT_AUTHORIZATION_NOT_PENDING".to_string()
+            }
+            ClientError::AuthorizationExpired { .. } => "CLIENT_AUTHORIZATION_EXPIRED".to_string(),
+            ClientError::CaptureExceedsAuthorization { .. } => {
+                "CLIENT_CAPTURE_EXCEEDS_AUTHORIZATION".to_string()
+            }
+            ClientError::ExchangeRateNotFound { .. } => {
+                "CLIENT_EXCHANGE_RATE_NOT_FOUND".to_string()
+            }
+            ClientError::BatchEmpty => "CLIENT_BATCH_EMPTY".to_string(),
+            // The index is reported apart, so the code stays the one of the rejected item.
+            ClientError::BatchItemRejected { reason, .. } => reason.code(),
+            ClientError::AmountOverflow => "CLIENT_AMOUNT_OVERFLOW".to_string(),
+            ClientError::ClientBlocked { .. } => "CLIENT_BLOCKED".to_string(),
+            ClientError::ClientClosed { .. } => "CLIENT_CLOSED".to_string(),
+            ClientError::InvalidStatusTransition { .. } => {
+                "CLIENT_INVALID_STATUS_TRANSITION".to_string()
+            }
+            ClientError::BalanceNotZero { .. } => "CLIENT_BALANCE_NOT_ZERO".to_string(),
+            ClientError::BirthDateInFuture { .. } => "CLIENT_BIRTH_DATE_IN_FUTURE".to_string(),
+            ClientError::BirthDateImplausible { .. } => "CLIENT_BIRTH_DATE_IMPLAUSIBLE".to_string(),
+            ClientError::UnderMinimumAge { .. } => "CLIENT_UNDER_MINIMUM_AGE".to_string(),
+            ClientError::DuplicateContact {
+                field_name,
+                value: _,
+            } => {
+                format!("CLIENT_{}_DUPLICATE", field_name.to_uppercase())
+            }
+            ClientError::AccountNotFound { .. } => "CLIENT_ACCOUNT_NOT_FOUND".to_string(),
+            ClientError::VersionConflict { .. } => "CLIENT_VERSION_CONFLICT".to_string(),
             ClientError::Unknown(_) => "CLIENT_UNKNOWN_ERROR".to_string(),
         }
     }
@@ -119,7 +409,12 @@ impl ClientError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::model::value::{client_id::ClientId, document::Document};
+    use crate::domain::model::value::document_type::DocumentType;
+    use crate::domain::model::value::{
+        account_id::AccountId, authorization_id::AuthorizationId, birth_date::BirthDate,
+        client_id::ClientId, country::Country, currency::Currency, document::Document,
+        idempotency_key::IdempotencyKey, transaction_id::TransactionId, version::Version,
+    };
     use anyhow::anyhow;
 
     #[test]
@@ -262,7 +557,7 @@ mod tests {
         // GIVEN
         let doc = "123".to_string();
         let id = ClientId::new("1").unwrap();
-        let d = Document::new("A").unwrap();
+        let d = Document::new(DocumentType::Passport, "A12345").unwrap();
         // THEN
         assert_eq!(
             ClientError::Duplicate {
@@ -318,6 +613,177 @@ mod tests {
         );
         assert_eq!(ClientError::ZeroAmount.code(), "CLIENT_ZERO_BALANCE");
         assert_eq!(ClientError::BalancesEmpty.code(), "CLIENT_BALANCES_EMPTY");
+        assert_eq!(
+            ClientError::SelfTransfer {
+                client_id: id.clone()
+            }
+            .code(),
+            "CLIENT_SELF_TRANSFER"
+        );
+        assert_eq!(
+            ClientError::IdempotencyKeyConflict {
+                key: IdempotencyKey::new("retry-1").unwrap()
+            }
+            .code(),
+            "CLIENT_IDEMPOTENCY_KEY_CONFLICT"
+        );
+        assert_eq!(
+            ClientError::InsufficientFunds {
+                client_id: id.clone()
+            }
+            .code(),
+            "CLIENT_INSUFFICIENT_FUNDS"
+        );
+        let t = TransactionId::new("3").unwrap();
+        assert_eq!(
+            ClientError::TransactionNotFound {
+                transaction_id: t.clone()
+            }
+            .code(),
+            "CLIENT_TRANSACTION_NOT_FOUND"
+        );
+        assert_eq!(
+            ClientError::TransactionAlreadyReversed {
+                transaction_id: t.clone()
+            }
+            .code(),
+            "CLIENT_TRANSACTION_ALREADY_REVERSED"
+        );
+        assert_eq!(
+            ClientError::TransactionNotReversible {
+                transaction_id: t.clone()
+            }
+            .code(),
+            "CLIENT_TRANSACTION_NOT_REVERSIBLE"
+        );
+        assert_eq!(
+            ClientError::ReversalExceedsOriginal { transaction_id: t }.code(),
+            "CLIENT_REVERSAL_EXCEEDS_ORIGINAL"
+        );
+        let a = AuthorizationId::new("4").unwrap();
+        assert_eq!(
+            ClientError::AuthorizationNotFound {
+                authorization_id: a.clone()
+            }
+            .code(),
+            "CLIENT_AUTHORIZATION_NOT_FOUND"
+        );
+        assert_eq!(
+            ClientError::AuthorizationNotPending {
+                authorization_id: a.clone()
+            }
+            .code(),
+            "CLIENT_AUTHORIZATION_NOT_PENDING"
+        );
+        assert_eq!(
+            ClientError::AuthorizationExpired {
+                authorization_id: a.clone()
+            }
+            .code(),
+            "CLIENT_AUTHORIZATION_EXPIRED"
+        );
+        assert_eq!(
+            ClientError::CaptureExceedsAuthorization {
+                authorization_id: a
+            }
+            .code(),
+            "CLIENT_CAPTURE_EXCEEDS_AUTHORIZATION"
+        );
+        let usd = Currency::new("USD").unwrap();
+        let ars = Currency::new("ARS").unwrap();
+        assert_eq!(
+            ClientError::ExchangeRateNotFound {
+                from: usd.clone(),
+                to: ars.clone()
+            }
+            .code(),
+            "CLIENT_EXCHANGE_RATE_NOT_FOUND"
+        );
+        assert_eq!(ClientError::BatchEmpty.code(), "CLIENT_BATCH_EMPTY");
+        assert_eq!(ClientError::AmountOverflow.code(), "CLIENT_AMOUNT_OVERFLOW");
+        assert_eq!(
+            ClientError::BatchItemRejected {
+                index: 3,
+                reason: Box::new(ClientError::ZeroAmount)
+            }
+            .code(),
+            "CLIENT_ZERO_BALANCE"
+        );
+        assert_eq!(
+            ClientError::ClientBlocked {
+                client_id: id.clone()
+            }
+            .code(),
+            "CLIENT_BLOCKED"
+        );
+        assert_eq!(
+            ClientError::ClientClosed {
+                client_id: id.clone()
+            }
+            .code(),
+            "CLIENT_CLOSED"
+        );
+        assert_eq!(
+            ClientError::InvalidStatusTransition {
+                client_id: id.clone(),
+                from: ClientStatus::Closed,
+                to: ClientStatus::Active
+            }
+            .code(),
+            "CLIENT_INVALID_STATUS_TRANSITION"
+        );
+        assert_eq!(
+            ClientError::BalanceNotZero { client_id: id }.code(),
+            "CLIENT_BALANCE_NOT_ZERO"
+        );
+        let birth_date = BirthDate::new("2999-01-01").unwrap();
+        assert_eq!(
+            ClientError::BirthDateInFuture {
+                birth_date: birth_date.clone()
+            }
+            .code(),
+            "CLIENT_BIRTH_DATE_IN_FUTURE"
+        );
+        assert_eq!(
+            ClientError::BirthDateImplausible {
+                birth_date,
+                max_age: 120
+            }
+            .code(),
+            "CLIENT_BIRTH_DATE_IMPLAUSIBLE"
+        );
+        assert_eq!(
+            ClientError::UnderMinimumAge {
+                country: Country::new("AR").unwrap(),
+                min_age: 18
+            }
+            .code(),
+            "CLIENT_UNDER_MINIMUM_AGE"
+        );
+        assert_eq!(
+            ClientError::DuplicateContact {
+                field_name: "email".to_string(),
+                value: "john@example.com".to_string()
+            }
+            .code(),
+            "CLIENT_EMAIL_DUPLICATE"
+        );
+        assert_eq!(
+            ClientError::AccountNotFound {
+                account_id: AccountId::new("1").unwrap()
+            }
+            .code(),
+            "CLIENT_ACCOUNT_NOT_FOUND"
+        );
+        assert_eq!(
+            ClientError::VersionConflict {
+                client_id: ClientId::new("1").unwrap(),
+                expected: Version::new("1").unwrap(),
+                actual: Version::new("2").unwrap()
+            }
+            .code(),
+            "CLIENT_VERSION_CONFLICT"
+        );
         assert_eq!(
             ClientError::Unknown(anyhow!("err")).code(),
             "CLIENT_UNKNOWN_ERROR"
@@ -329,7 +795,7 @@ mod tests {
         // GIVEN
         let doc = "123".to_string();
         let id = ClientId::new("1").unwrap();
-        let d = Document::new("A").unwrap();
+        let d = Document::new(DocumentType::Passport, "A12345").unwrap();
         // THEN
         assert_eq!(
             format!(
@@ -403,8 +869,448 @@ mod tests {
             format!("{}", ClientError::BalancesEmpty),
             "balances are empty"
         );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::SelfTransfer {
+                    client_id: id.clone()
+                }
+            ),
+            format!("client {} cannot transfer to itself", id)
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::IdempotencyKeyConflict {
+                    key: IdempotencyKey::new("retry-1").unwrap()
+                }
+            ),
+            "idempotency key retry-1 was already used with a different request"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::InsufficientFunds {
+                    client_id: id.clone()
+                }
+            ),
+            format!("client {} has insufficient funds", id)
+        );
+        let t = TransactionId::new("3").unwrap();
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::TransactionNotFound {
+                    transaction_id: t.clone()
+                }
+            ),
+            "transaction 3 not found"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::TransactionAlreadyReversed {
+                    transaction_id: t.clone()
+                }
+            ),
+            "transaction 3 was already reversed"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::TransactionNotReversible {
+                    transaction_id: t.clone()
+                }
+            ),
+            "transaction 3 cannot be reversed"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::ReversalExceedsOriginal { transaction_id: t }
+            ),
+            "reversal amount exceeds the amount of transaction 3"
+        );
+        let a = AuthorizationId::new("4").unwrap();
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::AuthorizationNotFound {
+                    authorization_id: a.clone()
+                }
+            ),
+            "authorization 4 not found"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::AuthorizationNotPending {
+                    authorization_id: a.clone()
+                }
+            ),
+            "authorization 4 is no longer pending"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::AuthorizationExpired {
+                    authorization_id: a.clone()
+                }
+            ),
+            "authorization 4 has expired"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::CaptureExceedsAuthorization {
+                    authorization_id: a
+                }
+            ),
+            "capture amount exceeds the amount of authorization 4"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::ExchangeRateNotFound {
+                    from: Currency::new("USD").unwrap(),
+                    to: Currency::new("ARS").unwrap()
+                }
+            ),
+            "exchange rate from USD to ARS not found"
+        );
+        assert_eq!(
+            format!("{}", ClientError::BatchEmpty),
+            "batch cannot be empty"
+        );
+        assert_eq!(
+            format!("{}", ClientError::AmountOverflow),
+            "amount is out of the supported range"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::BatchItemRejected {
+                    index: 3,
+                    reason: Box::new(ClientError::ZeroAmount)
+                }
+            ),
+            "batch item 3 was rejected: client amount cannot be zero"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::ClientBlocked {
+                    client_id: id.clone()
+                }
+            ),
+            "client 1 is blocked"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::ClientClosed {
+                    client_id: id.clone()
+                }
+            ),
+            "client 1 is closed"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::InvalidStatusTransition {
+                    client_id: id.clone(),
+                    from: ClientStatus::Closed,
+                    to: ClientStatus::Active
+                }
+            ),
+            "client 1 cannot change from closed to active"
+        );
+        assert_eq!(
+            format!("{}", ClientError::BalanceNotZero { client_id: id }),
+            "client 1 cannot be closed with a non-zero balance"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::BirthDateInFuture {
+                    birth_date: BirthDate::new("2999-01-01").unwrap()
+                }
+            ),
+            "client birth_date 2999-01-01 is in the future"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::BirthDateImplausible {
+                    birth_date: BirthDate::new("1800-01-01").unwrap(),
+                    max_age: 120
+                }
+            ),
+            "client birth_date 1800-01-01 is older than the maximum age of 120"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::UnderMinimumAge {
+                    country: Country::new("US").unwrap(),
+                    min_age: 21
+                }
+            ),
+            "client is younger than the minimum age of 21 for US"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::DuplicateContact {
+                    field_name: "phone".to_string(),
+                    value: "+5491123456789".to_string()
+                }
+            ),
+            "client with phone +5491123456789 already exists"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::AccountNotFound {
+                    account_id: AccountId::new("7").unwrap()
+                }
+            ),
+            "account 7 not found"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ClientError::VersionConflict {
+                    client_id: ClientId::new("7").unwrap(),
+                    expected: Version::new("1").unwrap(),
+                    actual: Version::new("3").unwrap()
+                }
+            ),
+            "client 7 is at version 3, not 1"
+        );
         // Unknown error: solo chequear que contiene el string
         let unknown = format!("{}", ClientError::Unknown(anyhow!("err")));
         assert!(unknown.contains("err"));
     }
+
+    #[test]
+    fn test_11_given_two_idempotency_key_conflict_errors_when_comparing_then_they_should_be_equal_or_not()
+     {
+        // GIVEN
+        let err1 = ClientError::IdempotencyKeyConflict {
+            key: IdempotencyKey::new("retry-1").unwrap(),
+        };
+        let err2 = ClientError::IdempotencyKeyConflict {
+            key: IdempotencyKey::new("retry-1").unwrap(),
+        };
+        let err3 = ClientError::IdempotencyKeyConflict {
+            key: IdempotencyKey::new("retry-2").unwrap(),
+        };
+        // THEN
+        assert_eq!(err1, err2);
+        assert_ne!(err1, err3);
+    }
+
+    #[test]
+    fn test_12_given_two_self_transfer_errors_when_comparing_then_they_should_be_equal_or_not() {
+        // GIVEN
+        let err1 = ClientError::SelfTransfer {
+            client_id: ClientId::new("1").unwrap(),
+        };
+        let err2 = ClientError::SelfTransfer {
+            client_id: ClientId::new("1").unwrap(),
+        };
+        let err3 = ClientError::SelfTransfer {
+            client_id: ClientId::new("2").unwrap(),
+        };
+        // THEN
+        assert_eq!(err1, err2);
+        assert_ne!(err1, err3);
+    }
+
+    #[test]
+    fn test_13_given_two_insufficient_funds_errors_when_comparing_then_they_should_be_equal_or_not()
+    {
+        // GIVEN
+        let err1 = ClientError::InsufficientFunds {
+            client_id: ClientId::new("1").unwrap(),
+        };
+        let err2 = ClientError::InsufficientFunds {
+            client_id: ClientId::new("1").unwrap(),
+        };
+        let err3 = ClientError::InsufficientFunds {
+            client_id: ClientId::new("2").unwrap(),
+        };
+        // THEN
+        assert_eq!(err1, err2);
+        assert_ne!(err1, err3);
+    }
+
+    #[test]
+    fn test_14_given_two_transaction_errors_when_comparing_then_they_should_match_by_variant_and_id()
+     {
+        // GIVEN
+        let t1 = TransactionId::new("1").unwrap();
+        let t2 = TransactionId::new("2").unwrap();
+        let err1 = ClientError::TransactionAlreadyReversed {
+            transaction_id: t1.clone(),
+        };
+        let err2 = ClientError::TransactionAlreadyReversed {
+            transaction_id: t1.clone(),
+        };
+        let err3 = ClientError::TransactionAlreadyReversed { transaction_id: t2 };
+        let err4 = ClientError::TransactionNotFound { transaction_id: t1 };
+        // THEN
+        assert_eq!(err1, err2);
+        assert_ne!(err1, err3);
+        assert_ne!(err1, err4);
+    }
+
+    #[test]
+    fn test_15_given_two_authorization_errors_when_comparing_then_they_should_match_by_variant_and_id()
+     {
+        // GIVEN
+        let a1 = AuthorizationId::new("1").unwrap();
+        let a2 = AuthorizationId::new("2").unwrap();
+        let err1 = ClientError::AuthorizationExpired {
+            authorization_id: a1.clone(),
+        };
+        let err2 = ClientError::AuthorizationExpired {
+            authorization_id: a1.clone(),
+        };
+        let err3 = ClientError::AuthorizationExpired {
+            authorization_id: a2,
+        };
+        let err4 = ClientError::AuthorizationNotPending {
+            authorization_id: a1,
+        };
+        // THEN
+        assert_eq!(err1, err2);
+        assert_ne!(err1, err3);
+        assert_ne!(err1, err4);
+    }
+
+    #[test]
+    fn test_16_given_two_exchange_rate_not_found_errors_when_comparing_then_they_should_match_by_pair()
+     {
+        // GIVEN
+        let usd = Currency::new("USD").unwrap();
+        let ars = Currency::new("ARS").unwrap();
+        let err1 = ClientError::ExchangeRateNotFound {
+            from: usd.clone(),
+            to: ars.clone(),
+        };
+        let err2 = ClientError::ExchangeRateNotFound {
+            from: usd.clone(),
+            to: ars.clone(),
+        };
+        let err3 = ClientError::ExchangeRateNotFound { from: ars, to: usd };
+        // THEN
+        assert_eq!(err1, err2);
+        assert_ne!(err1, err3);
+    }
+
+    #[test]
+    fn test_17_given_two_batch_item_rejected_errors_when_comparing_then_they_should_match_by_index_and_reason()
+     {
+        // GIVEN
+        let rejected = |index, reason| ClientError::BatchItemRejected {
+            index,
+            reason: Box::new(reason),
+        };
+        let err1 = rejected(1, ClientError::ZeroAmount);
+        let err2 = rejected(1, ClientError::ZeroAmount);
+        let err3 = rejected(2, ClientError::ZeroAmount);
+        let err4 = rejected(1, ClientError::NegativeAmount);
+        // THEN
+        assert_eq!(err1, err2);
+        assert_ne!(err1, err3);
+        assert_ne!(err1, err4);
+    }
+
+    #[test]
+    fn test_18_given_two_status_transition_errors_when_comparing_then_they_should_match_by_client_and_statuses()
+     {
+        // GIVEN
+        let transition = |from, to| ClientError::InvalidStatusTransition {
+            client_id: ClientId::new("1").unwrap(),
+            from,
+            to,
+        };
+        let err1 = transition(ClientStatus::Closed, ClientStatus::Active);
+        let err2 = transition(ClientStatus::Closed, ClientStatus::Active);
+        let err3 = transition(ClientStatus::Closed, ClientStatus::Blocked);
+        // THEN
+        assert_eq!(err1, err2);
+        assert_ne!(err1, err3);
+        assert_ne!(
+            ClientError::ClientBlocked {
+                client_id: ClientId::new("1").unwrap()
+            },
+            ClientError::ClientClosed {
+                client_id: ClientId::new("1").unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn test_19_given_two_minimum_age_errors_when_comparing_then_they_should_match_by_country_and_age()
+     {
+        // GIVEN
+        let under_minimum_age = |country, min_age| ClientError::UnderMinimumAge {
+            country: Country::new(country).unwrap(),
+            min_age,
+        };
+        // THEN
+        assert_eq!(under_minimum_age("AR", 18), under_minimum_age("AR", 18));
+        assert_ne!(under_minimum_age("AR", 18), under_minimum_age("US", 18));
+        assert_ne!(under_minimum_age("AR", 18), under_minimum_age("AR", 21));
+    }
+
+    #[test]
+    fn test_20_given_two_duplicate_contact_errors_when_comparing_then_they_should_match_by_field_and_value()
+     {
+        // GIVEN
+        let duplicate = |field_name: &str, value: &str| ClientError::DuplicateContact {
+            field_name: field_name.to_string(),
+            value: value.to_string(),
+        };
+        // THEN
+        assert_eq!(
+            duplicate("email", "john@example.com"),
+            duplicate("email", "john@example.com")
+        );
+        assert_ne!(
+            duplicate("email", "john@example.com"),
+            duplicate("email", "jane@example.com")
+        );
+        assert_ne!(duplicate("email", "x"), duplicate("phone", "x"));
+    }
+
+    #[test]
+    fn test_21_given_two_account_not_found_errors_when_comparing_then_they_should_match_by_account_id()
+     {
+        // GIVEN
+        let not_found = |account_id| ClientError::AccountNotFound {
+            account_id: AccountId::new(account_id).unwrap(),
+        };
+        // THEN
+        assert_eq!(not_found("1"), not_found("1"));
+        assert_ne!(not_found("1"), not_found("2"));
+    }
+
+    #[test]
+    fn test_22_given_two_version_conflict_errors_when_comparing_then_they_should_match_by_versions()
+    {
+        // GIVEN
+        let conflict = |expected, actual| ClientError::VersionConflict {
+            client_id: ClientId::new("1").unwrap(),
+            expected: Version::new(expected).unwrap(),
+            actual: Version::new(actual).unwrap(),
+        };
+        // THEN
+        assert_eq!(conflict("1", "2"), conflict("1", "2"));
+        assert_ne!(conflict("1", "2"), conflict("1", "3"));
+        assert_ne!(conflict("1", "2"), conflict("2", "2"));
+    }
 }
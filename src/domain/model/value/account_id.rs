@@ -0,0 +1,56 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A valid account id.
+pub struct AccountId(usize);
+
+impl AccountId {
+    pub fn new(id: &str) -> Result<Self, ClientError> {
+        let id_trimmed = id.trim();
+        match id_trimmed.parse::<usize>() {
+            Ok(id) => Ok(Self(id)),
+            Err(_) => Err(ClientError::FieldInvalid {
+                field_name: "account_id".to_string(),
+                value: id.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for AccountId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_string())
+    }
+}
+
+impl TryFrom<String> for AccountId {
+    type Error = ClientError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        AccountId::new(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_valid_integer_string_when_creating_account_id_then_it_should_be_created() {
+        let account_id = AccountId::new("42").unwrap();
+        assert_eq!(account_id.to_string(), "42");
+    }
+
+    #[test]
+    fn test_02_given_invalid_string_when_try_from_then_it_should_fail() {
+        let account_id = AccountId::try_from("not-a-number".to_string());
+        assert_eq!(
+            account_id.err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "account_id".to_string(),
+                value: "not-a-number".to_string(),
+            }
+        );
+    }
+}
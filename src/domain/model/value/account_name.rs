@@ -0,0 +1,66 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::{
+    error::ClientError,
+    value::{MAX_LENGTH_ACCOUNT_NAME, text},
+};
+
+/// The name of the account every client is created with.
+pub const MAIN_ACCOUNT_NAME: &str = "main";
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A valid account name, such as "savings", normalized like the client name.
+pub struct AccountName(String);
+
+impl AccountName {
+    pub fn new(name: &str) -> Result<Self, ClientError> {
+        let name = text::normalize("account_name", name, MAX_LENGTH_ACCOUNT_NAME)?;
+        Ok(AccountName(name))
+    }
+}
+
+impl Default for AccountName {
+    fn default() -> Self {
+        AccountName(MAIN_ACCOUNT_NAME.to_string())
+    }
+}
+
+impl Display for AccountName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for AccountName {
+    type Error = ClientError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        AccountName::new(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_an_account_name_with_extra_spaces_when_creating_it_then_they_should_be_collapsed()
+     {
+        let account_name = AccountName::new("  rainy   day  ").unwrap();
+        assert_eq!(account_name.to_string(), "rainy day");
+    }
+
+    #[test]
+    fn test_02_given_an_empty_account_name_when_creating_it_then_it_should_fail() {
+        assert_eq!(
+            AccountName::new("   ").err().unwrap(),
+            ClientError::FieldEmpty {
+                field_name: "account_name".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_03_given_no_account_name_then_it_should_be_the_main_one() {
+        assert_eq!(AccountName::default().to_string(), MAIN_ACCOUNT_NAME);
+    }
+}
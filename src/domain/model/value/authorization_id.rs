@@ -0,0 +1,57 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A valid authorization id.
+pub struct AuthorizationId(usize);
+
+impl AuthorizationId {
+    pub fn new(id: &str) -> Result<Self, ClientError> {
+        let id_trimmed = id.trim();
+        match id_trimmed.parse::<usize>() {
+            Ok(id) => Ok(Self(id)),
+            Err(_) => Err(ClientError::FieldInvalid {
+                field_name: "authorization_id".to_string(),
+                value: id.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for AuthorizationId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_string())
+    }
+}
+
+impl TryFrom<String> for AuthorizationId {
+    type Error = ClientError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        AuthorizationId::new(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_valid_integer_string_when_creating_authorization_id_then_it_should_be_created()
+    {
+        let authorization_id = AuthorizationId::new("42").unwrap();
+        assert_eq!(authorization_id.to_string(), "42");
+    }
+
+    #[test]
+    fn test_02_given_invalid_string_when_try_from_then_it_should_fail() {
+        let authorization_id = AuthorizationId::try_from("not-a-number".to_string());
+        assert_eq!(
+            authorization_id.err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "authorization_id".to_string(),
+                value: "not-a-number".to_string(),
+            }
+        );
+    }
+}
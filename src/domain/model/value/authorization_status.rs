@@ -0,0 +1,75 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The lifecycle of an authorization hold.
+pub enum AuthorizationStatus {
+    /// The funds are reserved and can still be captured or voided.
+    Pending,
+    Captured,
+    Voided,
+    /// The hold timed out before being captured or voided, so the funds were released.
+    Expired,
+}
+
+impl AuthorizationStatus {
+    pub fn new(status: &str) -> Result<Self, ClientError> {
+        match status.trim().to_lowercase().as_str() {
+            "pending" => Ok(AuthorizationStatus::Pending),
+            "captured" => Ok(AuthorizationStatus::Captured),
+            "voided" => Ok(AuthorizationStatus::Voided),
+            "expired" => Ok(AuthorizationStatus::Expired),
+            _ => Err(ClientError::FieldInvalid {
+                field_name: "status".to_string(),
+                value: status.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for AuthorizationStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthorizationStatus::Pending => f.write_str("pending"),
+            AuthorizationStatus::Captured => f.write_str("captured"),
+            AuthorizationStatus::Voided => f.write_str("voided"),
+            AuthorizationStatus::Expired => f.write_str("expired"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_each_status_when_display_then_it_should_be_snake_case() {
+        assert_eq!(AuthorizationStatus::Pending.to_string(), "pending");
+        assert_eq!(AuthorizationStatus::Captured.to_string(), "captured");
+        assert_eq!(AuthorizationStatus::Voided.to_string(), "voided");
+        assert_eq!(AuthorizationStatus::Expired.to_string(), "expired");
+    }
+
+    #[test]
+    fn test_02_given_each_status_when_parsing_its_display_then_it_should_be_the_same() {
+        for status in [
+            AuthorizationStatus::Pending,
+            AuthorizationStatus::Captured,
+            AuthorizationStatus::Voided,
+            AuthorizationStatus::Expired,
+        ] {
+            assert_eq!(
+                AuthorizationStatus::new(&status.to_string()).unwrap(),
+                status
+            );
+        }
+        assert_eq!(
+            AuthorizationStatus::new("unknown").err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "status".to_string(),
+                value: "unknown".to_string(),
+            }
+        );
+    }
+}
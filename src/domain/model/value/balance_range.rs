@@ -0,0 +1,63 @@
+use rust_decimal::Decimal;
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An inclusive range of balances. Either end is open when not set.
+pub struct BalanceRange {
+    min: Option<Decimal>,
+    max: Option<Decimal>,
+}
+
+impl BalanceRange {
+    pub fn new(min: Option<Decimal>, max: Option<Decimal>) -> Result<Self, ClientError> {
+        if let (Some(min), Some(max)) = (min, max)
+            && min > max
+        {
+            return Err(ClientError::FieldInvalid {
+                field_name: "max_balance".to_string(),
+                value: max.to_string(),
+            });
+        }
+        Ok(Self { min, max })
+    }
+
+    pub fn min(&self) -> Option<&Decimal> {
+        self.min.as_ref()
+    }
+
+    pub fn max(&self) -> Option<&Decimal> {
+        self.max.as_ref()
+    }
+
+    pub fn contains(&self, balance: &Decimal) -> bool {
+        self.min.is_none_or(|min| *balance >= min) && self.max.is_none_or(|max| *balance <= max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_a_range_when_checking_a_balance_then_both_ends_should_be_included() {
+        let range = BalanceRange::new(Some(Decimal::from(-10)), Some(Decimal::from(10))).unwrap();
+        assert!(range.contains(&Decimal::from(-10)));
+        assert!(range.contains(&Decimal::from(10)));
+        assert!(!range.contains(&Decimal::from(11)));
+        assert!(BalanceRange::default().contains(&Decimal::MAX));
+    }
+
+    #[test]
+    fn test_02_given_a_min_greater_than_the_max_when_creating_a_range_then_should_fail() {
+        assert_eq!(
+            BalanceRange::new(Some(Decimal::from(10)), Some(Decimal::from(5)))
+                .err()
+                .unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "max_balance".to_string(),
+                value: "5".to_string()
+            }
+        );
+    }
+}
@@ -24,6 +24,11 @@ impl BirthDate {
             Ok(BirthDate(birth_date))
         }
     }
+
+    /// The age in complete years on the given day, or `None` if it is before the birth date.
+    pub fn age_on(&self, today: NaiveDate) -> Option<u32> {
+        today.years_since(self.0)
+    }
 }
 
 impl Display for BirthDate {
@@ -76,7 +81,17 @@ mod tests {
     fn test_06_given_a_birth_date_out_of_range_when_creating_it_then_it_should_be_accepted() {
         let date_str = "1800-01-01";
         let birth_date = BirthDate::new(date_str);
-        // chrono acepta fechas fuera de un rango "normal", así que solo validamos que no falle
+        // la fecha en sí es válida: que sea plausible lo validan las BirthDateRules del servicio
         assert!(birth_date.is_ok());
     }
+
+    #[test]
+    fn test_07_given_a_birth_date_when_getting_the_age_then_it_should_count_only_complete_years() {
+        let birth_date = BirthDate::new("2000-06-15").unwrap();
+        let day = |date| NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+        assert_eq!(birth_date.age_on(day("2018-06-14")), Some(17));
+        assert_eq!(birth_date.age_on(day("2018-06-15")), Some(18));
+        assert_eq!(birth_date.age_on(day("2000-06-15")), Some(0));
+        assert_eq!(birth_date.age_on(day("2000-06-14")), None);
+    }
 }
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use chrono::NaiveDate;
+
+use crate::domain::model::{
+    error::ClientError,
+    value::{birth_date::BirthDate, country::Country},
+};
+
+/// The minimum age of a client when the country has no rule of its own.
+pub const DEFAULT_MIN_AGE: u32 = 18;
+/// The maximum age a client can plausibly have.
+pub const DEFAULT_MAX_AGE: u32 = 120;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// The rules a [BirthDate] must follow for a client of a given [Country]: it cannot be in the
+/// future, the client must have the minimum age of the country and cannot be older than the maximum age.
+pub struct BirthDateRules {
+    min_age: u32,
+    min_age_by_country: HashMap<Country, u32>,
+    max_age: u32,
+}
+
+impl BirthDateRules {
+    pub fn new(min_age: u32, max_age: u32) -> Self {
+        Self {
+            min_age,
+            min_age_by_country: HashMap::new(),
+            max_age,
+        }
+    }
+
+    /// Overrides the minimum age for the clients of the [Country].
+    pub fn with_min_age(mut self, country: Country, min_age: u32) -> Self {
+        self.min_age_by_country.insert(country, min_age);
+        self
+    }
+
+    /// Loads the rules from `MIN_AGE`, `MAX_AGE` and `MIN_AGE_BY_COUNTRY`, the latter with the format
+    /// "COUNTRY:AGE,COUNTRY:AGE", for example "AR:18,US:21". The defaults are used for the ones not set.
+    pub fn from_env() -> Result<Self, anyhow::Error> {
+        let min_age = match std::env::var("MIN_AGE") {
+            Ok(min_age) => min_age.parse().context("MIN_AGE must be a number")?,
+            Err(_) => DEFAULT_MIN_AGE,
+        };
+        let max_age = match std::env::var("MAX_AGE") {
+            Ok(max_age) => max_age.parse().context("MAX_AGE must be a number")?,
+            Err(_) => DEFAULT_MAX_AGE,
+        };
+
+        let mut rules = Self::new(min_age, max_age);
+        if let Ok(min_age_by_country) = std::env::var("MIN_AGE_BY_COUNTRY") {
+            for rule in min_age_by_country
+                .split(',')
+                .filter(|rule| !rule.trim().is_empty())
+            {
+                let (country, min_age) = rule
+                    .split_once(':')
+                    .with_context(|| format!("expected \"COUNTRY:AGE\", got \"{rule}\""))?;
+                let min_age = min_age
+                    .trim()
+                    .parse()
+                    .with_context(|| format!("Invalid minimum age for {country}"))?;
+                rules = rules.with_min_age(Country::new(country)?, min_age);
+            }
+        }
+        Ok(rules)
+    }
+
+    /// The minimum age for the clients of the [Country].
+    pub fn min_age(&self, country: &Country) -> u32 {
+        self.min_age_by_country
+            .get(country)
+            .copied()
+            .unwrap_or(self.min_age)
+    }
+
+    /// Checks the [BirthDate] of a client of the [Country] as of the given day.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::BirthDateInFuture] if the birth date is after the given day.
+    /// - [ClientError::BirthDateImplausible] if the client would be older than the maximum age.
+    /// - [ClientError::UnderMinimumAge] if the client is younger than the minimum age of the country.
+    pub fn validate(
+        &self,
+        birth_date: &BirthDate,
+        country: &Country,
+        today: NaiveDate,
+    ) -> Result<(), ClientError> {
+        let Some(age) = birth_date.age_on(today) else {
+            return Err(ClientError::BirthDateInFuture {
+                birth_date: birth_date.clone(),
+            });
+        };
+        if age > self.max_age {
+            return Err(ClientError::BirthDateImplausible {
+                birth_date: birth_date.clone(),
+                max_age: self.max_age,
+            });
+        }
+        let min_age = self.min_age(country);
+        if age < min_age {
+            return Err(ClientError::UnderMinimumAge {
+                country: country.clone(),
+                min_age,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Default for BirthDateRules {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_AGE, DEFAULT_MAX_AGE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, 15).unwrap()
+    }
+
+    #[test]
+    fn test_01_given_an_adult_when_validating_the_birth_date_then_it_should_be_accepted() {
+        let rules = BirthDateRules::default();
+        let birth_date = BirthDate::new("2008-06-15").unwrap();
+        let country = Country::new("AR").unwrap();
+        assert!(rules.validate(&birth_date, &country, today()).is_ok());
+    }
+
+    #[test]
+    fn test_02_given_a_birth_date_in_the_future_when_validating_it_then_it_should_fail() {
+        let rules = BirthDateRules::default();
+        let birth_date = BirthDate::new("2026-06-16").unwrap();
+        let country = Country::new("AR").unwrap();
+        assert_eq!(
+            rules
+                .validate(&birth_date, &country, today())
+                .err()
+                .unwrap(),
+            ClientError::BirthDateInFuture { birth_date }
+        );
+    }
+
+    #[test]
+    fn test_03_given_a_birth_date_older_than_the_max_age_when_validating_it_then_it_should_fail() {
+        let rules = BirthDateRules::default();
+        let birth_date = BirthDate::new("1800-01-01").unwrap();
+        let country = Country::new("AR").unwrap();
+        assert_eq!(
+            rules
+                .validate(&birth_date, &country, today())
+                .err()
+                .unwrap(),
+            ClientError::BirthDateImplausible {
+                birth_date,
+                max_age: DEFAULT_MAX_AGE
+            }
+        );
+    }
+
+    #[test]
+    fn test_04_given_a_minor_when_validating_the_birth_date_then_it_should_fail() {
+        let rules = BirthDateRules::default();
+        let birth_date = BirthDate::new("2008-06-16").unwrap();
+        let country = Country::new("AR").unwrap();
+        assert_eq!(
+            rules
+                .validate(&birth_date, &country, today())
+                .err()
+                .unwrap(),
+            ClientError::UnderMinimumAge {
+                country,
+                min_age: DEFAULT_MIN_AGE
+            }
+        );
+    }
+
+    #[test]
+    fn test_05_given_a_country_with_its_own_min_age_when_validating_the_birth_date_then_it_should_be_used()
+     {
+        let rules = BirthDateRules::default().with_min_age(Country::new("US").unwrap(), 21);
+        let birth_date = BirthDate::new("2006-01-01").unwrap();
+        assert!(
+            rules
+                .validate(&birth_date, &Country::new("AR").unwrap(), today())
+                .is_ok()
+        );
+        assert_eq!(
+            rules
+                .validate(&birth_date, &Country::new("US").unwrap(), today())
+                .err()
+                .unwrap(),
+            ClientError::UnderMinimumAge {
+                country: Country::new("US").unwrap(),
+                min_age: 21
+            }
+        );
+    }
+}
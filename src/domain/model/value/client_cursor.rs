@@ -0,0 +1,93 @@
+use std::fmt::{Display, Formatter};
+
+use rust_decimal::Decimal;
+
+use crate::domain::model::{error::ClientError, value::client_id::ClientId};
+
+const BALANCE_SEPARATOR: char = ':';
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The position of the last client of a page, so the next page starts right after it. It keeps the
+/// balance the client had when listed if the clients are sorted by balance.
+pub struct ClientCursor {
+    balance: Option<Decimal>,
+    client_id: ClientId,
+}
+
+impl ClientCursor {
+    pub fn new(cursor: &str) -> Result<Self, ClientError> {
+        let invalid = || ClientError::FieldInvalid {
+            field_name: "cursor".to_string(),
+            value: cursor.to_string(),
+        };
+        match cursor.trim().split_once(BALANCE_SEPARATOR) {
+            Some((balance, client_id)) => Ok(Self {
+                balance: Some(balance.parse::<Decimal>().map_err(|_| invalid())?),
+                client_id: ClientId::new(client_id).map_err(|_| invalid())?,
+            }),
+            None => Ok(Self {
+                balance: None,
+                client_id: ClientId::new(cursor).map_err(|_| invalid())?,
+            }),
+        }
+    }
+
+    pub fn after_id(client_id: ClientId) -> Self {
+        Self {
+            balance: None,
+            client_id,
+        }
+    }
+
+    pub fn after_balance(balance: Decimal, client_id: ClientId) -> Self {
+        Self {
+            balance: Some(balance),
+            client_id,
+        }
+    }
+
+    pub fn balance(&self) -> Option<&Decimal> {
+        self.balance.as_ref()
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+}
+
+impl Display for ClientCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.balance {
+            Some(balance) => write!(f, "{balance}{BALANCE_SEPARATOR}{}", self.client_id),
+            None => write!(f, "{}", self.client_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_a_cursor_when_displaying_and_parsing_it_then_it_should_be_the_same() {
+        let by_id = ClientCursor::after_id(ClientId::new("7").unwrap());
+        assert_eq!(ClientCursor::new(&by_id.to_string()).unwrap(), by_id);
+        let by_balance =
+            ClientCursor::after_balance(Decimal::new(-1050, 2), ClientId::new("7").unwrap());
+        assert_eq!(by_balance.to_string(), "-10.50:7");
+        assert_eq!(ClientCursor::new("-10.50:7").unwrap(), by_balance);
+    }
+
+    #[test]
+    fn test_02_given_a_malformed_cursor_when_parsing_it_then_it_should_fail() {
+        for cursor in ["", "a b", "x:7", "10:", "10:a/b"] {
+            assert_eq!(
+                ClientCursor::new(cursor).err().unwrap(),
+                ClientError::FieldInvalid {
+                    field_name: "cursor".to_string(),
+                    value: cursor.to_string()
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The details of a client that can be corrected after it was created.
+pub enum ClientField {
+    Name,
+    BirthDate,
+    Document,
+    Country,
+    Email,
+    Phone,
+}
+
+impl ClientField {
+    pub fn new(field: &str) -> Result<Self, ClientError> {
+        match field.trim().to_lowercase().as_str() {
+            "name" => Ok(ClientField::Name),
+            "birth_date" => Ok(ClientField::BirthDate),
+            "document" => Ok(ClientField::Document),
+            "country" => Ok(ClientField::Country),
+            "email" => Ok(ClientField::Email),
+            "phone" => Ok(ClientField::Phone),
+            _ => Err(ClientError::FieldInvalid {
+                field_name: "field".to_string(),
+                value: field.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for ClientField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientField::Name => f.write_str("name"),
+            ClientField::BirthDate => f.write_str("birth_date"),
+            ClientField::Document => f.write_str("document"),
+            ClientField::Country => f.write_str("country"),
+            ClientField::Email => f.write_str("email"),
+            ClientField::Phone => f.write_str("phone"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_each_field_when_display_then_it_should_be_snake_case() {
+        assert_eq!(ClientField::Name.to_string(), "name");
+        assert_eq!(ClientField::BirthDate.to_string(), "birth_date");
+        assert_eq!(ClientField::Document.to_string(), "document");
+        assert_eq!(ClientField::Country.to_string(), "country");
+        assert_eq!(ClientField::Email.to_string(), "email");
+        assert_eq!(ClientField::Phone.to_string(), "phone");
+    }
+
+    #[test]
+    fn test_02_given_each_field_when_parsing_its_display_then_it_should_be_the_same() {
+        for field in [
+            ClientField::Name,
+            ClientField::BirthDate,
+            ClientField::Document,
+            ClientField::Country,
+            ClientField::Email,
+            ClientField::Phone,
+        ] {
+            assert_eq!(ClientField::new(&field.to_string()).unwrap(), field);
+        }
+        assert_eq!(
+            ClientField::new("unknown").err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "field".to_string(),
+                value: "unknown".to_string(),
+            }
+        );
+    }
+}
@@ -1,34 +1,54 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+};
 
-use crate::domain::model::error::ClientError;
+use crate::domain::model::{error::ClientError, value::MAX_LENGTH_CLIENT_ID};
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-/// A valid client id.
-pub struct ClientId(usize);
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// A valid client id. It is opaque: its shape depends on the strategy that generated it, such as a
+/// sequential number, a UUID, a ULID or a Snowflake id, so it only allows ASCII letters, digits, `-`
+/// and `_`.
+pub struct ClientId(String);
 
 impl ClientId {
     pub fn new(id: &str) -> Result<Self, ClientError> {
         let id_trimmed = id.trim();
-        if id_trimmed.is_empty() {
+        let valid = !id_trimmed.is_empty()
+            && id_trimmed.len() <= MAX_LENGTH_CLIENT_ID
+            && id_trimmed
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
             return Err(ClientError::FieldInvalid {
                 field_name: "client_id".to_string(),
                 value: id.to_string(),
             });
         }
-        let id_parsed = id_trimmed.parse::<usize>();
-        match id_parsed {
-            Ok(id) => Ok(Self(id)),
-            Err(_) => Err(ClientError::FieldInvalid {
-                field_name: "client_id".to_string(),
-                value: id.to_string(),
-            }),
-        }
+        Ok(Self(id_trimmed.to_string()))
+    }
+}
+
+/// Shorter ids come first and ids of the same length compare as text, so sequential ids keep their
+/// numeric order and time-ordered ids of a fixed length keep the order they were generated in.
+impl Ord for ClientId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl PartialOrd for ClientId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl Display for ClientId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.0.to_string())
+        f.write_str(&self.0)
     }
 }
 
@@ -58,9 +78,12 @@ mod tests {
 
     #[test]
     fn test_03_given_invalid_string_when_try_from_then_it_should_fail() {
-        let invalid = "not-a-number".to_string();
-        let client_id = ClientId::try_from(invalid);
-        assert!(client_id.is_err());
+        for invalid in ["not a number", "1:2", "ñandú", "1/2"] {
+            let client_id = ClientId::try_from(invalid.to_string());
+            assert!(client_id.is_err(), "{invalid} should be invalid");
+        }
+        let too_long = "1".repeat(MAX_LENGTH_CLIENT_ID + 1);
+        assert!(ClientId::new(&too_long).is_err());
     }
 
     #[test]
@@ -90,4 +113,22 @@ mod tests {
         let client_id = ClientId::try_from("   ".to_string());
         assert!(client_id.is_err());
     }
+
+    #[test]
+    fn test_07_given_opaque_ids_when_creating_them_then_they_should_be_kept_as_is() {
+        for id in [
+            "0190f5a2-7b3c-7d4e-8f90-123456789abc",
+            "01J1ZK7Q8X9Y0Z1A2B3C4D5E6F",
+            "7212836154281984000",
+        ] {
+            assert_eq!(ClientId::new(id).unwrap().to_string(), id);
+        }
+    }
+
+    #[test]
+    fn test_08_given_sequential_ids_when_sorting_them_then_they_should_keep_their_numeric_order() {
+        let mut ids = ["10", "9", "100", "2"].map(|id| ClientId::new(id).unwrap());
+        ids.sort();
+        assert_eq!(ids.map(|id| id.to_string()), ["2", "9", "10", "100"]);
+    }
 }
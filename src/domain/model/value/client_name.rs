@@ -1,26 +1,23 @@
 use std::fmt::{Display, Formatter};
 
-use crate::domain::model::{error::ClientError, value::MAX_LENGTH_NAME};
+use crate::domain::model::{
+    error::ClientError,
+    value::{MAX_LENGTH_NAME, text},
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-/// A valid client name.
+/// A valid client name, in NFC and with its whitespace collapsed, so the same name always compares equal.
 pub struct ClientName(String);
 
 impl ClientName {
     pub fn new(name: &str) -> Result<Self, ClientError> {
-        let name = name.trim();
-        if name.is_empty() {
-            Err(ClientError::FieldEmpty {
-                field_name: "name".to_string(),
-            })
-        } else if name.len() > MAX_LENGTH_NAME {
-            Err(ClientError::FieldMaxLength {
-                field_name: "name".to_string(),
-                max_length: MAX_LENGTH_NAME,
-            })
-        } else {
-            Ok(ClientName(name.to_string()))
-        }
+        let name = text::normalize("name", name, MAX_LENGTH_NAME)?;
+        Ok(ClientName(name))
+    }
+
+    /// Rebuilds a name that was valid when it was stored, without checking the current rules.
+    pub(crate) fn restore(name: &str) -> Self {
+        ClientName(name.to_string())
     }
 }
 
@@ -97,4 +94,49 @@ mod tests {
         let client_name = ClientName::new(name).unwrap();
         assert_eq!(client_name.to_string(), name);
     }
+
+    #[test]
+    fn test_07_given_an_accented_name_at_the_max_length_when_creating_it_then_it_should_be_accepted()
+     {
+        // GIVEN
+        let name = "é".repeat(MAX_LENGTH_NAME);
+
+        // WHEN
+        let result = ClientName::new(&name);
+
+        // THEN
+        assert_eq!(result.unwrap().to_string(), name);
+    }
+
+    #[test]
+    fn test_08_given_the_same_name_composed_and_decomposed_when_creating_them_then_they_should_be_equal()
+     {
+        // GIVEN
+        let composed = "José  Peña";
+        let decomposed = "Jose\u{0301} Pen\u{0303}a";
+
+        // WHEN
+        let result = (ClientName::new(composed), ClientName::new(decomposed));
+
+        // THEN
+        assert_eq!(result.0.unwrap(), result.1.unwrap());
+    }
+
+    #[test]
+    fn test_09_given_a_name_with_a_bidi_override_when_creating_it_then_it_should_fail() {
+        // GIVEN
+        let name = "John \u{202E}eoD";
+
+        // WHEN
+        let result = ClientName::new(name);
+
+        // THEN
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "name".to_string(),
+                value: "John \\u{202e}eoD".to_string(),
+            }
+        );
+    }
 }
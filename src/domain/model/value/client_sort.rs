@@ -0,0 +1,90 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The order in which clients are listed. Ties are always broken by client id.
+pub enum ClientSort {
+    #[default]
+    Id,
+    /// The ledger balance in the currency of the listing.
+    Balance,
+}
+
+impl ClientSort {
+    pub fn new(sort: &str) -> Result<Self, ClientError> {
+        match sort.trim().to_lowercase().as_str() {
+            "id" => Ok(ClientSort::Id),
+            "balance" => Ok(ClientSort::Balance),
+            _ => Err(ClientError::FieldInvalid {
+                field_name: "sort".to_string(),
+                value: sort.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for ClientSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientSort::Id => f.write_str("id"),
+            ClientSort::Balance => f.write_str("balance"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn new(direction: &str) -> Result<Self, ClientError> {
+        match direction.trim().to_lowercase().as_str() {
+            "asc" => Ok(SortDirection::Ascending),
+            "desc" => Ok(SortDirection::Descending),
+            _ => Err(ClientError::FieldInvalid {
+                field_name: "order".to_string(),
+                value: direction.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for SortDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortDirection::Ascending => f.write_str("asc"),
+            SortDirection::Descending => f.write_str("desc"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_a_known_sort_when_creating_it_then_it_should_ignore_the_case() {
+        assert_eq!(ClientSort::new("id").unwrap(), ClientSort::Id);
+        assert_eq!(ClientSort::new(" Balance ").unwrap(), ClientSort::Balance);
+        assert_eq!(
+            SortDirection::new("DESC").unwrap(),
+            SortDirection::Descending
+        );
+    }
+
+    #[test]
+    fn test_02_given_an_unknown_sort_when_creating_it_then_it_should_fail() {
+        assert_eq!(
+            ClientSort::new("name").err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "sort".to_string(),
+                value: "name".to_string()
+            }
+        );
+        assert!(SortDirection::new("up").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The lifecycle of a client account.
+pub enum ClientStatus {
+    /// The client can operate with its balances.
+    #[default]
+    Active,
+    /// The account is frozen: no balance can be credited or debited until it is unblocked.
+    Blocked,
+    /// The account was closed with a zero balance and cannot be reopened.
+    Closed,
+}
+
+impl ClientStatus {
+    pub fn new(status: &str) -> Result<Self, ClientError> {
+        match status.trim().to_lowercase().as_str() {
+            "active" => Ok(ClientStatus::Active),
+            "blocked" => Ok(ClientStatus::Blocked),
+            "closed" => Ok(ClientStatus::Closed),
+            _ => Err(ClientError::FieldInvalid {
+                field_name: "status".to_string(),
+                value: status.to_string(),
+            }),
+        }
+    }
+
+    /// Returns if the status can be changed to the given one.
+    pub fn can_change_to(&self, to: ClientStatus) -> bool {
+        matches!(
+            (self, to),
+            (ClientStatus::Active, ClientStatus::Blocked)
+                | (ClientStatus::Blocked, ClientStatus::Active)
+                | (ClientStatus::Active, ClientStatus::Closed)
+                | (ClientStatus::Blocked, ClientStatus::Closed)
+        )
+    }
+}
+
+impl Display for ClientStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientStatus::Active => f.write_str("active"),
+            ClientStatus::Blocked => f.write_str("blocked"),
+            ClientStatus::Closed => f.write_str("closed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_each_status_when_display_then_it_should_be_snake_case() {
+        assert_eq!(ClientStatus::Active.to_string(), "active");
+        assert_eq!(ClientStatus::Blocked.to_string(), "blocked");
+        assert_eq!(ClientStatus::Closed.to_string(), "closed");
+    }
+
+    #[test]
+    fn test_02_given_a_closed_client_when_changing_its_status_then_it_should_not_be_allowed() {
+        assert!(!ClientStatus::Closed.can_change_to(ClientStatus::Active));
+        assert!(!ClientStatus::Closed.can_change_to(ClientStatus::Blocked));
+        assert!(!ClientStatus::Closed.can_change_to(ClientStatus::Closed));
+    }
+
+    #[test]
+    fn test_03_given_an_open_client_when_changing_its_status_then_only_real_changes_should_be_allowed()
+     {
+        assert!(ClientStatus::Active.can_change_to(ClientStatus::Blocked));
+        assert!(ClientStatus::Blocked.can_change_to(ClientStatus::Active));
+        assert!(ClientStatus::Active.can_change_to(ClientStatus::Closed));
+        assert!(ClientStatus::Blocked.can_change_to(ClientStatus::Closed));
+        assert!(!ClientStatus::Active.can_change_to(ClientStatus::Active));
+        assert!(!ClientStatus::Blocked.can_change_to(ClientStatus::Blocked));
+    }
+
+    #[test]
+    fn test_04_given_each_status_when_parsing_its_display_then_it_should_be_the_same() {
+        for status in [
+            ClientStatus::Active,
+            ClientStatus::Blocked,
+            ClientStatus::Closed,
+        ] {
+            assert_eq!(ClientStatus::new(&status.to_string()).unwrap(), status);
+        }
+        assert_eq!(
+            ClientStatus::new("unknown").err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "status".to_string(),
+                value: "unknown".to_string(),
+            }
+        );
+    }
+}
@@ -1,26 +1,353 @@
 use std::fmt::{Display, Formatter};
 
-use crate::domain::model::{error::ClientError, value::MAX_LENGTH_COUNTRY};
+use crate::domain::model::{
+    error::ClientError,
+    value::{MAX_LENGTH_COUNTRY, text},
+};
+
+/// ISO 3166-1 countries as (alpha-2, alpha-3, name), sorted by alpha-2 to allow a binary search.
+const ISO_3166_COUNTRIES: [(&str, &str, &str); 249] = [
+    ("AD", "AND", "Andorra"),
+    ("AE", "ARE", "United Arab Emirates"),
+    ("AF", "AFG", "Afghanistan"),
+    ("AG", "ATG", "Antigua and Barbuda"),
+    ("AI", "AIA", "Anguilla"),
+    ("AL", "ALB", "Albania"),
+    ("AM", "ARM", "Armenia"),
+    ("AO", "AGO", "Angola"),
+    ("AQ", "ATA", "Antarctica"),
+    ("AR", "ARG", "Argentina"),
+    ("AS", "ASM", "American Samoa"),
+    ("AT", "AUT", "Austria"),
+    ("AU", "AUS", "Australia"),
+    ("AW", "ABW", "Aruba"),
+    ("AX", "ALA", "Åland Islands"),
+    ("AZ", "AZE", "Azerbaijan"),
+    ("BA", "BIH", "Bosnia and Herzegovina"),
+    ("BB", "BRB", "Barbados"),
+    ("BD", "BGD", "Bangladesh"),
+    ("BE", "BEL", "Belgium"),
+    ("BF", "BFA", "Burkina Faso"),
+    ("BG", "BGR", "Bulgaria"),
+    ("BH", "BHR", "Bahrain"),
+    ("BI", "BDI", "Burundi"),
+    ("BJ", "BEN", "Benin"),
+    ("BL", "BLM", "Saint Barthélemy"),
+    ("BM", "BMU", "Bermuda"),
+    ("BN", "BRN", "Brunei Darussalam"),
+    ("BO", "BOL", "Bolivia"),
+    ("BQ", "BES", "Bonaire, Sint Eustatius and Saba"),
+    ("BR", "BRA", "Brazil"),
+    ("BS", "BHS", "Bahamas"),
+    ("BT", "BTN", "Bhutan"),
+    ("BV", "BVT", "Bouvet Island"),
+    ("BW", "BWA", "Botswana"),
+    ("BY", "BLR", "Belarus"),
+    ("BZ", "BLZ", "Belize"),
+    ("CA", "CAN", "Canada"),
+    ("CC", "CCK", "Cocos (Keeling) Islands"),
+    ("CD", "COD", "Congo, The Democratic Republic of the"),
+    ("CF", "CAF", "Central African Republic"),
+    ("CG", "COG", "Congo"),
+    ("CH", "CHE", "Switzerland"),
+    ("CI", "CIV", "Côte d'Ivoire"),
+    ("CK", "COK", "Cook Islands"),
+    ("CL", "CHL", "Chile"),
+    ("CM", "CMR", "Cameroon"),
+    ("CN", "CHN", "China"),
+    ("CO", "COL", "Colombia"),
+    ("CR", "CRI", "Costa Rica"),
+    ("CU", "CUB", "Cuba"),
+    ("CV", "CPV", "Cabo Verde"),
+    ("CW", "CUW", "Curaçao"),
+    ("CX", "CXR", "Christmas Island"),
+    ("CY", "CYP", "Cyprus"),
+    ("CZ", "CZE", "Czechia"),
+    ("DE", "DEU", "Germany"),
+    ("DJ", "DJI", "Djibouti"),
+    ("DK", "DNK", "Denmark"),
+    ("DM", "DMA", "Dominica"),
+    ("DO", "DOM", "Dominican Republic"),
+    ("DZ", "DZA", "Algeria"),
+    ("EC", "ECU", "Ecuador"),
+    ("EE", "EST", "Estonia"),
+    ("EG", "EGY", "Egypt"),
+    ("EH", "ESH", "Western Sahara"),
+    ("ER", "ERI", "Eritrea"),
+    ("ES", "ESP", "Spain"),
+    ("ET", "ETH", "Ethiopia"),
+    ("FI", "FIN", "Finland"),
+    ("FJ", "FJI", "Fiji"),
+    ("FK", "FLK", "Falkland Islands (Malvinas)"),
+    ("FM", "FSM", "Micronesia, Federated States of"),
+    ("FO", "FRO", "Faroe Islands"),
+    ("FR", "FRA", "France"),
+    ("GA", "GAB", "Gabon"),
+    ("GB", "GBR", "United Kingdom"),
+    ("GD", "GRD", "Grenada"),
+    ("GE", "GEO", "Georgia"),
+    ("GF", "GUF", "French Guiana"),
+    ("GG", "GGY", "Guernsey"),
+    ("GH", "GHA", "Ghana"),
+    ("GI", "GIB", "Gibraltar"),
+    ("GL", "GRL", "Greenland"),
+    ("GM", "GMB", "Gambia"),
+    ("GN", "GIN", "Guinea"),
+    ("GP", "GLP", "Guadeloupe"),
+    ("GQ", "GNQ", "Equatorial Guinea"),
+    ("GR", "GRC", "Greece"),
+    ("GS", "SGS", "South Georgia and the South Sandwich Islands"),
+    ("GT", "GTM", "Guatemala"),
+    ("GU", "GUM", "Guam"),
+    ("GW", "GNB", "Guinea-Bissau"),
+    ("GY", "GUY", "Guyana"),
+    ("HK", "HKG", "Hong Kong"),
+    ("HM", "HMD", "Heard Island and McDonald Islands"),
+    ("HN", "HND", "Honduras"),
+    ("HR", "HRV", "Croatia"),
+    ("HT", "HTI", "Haiti"),
+    ("HU", "HUN", "Hungary"),
+    ("ID", "IDN", "Indonesia"),
+    ("IE", "IRL", "Ireland"),
+    ("IL", "ISR", "Israel"),
+    ("IM", "IMN", "Isle of Man"),
+    ("IN", "IND", "India"),
+    ("IO", "IOT", "British Indian Ocean Territory"),
+    ("IQ", "IRQ", "Iraq"),
+    ("IR", "IRN", "Iran"),
+    ("IS", "ISL", "Iceland"),
+    ("IT", "ITA", "Italy"),
+    ("JE", "JEY", "Jersey"),
+    ("JM", "JAM", "Jamaica"),
+    ("JO", "JOR", "Jordan"),
+    ("JP", "JPN", "Japan"),
+    ("KE", "KEN", "Kenya"),
+    ("KG", "KGZ", "Kyrgyzstan"),
+    ("KH", "KHM", "Cambodia"),
+    ("KI", "KIR", "Kiribati"),
+    ("KM", "COM", "Comoros"),
+    ("KN", "KNA", "Saint Kitts and Nevis"),
+    ("KP", "PRK", "North Korea"),
+    ("KR", "KOR", "South Korea"),
+    ("KW", "KWT", "Kuwait"),
+    ("KY", "CYM", "Cayman Islands"),
+    ("KZ", "KAZ", "Kazakhstan"),
+    ("LA", "LAO", "Laos"),
+    ("LB", "LBN", "Lebanon"),
+    ("LC", "LCA", "Saint Lucia"),
+    ("LI", "LIE", "Liechtenstein"),
+    ("LK", "LKA", "Sri Lanka"),
+    ("LR", "LBR", "Liberia"),
+    ("LS", "LSO", "Lesotho"),
+    ("LT", "LTU", "Lithuania"),
+    ("LU", "LUX", "Luxembourg"),
+    ("LV", "LVA", "Latvia"),
+    ("LY", "LBY", "Libya"),
+    ("MA", "MAR", "Morocco"),
+    ("MC", "MCO", "Monaco"),
+    ("MD", "MDA", "Moldova"),
+    ("ME", "MNE", "Montenegro"),
+    ("MF", "MAF", "Saint Martin (French part)"),
+    ("MG", "MDG", "Madagascar"),
+    ("MH", "MHL", "Marshall Islands"),
+    ("MK", "MKD", "North Macedonia"),
+    ("ML", "MLI", "Mali"),
+    ("MM", "MMR", "Myanmar"),
+    ("MN", "MNG", "Mongolia"),
+    ("MO", "MAC", "Macao"),
+    ("MP", "MNP", "Northern Mariana Islands"),
+    ("MQ", "MTQ", "Martinique"),
+    ("MR", "MRT", "Mauritania"),
+    ("MS", "MSR", "Montserrat"),
+    ("MT", "MLT", "Malta"),
+    ("MU", "MUS", "Mauritius"),
+    ("MV", "MDV", "Maldives"),
+    ("MW", "MWI", "Malawi"),
+    ("MX", "MEX", "Mexico"),
+    ("MY", "MYS", "Malaysia"),
+    ("MZ", "MOZ", "Mozambique"),
+    ("NA", "NAM", "Namibia"),
+    ("NC", "NCL", "New Caledonia"),
+    ("NE", "NER", "Niger"),
+    ("NF", "NFK", "Norfolk Island"),
+    ("NG", "NGA", "Nigeria"),
+    ("NI", "NIC", "Nicaragua"),
+    ("NL", "NLD", "Netherlands"),
+    ("NO", "NOR", "Norway"),
+    ("NP", "NPL", "Nepal"),
+    ("NR", "NRU", "Nauru"),
+    ("NU", "NIU", "Niue"),
+    ("NZ", "NZL", "New Zealand"),
+    ("OM", "OMN", "Oman"),
+    ("PA", "PAN", "Panama"),
+    ("PE", "PER", "Peru"),
+    ("PF", "PYF", "French Polynesia"),
+    ("PG", "PNG", "Papua New Guinea"),
+    ("PH", "PHL", "Philippines"),
+    ("PK", "PAK", "Pakistan"),
+    ("PL", "POL", "Poland"),
+    ("PM", "SPM", "Saint Pierre and Miquelon"),
+    ("PN", "PCN", "Pitcairn"),
+    ("PR", "PRI", "Puerto Rico"),
+    ("PS", "PSE", "Palestine, State of"),
+    ("PT", "PRT", "Portugal"),
+    ("PW", "PLW", "Palau"),
+    ("PY", "PRY", "Paraguay"),
+    ("QA", "QAT", "Qatar"),
+    ("RE", "REU", "Réunion"),
+    ("RO", "ROU", "Romania"),
+    ("RS", "SRB", "Serbia"),
+    ("RU", "RUS", "Russian Federation"),
+    ("RW", "RWA", "Rwanda"),
+    ("SA", "SAU", "Saudi Arabia"),
+    ("SB", "SLB", "Solomon Islands"),
+    ("SC", "SYC", "Seychelles"),
+    ("SD", "SDN", "Sudan"),
+    ("SE", "SWE", "Sweden"),
+    ("SG", "SGP", "Singapore"),
+    ("SH", "SHN", "Saint Helena, Ascension and Tristan da Cunha"),
+    ("SI", "SVN", "Slovenia"),
+    ("SJ", "SJM", "Svalbard and Jan Mayen"),
+    ("SK", "SVK", "Slovakia"),
+    ("SL", "SLE", "Sierra Leone"),
+    ("SM", "SMR", "San Marino"),
+    ("SN", "SEN", "Senegal"),
+    ("SO", "SOM", "Somalia"),
+    ("SR", "SUR", "Suriname"),
+    ("SS", "SSD", "South Sudan"),
+    ("ST", "STP", "Sao Tome and Principe"),
+    ("SV", "SLV", "El Salvador"),
+    ("SX", "SXM", "Sint Maarten (Dutch part)"),
+    ("SY", "SYR", "Syria"),
+    ("SZ", "SWZ", "Eswatini"),
+    ("TC", "TCA", "Turks and Caicos Islands"),
+    ("TD", "TCD", "Chad"),
+    ("TF", "ATF", "French Southern Territories"),
+    ("TG", "TGO", "Togo"),
+    ("TH", "THA", "Thailand"),
+    ("TJ", "TJK", "Tajikistan"),
+    ("TK", "TKL", "Tokelau"),
+    ("TL", "TLS", "Timor-Leste"),
+    ("TM", "TKM", "Turkmenistan"),
+    ("TN", "TUN", "Tunisia"),
+    ("TO", "TON", "Tonga"),
+    ("TR", "TUR", "Türkiye"),
+    ("TT", "TTO", "Trinidad and Tobago"),
+    ("TV", "TUV", "Tuvalu"),
+    ("TW", "TWN", "Taiwan"),
+    ("TZ", "TZA", "Tanzania"),
+    ("UA", "UKR", "Ukraine"),
+    ("UG", "UGA", "Uganda"),
+    ("UM", "UMI", "United States Minor Outlying Islands"),
+    ("US", "USA", "United States"),
+    ("UY", "URY", "Uruguay"),
+    ("UZ", "UZB", "Uzbekistan"),
+    ("VA", "VAT", "Holy See (Vatican City State)"),
+    ("VC", "VCT", "Saint Vincent and the Grenadines"),
+    ("VE", "VEN", "Venezuela"),
+    ("VG", "VGB", "Virgin Islands, British"),
+    ("VI", "VIR", "Virgin Islands, U.S."),
+    ("VN", "VNM", "Vietnam"),
+    ("VU", "VUT", "Vanuatu"),
+    ("WF", "WLF", "Wallis and Futuna"),
+    ("WS", "WSM", "Samoa"),
+    ("YE", "YEM", "Yemen"),
+    ("YT", "MYT", "Mayotte"),
+    ("ZA", "ZAF", "South Africa"),
+    ("ZM", "ZMB", "Zambia"),
+    ("ZW", "ZWE", "Zimbabwe"),
+];
+
+/// Other names a country is commonly given, besides the one in `ISO_3166_COUNTRIES`.
+const COUNTRY_NAME_ALIASES: [(&str, &str); 28] = [
+    ("Alemania", "DE"),
+    ("Bolivia, Plurinational State of", "BO"),
+    ("Brasil", "BR"),
+    ("Canadá", "CA"),
+    ("España", "ES"),
+    ("Estados Unidos", "US"),
+    ("Francia", "FR"),
+    ("Great Britain", "GB"),
+    ("Inglaterra", "GB"),
+    ("Iran, Islamic Republic of", "IR"),
+    ("Italia", "IT"),
+    ("Japón", "JP"),
+    ("Korea, Democratic People's Republic of", "KP"),
+    ("Korea, Republic of", "KR"),
+    ("Lao People's Democratic Republic", "LA"),
+    ("Moldova, Republic of", "MD"),
+    ("México", "MX"),
+    ("Panamá", "PA"),
+    ("Perú", "PE"),
+    ("Reino Unido", "GB"),
+    ("Syrian Arab Republic", "SY"),
+    ("Taiwan, Province of China", "TW"),
+    ("Tanzania, United Republic of", "TZ"),
+    ("UK", "GB"),
+    ("United States of America", "US"),
+    ("USA", "US"),
+    ("Venezuela, Bolivarian Republic of", "VE"),
+    ("Viet Nam", "VN"),
+];
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-/// A valid country.
+/// A valid ISO 3166-1 country, always kept as its alpha-2 code.
 pub struct Country(String);
 
 impl Country {
+    /// Accepts an alpha-2 code, an alpha-3 code or a name of the country, case insensitive.
     pub fn new(name: &str) -> Result<Self, ClientError> {
-        let name = name.trim();
-        if name.is_empty() {
-            Err(ClientError::FieldEmpty {
+        let name = text::normalize("country", name, MAX_LENGTH_COUNTRY)?;
+        Self::find_code(&name)
+            .map(|code| Country(code.to_string()))
+            .ok_or(ClientError::FieldInvalid {
                 field_name: "country".to_string(),
+                value: name,
             })
-        } else if name.len() > MAX_LENGTH_COUNTRY {
-            Err(ClientError::FieldMaxLength {
-                field_name: "country".to_string(),
-                max_length: MAX_LENGTH_COUNTRY,
+    }
+
+    /// Rebuilds a country from the code it was stored with, without checking the current rules.
+    pub(crate) fn restore(code: &str) -> Self {
+        Country(code.to_string())
+    }
+
+    fn find_code(name: &str) -> Option<&'static str> {
+        let upper = name.to_uppercase();
+        let lower = name.to_lowercase();
+        ISO_3166_COUNTRIES
+            .iter()
+            .find(|(alpha_2, alpha_3, country_name)| {
+                *alpha_2 == upper || *alpha_3 == upper || country_name.to_lowercase() == lower
             })
-        } else {
-            Ok(Country(name.to_string()))
-        }
+            .map(|(alpha_2, _, _)| *alpha_2)
+            .or_else(|| {
+                COUNTRY_NAME_ALIASES
+                    .iter()
+                    .find(|(alias, _)| alias.to_lowercase() == lower)
+                    .map(|(_, alpha_2)| *alpha_2)
+            })
+    }
+
+    /// The ISO 3166-1 alpha-2 code, e.g. `AR`.
+    pub fn code(&self) -> &str {
+        &self.0
+    }
+
+    /// The ISO 3166-1 alpha-3 code, e.g. `ARG`.
+    pub fn alpha_3(&self) -> &'static str {
+        self.entry().1
+    }
+
+    /// The name to show for the country, e.g. `Argentina`.
+    pub fn name(&self) -> &'static str {
+        self.entry().2
+    }
+
+    fn entry(&self) -> &'static (&'static str, &'static str, &'static str) {
+        let index = ISO_3166_COUNTRIES
+            .binary_search_by(|(alpha_2, _, _)| (*alpha_2).cmp(self.0.as_str()))
+            .expect("a country is always built from a known code");
+        &ISO_3166_COUNTRIES[index]
     }
 }
 
@@ -35,10 +362,12 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_01_given_a_valid_country_when_creating_it_then_it_should_be_created() {
+    fn test_01_given_a_valid_country_when_creating_it_then_it_should_be_normalized_to_alpha_2() {
         let country_name = "Argentina";
         let country = Country::new(country_name).unwrap();
-        assert_eq!(country.to_string(), country_name);
+        assert_eq!(country.to_string(), "AR");
+        assert_eq!(country.alpha_3(), "ARG");
+        assert_eq!(country.name(), country_name);
     }
 
     #[test]
@@ -67,14 +396,49 @@ mod tests {
     {
         let country_name = "   Argentina   ";
         let country = Country::new(country_name).unwrap();
-        assert_eq!(country.to_string(), "Argentina");
+        assert_eq!(country.to_string(), "AR");
     }
 
     #[test]
-    fn test_06_given_a_country_with_special_characters_when_creating_it_then_it_should_be_accepted()
-    {
+    fn test_06_given_an_unknown_country_when_creating_it_then_it_should_fail() {
         let country_name = "Côte d'Ivoire!@#";
-        let country = Country::new(country_name).unwrap();
-        assert_eq!(country.to_string(), country_name);
+        let result = Country::new(country_name);
+        assert_eq!(
+            result.err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "country".to_string(),
+                value: country_name.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_07_given_the_same_country_in_different_forms_when_creating_it_then_they_should_be_equal()
+     {
+        let expected = Country::new("AR").unwrap();
+        for country_name in ["ar", "ARG", "arg", "argentina", "ARGENTINA"] {
+            assert_eq!(Country::new(country_name).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_08_given_a_common_name_when_creating_a_country_then_it_should_be_accepted() {
+        assert_eq!(Country::new("Estados Unidos").unwrap().code(), "US");
+        assert_eq!(Country::new("usa").unwrap().code(), "US");
+        assert_eq!(Country::new("Brasil").unwrap().code(), "BR");
+        assert_eq!(Country::new("côte d'ivoire").unwrap().code(), "CI");
+        assert_eq!(
+            Country::new("Korea, Republic of").unwrap().name(),
+            "South Korea"
+        );
+    }
+
+    #[test]
+    fn test_09_given_the_iso_countries_then_they_should_be_sorted_for_binary_search() {
+        assert!(
+            ISO_3166_COUNTRIES
+                .windows(2)
+                .all(|pair| pair[0].0 < pair[1].0)
+        );
     }
 }
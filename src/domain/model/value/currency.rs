@@ -0,0 +1,152 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+/// Active ISO 4217 alphabetic codes, sorted to allow a binary search.
+const ISO_4217_CODES: [&str; 154] = [
+    "AED", "AFN", "ALL", "AMD", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT", "BGN",
+    "BHD", "BIF", "BMD", "BND", "BOB", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD", "CDF",
+    "CHF", "CLP", "CNY", "COP", "CRC", "CUP", "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP",
+    "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD",
+    "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY",
+    "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD",
+    "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK",
+    "MXN", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK",
+    "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG",
+    "SEK", "SGD", "SHP", "SLE", "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS",
+    "TMT", "TND", "TOP", "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "UYU", "UZS", "VES",
+    "VND", "VUV", "WST", "XAF", "XCD", "XOF", "XPF", "YER", "ZAR", "ZMW", "ZWG",
+];
+
+/// Number of decimal places each currency allows, for the ones that do not use 2 (ISO 4217 minor units).
+const MINOR_UNITS_EXCEPTIONS: [(&str, u32); 23] = [
+    ("BHD", 3),
+    ("BIF", 0),
+    ("CLP", 0),
+    ("DJF", 0),
+    ("GNF", 0),
+    ("IQD", 3),
+    ("ISK", 0),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KMF", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("LYD", 3),
+    ("OMR", 3),
+    ("PYG", 0),
+    ("RWF", 0),
+    ("TND", 3),
+    ("UGX", 0),
+    ("VND", 0),
+    ("VUV", 0),
+    ("XAF", 0),
+    ("XOF", 0),
+    ("XPF", 0),
+];
+const DEFAULT_MINOR_UNITS: u32 = 2;
+
+/// The currency of the balances when none is given.
+pub const DEFAULT_CURRENCY: &str = "ARS";
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A valid ISO 4217 currency code, always uppercase.
+pub struct Currency(String);
+
+impl Currency {
+    pub fn new(code: &str) -> Result<Self, ClientError> {
+        let code = code.trim().to_uppercase();
+        if code.is_empty() {
+            Err(ClientError::FieldEmpty {
+                field_name: "currency".to_string(),
+            })
+        } else if ISO_4217_CODES.binary_search(&code.as_str()).is_err() {
+            Err(ClientError::FieldInvalid {
+                field_name: "currency".to_string(),
+                value: code,
+            })
+        } else {
+            Ok(Currency(code))
+        }
+    }
+
+    /// The maximum number of decimal places an amount in this currency can have.
+    pub fn max_scale(&self) -> u32 {
+        MINOR_UNITS_EXCEPTIONS
+            .iter()
+            .find(|(code, _)| *code == self.0)
+            .map_or(DEFAULT_MINOR_UNITS, |(_, minor_units)| *minor_units)
+    }
+}
+
+impl Default for Currency {
+    fn default() -> Self {
+        Currency(DEFAULT_CURRENCY.to_string())
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for Currency {
+    type Error = ClientError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Currency::new(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_a_valid_code_when_creating_currency_then_it_should_be_uppercased() {
+        assert_eq!(Currency::new("USD").unwrap().to_string(), "USD");
+        assert_eq!(Currency::new(" ars ").unwrap().to_string(), "ARS");
+    }
+
+    #[test]
+    fn test_02_given_an_unknown_code_when_creating_currency_then_it_should_fail() {
+        assert_eq!(
+            Currency::new("XYZ").err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "currency".to_string(),
+                value: "XYZ".to_string(),
+            }
+        );
+        assert!(Currency::new("Dollar").is_err());
+    }
+
+    #[test]
+    fn test_03_given_an_empty_code_when_creating_currency_then_it_should_fail() {
+        assert_eq!(
+            Currency::new("  ").err().unwrap(),
+            ClientError::FieldEmpty {
+                field_name: "currency".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_04_given_no_code_when_using_default_currency_then_it_should_be_ars() {
+        assert_eq!(
+            Currency::default(),
+            Currency::new(DEFAULT_CURRENCY).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_05_given_the_iso_codes_then_they_should_be_sorted_for_binary_search() {
+        assert!(ISO_4217_CODES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_06_given_currencies_with_different_minor_units_then_max_scale_should_follow_iso_4217() {
+        assert_eq!(Currency::new("ARS").unwrap().max_scale(), 2);
+        assert_eq!(Currency::new("JPY").unwrap().max_scale(), 0);
+        assert_eq!(Currency::new("KWD").unwrap().max_scale(), 3);
+    }
+}
@@ -1,32 +1,94 @@
 use std::fmt::{Display, Formatter};
 
-use crate::domain::model::{error::ClientError, value::MAX_LENGTH_DOCUMENT};
+use crate::domain::model::{
+    error::ClientError,
+    value::{MAX_LENGTH_DOCUMENT, document_type::DocumentType, text},
+};
+
+const DNI_LENGTHS: [usize; 2] = [7, 8];
+const CUIT_LENGTH: usize = 11;
+/// The weights of the first 10 digits of a CUIT/CUIL to compute its check digit.
+const CUIT_WEIGHTS: [u32; 10] = [5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+const PASSPORT_MIN_LENGTH: usize = 6;
+const PASSPORT_MAX_LENGTH: usize = 9;
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-/// A valid document.
-pub struct Document(String);
+/// A valid identity document. Two documents are the same one only if both the type and the number match.
+pub struct Document {
+    document_type: DocumentType,
+    /// The number without separators: the dots of a DNI and the dashes of a CUIT/CUIL are dropped,
+    /// and a passport is uppercase.
+    number: String,
+}
 
 impl Document {
-    pub fn new(name: &str) -> Result<Self, ClientError> {
-        let name = name.trim();
-        if name.is_empty() {
-            Err(ClientError::FieldEmpty {
-                field_name: "document".to_string(),
-            })
-        } else if name.len() > MAX_LENGTH_DOCUMENT {
-            Err(ClientError::FieldMaxLength {
+    pub fn new(document_type: DocumentType, number: &str) -> Result<Self, ClientError> {
+        let number = text::normalize("document", number, MAX_LENGTH_DOCUMENT)?;
+
+        let normalized = match document_type {
+            DocumentType::Dni => number.replace('.', ""),
+            DocumentType::Cuit | DocumentType::Cuil => number.replace('-', ""),
+            DocumentType::Passport => number.to_uppercase(),
+        };
+        let is_valid = match document_type {
+            DocumentType::Dni => {
+                is_all_digits(&normalized) && DNI_LENGTHS.contains(&normalized.len())
+            }
+            DocumentType::Cuit | DocumentType::Cuil => is_valid_cuit(&normalized),
+            DocumentType::Passport => {
+                normalized.chars().all(|c| c.is_ascii_alphanumeric())
+                    && (PASSPORT_MIN_LENGTH..=PASSPORT_MAX_LENGTH).contains(&normalized.len())
+            }
+        };
+        if !is_valid {
+            return Err(ClientError::FieldInvalid {
                 field_name: "document".to_string(),
-                max_length: MAX_LENGTH_DOCUMENT,
-            })
-        } else {
-            Ok(Document(name.to_string()))
+                value: number.to_string(),
+            });
         }
+
+        Ok(Self {
+            document_type,
+            number: normalized,
+        })
+    }
+
+    pub fn document_type(&self) -> DocumentType {
+        self.document_type
+    }
+
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+}
+
+fn is_all_digits(number: &str) -> bool {
+    number.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Checks the length and the mod-11 check digit of a CUIT/CUIL without separators.
+fn is_valid_cuit(number: &str) -> bool {
+    if number.len() != CUIT_LENGTH || !is_all_digits(number) {
+        return false;
     }
+    let digits: Vec<u32> = number.chars().filter_map(|c| c.to_digit(10)).collect();
+    let sum: u32 = CUIT_WEIGHTS
+        .iter()
+        .zip(&digits)
+        .map(|(weight, digit)| weight * digit)
+        .sum();
+    // A remainder of 1 would need a check digit of 10, which no valid CUIT/CUIL has.
+    let check_digit = match 11 - sum % 11 {
+        11 => 0,
+        10 => return false,
+        check_digit => check_digit,
+    };
+    digits[CUIT_LENGTH - 1] == check_digit
 }
 
 impl Display for Document {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.0)
+        write!(f, "{} {}", self.document_type, self.number)
     }
 }
 
@@ -35,38 +97,81 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_01_given_a_valid_document_when_creating_it_then_it_should_be_created() {
-        let doc = "1234567890";
-        let document = Document::new(doc).unwrap();
-        assert_eq!(document.to_string(), doc);
+    fn test_01_given_a_valid_dni_when_creating_it_then_it_should_be_created() {
+        let document = Document::new(DocumentType::Dni, "30111222").unwrap();
+        assert_eq!(document.document_type(), DocumentType::Dni);
+        assert_eq!(document.number(), "30111222");
+        assert_eq!(document.to_string(), "dni 30111222");
+        assert!(Document::new(DocumentType::Dni, "1234567").is_ok());
     }
 
     #[test]
     fn test_02_given_an_empty_document_when_creating_it_then_it_should_fail() {
         let doc = "";
-        let result = Document::new(doc);
+        let result = Document::new(DocumentType::Dni, doc);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_03_given_a_document_with_only_spaces_when_creating_it_then_it_should_fail() {
         let doc = "    ";
-        let result = Document::new(doc);
+        let result = Document::new(DocumentType::Dni, doc);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_04_given_a_document_exceeding_max_length_when_creating_it_then_it_should_fail() {
         let doc = "a".repeat(MAX_LENGTH_DOCUMENT + 1);
-        let result = Document::new(&doc);
+        let result = Document::new(DocumentType::Passport, &doc);
         assert!(result.is_err());
     }
 
     #[test]
-    fn test_05_given_a_document_with_spaces_when_creating_it_then_it_should_be_trimmed_and_accepted()
+    fn test_05_given_a_document_with_spaces_and_separators_when_creating_it_then_it_should_be_normalized()
      {
-        let doc = "   1234567890   ";
-        let document = Document::new(doc).unwrap();
-        assert_eq!(document.to_string(), "1234567890");
+        let document = Document::new(DocumentType::Dni, "   30.111.222   ").unwrap();
+        assert_eq!(document.number(), "30111222");
+        let document = Document::new(DocumentType::Cuit, "20-30111222-0").unwrap();
+        assert_eq!(document.number(), "20301112220");
+        let document = Document::new(DocumentType::Passport, "aaa123456").unwrap();
+        assert_eq!(document.number(), "AAA123456");
+    }
+
+    #[test]
+    fn test_06_given_a_dni_with_letters_or_a_wrong_length_when_creating_it_then_it_should_fail() {
+        for number in ["abc", "123456", "123456789", "3011122A"] {
+            assert_eq!(
+                Document::new(DocumentType::Dni, number).err().unwrap(),
+                ClientError::FieldInvalid {
+                    field_name: "document".to_string(),
+                    value: number.to_string()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_07_given_a_cuit_or_cuil_when_creating_it_then_its_check_digit_should_be_validated() {
+        assert!(Document::new(DocumentType::Cuit, "30-50001091-2").is_ok());
+        assert!(Document::new(DocumentType::Cuil, "27-12345678-0").is_ok());
+        assert!(Document::new(DocumentType::Cuit, "20-30111222-8").is_err());
+        assert!(Document::new(DocumentType::Cuil, "2030111222").is_err());
+        // The check digit would be 10.
+        assert!(Document::new(DocumentType::Cuit, "20-12345676-0").is_err());
+    }
+
+    #[test]
+    fn test_08_given_a_passport_with_symbols_or_a_wrong_length_when_creating_it_then_it_should_fail()
+     {
+        assert!(Document::new(DocumentType::Passport, "AB-12345").is_err());
+        assert!(Document::new(DocumentType::Passport, "AB123").is_err());
+        assert!(Document::new(DocumentType::Passport, "AB1234567X").is_err());
+    }
+
+    #[test]
+    fn test_09_given_the_same_number_with_another_type_when_comparing_then_they_should_differ() {
+        let dni = Document::new(DocumentType::Dni, "12345678").unwrap();
+        let passport = Document::new(DocumentType::Passport, "12345678").unwrap();
+        assert_ne!(dni, passport);
     }
 }
@@ -0,0 +1,78 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The kind of identity document of a client, which defines how its number is validated.
+pub enum DocumentType {
+    /// Documento Nacional de Identidad: 7 or 8 digits.
+    #[default]
+    Dni,
+    /// Clave Única de Identificación Tributaria: 11 digits, the last one a mod-11 check digit.
+    Cuit,
+    /// Código Único de Identificación Laboral: same format as the CUIT.
+    Cuil,
+    /// 6 to 9 letters or digits.
+    Passport,
+}
+
+impl DocumentType {
+    pub fn new(document_type: &str) -> Result<Self, ClientError> {
+        match document_type.trim().to_lowercase().as_str() {
+            "dni" => Ok(DocumentType::Dni),
+            "cuit" => Ok(DocumentType::Cuit),
+            "cuil" => Ok(DocumentType::Cuil),
+            "passport" => Ok(DocumentType::Passport),
+            _ => Err(ClientError::FieldInvalid {
+                field_name: "document_type".to_string(),
+                value: document_type.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for DocumentType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DocumentType::Dni => f.write_str("dni"),
+            DocumentType::Cuit => f.write_str("cuit"),
+            DocumentType::Cuil => f.write_str("cuil"),
+            DocumentType::Passport => f.write_str("passport"),
+        }
+    }
+}
+
+impl TryFrom<String> for DocumentType {
+    type Error = ClientError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        DocumentType::new(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_a_known_type_when_creating_it_then_it_should_ignore_the_case() {
+        assert_eq!(DocumentType::new("DNI").unwrap(), DocumentType::Dni);
+        assert_eq!(DocumentType::new(" cuit ").unwrap(), DocumentType::Cuit);
+        assert_eq!(DocumentType::new("Cuil").unwrap(), DocumentType::Cuil);
+        assert_eq!(
+            DocumentType::new("passport").unwrap(),
+            DocumentType::Passport
+        );
+        assert_eq!(DocumentType::Passport.to_string(), "passport");
+    }
+
+    #[test]
+    fn test_02_given_an_unknown_type_when_creating_it_then_it_should_fail() {
+        assert_eq!(
+            DocumentType::new("ssn").err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "document_type".to_string(),
+                value: "ssn".to_string()
+            }
+        );
+    }
+}
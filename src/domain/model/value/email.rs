@@ -0,0 +1,139 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::{error::ClientError, value::MAX_LENGTH_EMAIL};
+
+const MAX_LENGTH_LOCAL_PART: usize = 64;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A valid email address, always lowercase.
+pub struct Email(String);
+
+impl Email {
+    pub fn new(email: &str) -> Result<Self, ClientError> {
+        let email = email.trim().to_lowercase();
+        if email.is_empty() {
+            return Err(ClientError::FieldEmpty {
+                field_name: "email".to_string(),
+            });
+        }
+        if email.len() > MAX_LENGTH_EMAIL {
+            return Err(ClientError::FieldMaxLength {
+                field_name: "email".to_string(),
+                max_length: MAX_LENGTH_EMAIL,
+            });
+        }
+
+        let is_valid = email.split_once('@').is_some_and(|(local_part, domain)| {
+            is_valid_local_part(local_part) && is_valid_domain(domain)
+        });
+        if !is_valid {
+            return Err(ClientError::FieldInvalid {
+                field_name: "email".to_string(),
+                value: email,
+            });
+        }
+        Ok(Email(email))
+    }
+
+    /// Rebuilds an email that was valid when it was stored, without checking the current rules.
+    pub(crate) fn restore(email: &str) -> Self {
+        Email(email.to_string())
+    }
+
+    /// The address with the local part hidden but its first character, e.g. `j***@example.com`.
+    pub fn masked(&self) -> String {
+        let (local_part, domain) = self
+            .0
+            .split_once('@')
+            .expect("an email always has a local part and a domain");
+        let first = local_part.chars().next().unwrap_or_default();
+        format!("{first}***@{domain}")
+    }
+}
+
+fn is_valid_local_part(local_part: &str) -> bool {
+    !local_part.is_empty()
+        && local_part.len() <= MAX_LENGTH_LOCAL_PART
+        && !local_part.starts_with('.')
+        && !local_part.ends_with('.')
+        && !local_part.contains("..")
+        && local_part
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c))
+}
+
+/// At least two labels of letters, digits and inner hyphens, e.g. `example.com`.
+fn is_valid_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+impl Display for Email {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = ClientError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Email::new(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_a_valid_email_when_creating_it_then_it_should_be_lowercased() {
+        let email = Email::new("  John.Doe+bank@Example.COM ").unwrap();
+        assert_eq!(email.to_string(), "john.doe+bank@example.com");
+    }
+
+    #[test]
+    fn test_02_given_an_invalid_email_when_creating_it_then_it_should_fail() {
+        for email in [
+            "john",
+            "john@",
+            "@example.com",
+            "john@example",
+            "john@@example.com",
+            "john..doe@example.com",
+            "john doe@example.com",
+            "john@-example.com",
+        ] {
+            assert_eq!(
+                Email::new(email).err().unwrap(),
+                ClientError::FieldInvalid {
+                    field_name: "email".to_string(),
+                    value: email.to_string(),
+                },
+                "{email}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_03_given_an_empty_email_when_creating_it_then_it_should_fail() {
+        assert_eq!(
+            Email::new("  ").err().unwrap(),
+            ClientError::FieldEmpty {
+                field_name: "email".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_04_given_an_email_when_masking_it_then_only_the_first_character_and_the_domain_should_be_shown()
+     {
+        let email = Email::new("john.doe@example.com").unwrap();
+        assert_eq!(email.masked(), "j***@example.com");
+    }
+}
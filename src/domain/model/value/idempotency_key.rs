@@ -0,0 +1,70 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::{error::ClientError, value::MAX_LENGTH_IDEMPOTENCY_KEY};
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A valid idempotency key, chosen by the caller to safely retry a transaction.
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    pub fn new(key: &str) -> Result<Self, ClientError> {
+        let key = key.trim();
+        if key.is_empty() {
+            Err(ClientError::FieldEmpty {
+                field_name: "idempotency_key".to_string(),
+            })
+        } else if key.len() > MAX_LENGTH_IDEMPOTENCY_KEY {
+            Err(ClientError::FieldMaxLength {
+                field_name: "idempotency_key".to_string(),
+                max_length: MAX_LENGTH_IDEMPOTENCY_KEY,
+            })
+        } else {
+            Ok(IdempotencyKey(key.to_string()))
+        }
+    }
+}
+
+impl Display for IdempotencyKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_a_valid_key_when_creating_it_then_it_should_be_created() {
+        let key = IdempotencyKey::new("8e03978e-40d5-43e8-bc93-6894a57f9324").unwrap();
+        assert_eq!(key.to_string(), "8e03978e-40d5-43e8-bc93-6894a57f9324");
+    }
+
+    #[test]
+    fn test_02_given_an_empty_key_when_creating_it_then_it_should_fail() {
+        assert_eq!(
+            IdempotencyKey::new("   ").err().unwrap(),
+            ClientError::FieldEmpty {
+                field_name: "idempotency_key".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_03_given_a_key_exceeding_max_length_when_creating_it_then_it_should_fail() {
+        let key = "a".repeat(MAX_LENGTH_IDEMPOTENCY_KEY + 1);
+        assert_eq!(
+            IdempotencyKey::new(&key).err().unwrap(),
+            ClientError::FieldMaxLength {
+                field_name: "idempotency_key".to_string(),
+                max_length: MAX_LENGTH_IDEMPOTENCY_KEY,
+            }
+        );
+    }
+
+    #[test]
+    fn test_04_given_a_key_with_spaces_when_creating_it_then_it_should_be_trimmed() {
+        let key = IdempotencyKey::new("  retry-1  ").unwrap();
+        assert_eq!(key.to_string(), "retry-1");
+    }
+}
@@ -1,9 +1,38 @@
+pub mod account_id;
+pub mod account_name;
+pub mod authorization_id;
+pub mod authorization_status;
+pub mod balance_range;
 pub mod birth_date;
+pub mod birth_date_rules;
+pub mod client_cursor;
+pub mod client_field;
 pub mod client_id;
 pub mod client_name;
+pub mod client_sort;
+pub mod client_status;
 pub mod country;
+pub mod currency;
 pub mod document;
+pub mod document_type;
+pub mod email;
+pub mod idempotency_key;
+pub mod money;
+pub mod overdraft_limit;
+pub mod phone_number;
+pub mod text;
+pub mod transaction_id;
+pub mod transaction_kind;
+pub mod version;
 
+/// The maximum lengths of the client fields, in graphemes rather than bytes.
 pub const MAX_LENGTH_NAME: usize = 128;
 pub const MAX_LENGTH_DOCUMENT: usize = 64;
-pub const MAX_LENGTH_COUNTRY: usize = 32;
+pub const MAX_LENGTH_COUNTRY: usize = 64;
+/// RFC 5321 limit for a whole address.
+pub const MAX_LENGTH_EMAIL: usize = 254;
+pub const MAX_LENGTH_ACCOUNT_NAME: usize = 64;
+
+pub const MAX_LENGTH_IDEMPOTENCY_KEY: usize = 255;
+/// Long enough for any of the id generation strategies.
+pub const MAX_LENGTH_CLIENT_ID: usize = 64;
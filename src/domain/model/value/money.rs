@@ -0,0 +1,147 @@
+use std::fmt::{Display, Formatter};
+
+use rust_decimal::Decimal;
+
+use crate::domain::model::{error::ClientError, value::currency::Currency};
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An amount in a [Currency], with no more decimal places than the currency allows.
+/// Arithmetic is checked, so an overflow is an error instead of a panic.
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: Decimal, currency: Currency) -> Result<Self, ClientError> {
+        // Trailing zeros do not count, so "10.50" is a valid amount in a currency with 1 decimal.
+        if amount.normalize().scale() > currency.max_scale() {
+            return Err(ClientError::FieldInvalid {
+                field_name: "amount".to_string(),
+                value: amount.to_string(),
+            });
+        }
+
+        Ok(Self { amount, currency })
+    }
+
+    /// Rebuilds an amount that was valid when it was stored, without checking the scale of the
+    /// currency.
+    pub(crate) fn restore(amount: Decimal, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Self {
+            amount: Decimal::ZERO,
+            currency,
+        }
+    }
+
+    pub fn amount(&self) -> &Decimal {
+        &self.amount
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn checked_add(&self, other: &Money) -> Result<Money, ClientError> {
+        self.ensure_same_currency(other)?;
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or(ClientError::AmountOverflow)?;
+        Ok(Self {
+            amount,
+            currency: self.currency.clone(),
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, ClientError> {
+        self.ensure_same_currency(other)?;
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or(ClientError::AmountOverflow)?;
+        Ok(Self {
+            amount,
+            currency: self.currency.clone(),
+        })
+    }
+
+    fn ensure_same_currency(&self, other: &Money) -> Result<(), ClientError> {
+        if self.currency != other.currency {
+            return Err(ClientError::FieldInvalid {
+                field_name: "currency".to_string(),
+                value: other.currency.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ars(amount: Decimal) -> Money {
+        Money::new(amount, Currency::default()).unwrap()
+    }
+
+    #[test]
+    fn test_01_given_more_decimals_than_the_currency_allows_when_creating_money_then_it_should_fail()
+     {
+        assert_eq!(
+            Money::new(Decimal::new(1001, 3), Currency::default())
+                .err()
+                .unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "amount".to_string(),
+                value: "1.001".to_string(),
+            }
+        );
+        assert!(Money::new(Decimal::new(1, 1), Currency::new("JPY").unwrap()).is_err());
+        assert!(Money::new(Decimal::new(10500, 3), Currency::default()).is_ok());
+    }
+
+    #[test]
+    fn test_02_given_two_amounts_when_adding_and_subtracting_then_it_should_keep_the_currency() {
+        let total = ars(Decimal::new(1050, 2))
+            .checked_add(&ars(Decimal::from(5)))
+            .unwrap();
+        assert_eq!(total, ars(Decimal::new(1550, 2)));
+        let rest = total.checked_sub(&ars(Decimal::from(20))).unwrap();
+        assert_eq!(rest.amount(), &Decimal::new(-450, 2));
+        assert_eq!(rest.to_string(), "-4.50 ARS");
+    }
+
+    #[test]
+    fn test_03_given_an_amount_near_the_limit_when_adding_then_it_should_overflow_without_panicking()
+     {
+        let max = ars(Decimal::MAX);
+        assert_eq!(
+            max.checked_add(&ars(Decimal::ONE)).err().unwrap(),
+            ClientError::AmountOverflow
+        );
+        assert_eq!(
+            ars(Decimal::MIN)
+                .checked_sub(&ars(Decimal::ONE))
+                .err()
+                .unwrap(),
+            ClientError::AmountOverflow
+        );
+    }
+
+    #[test]
+    fn test_04_given_amounts_in_different_currencies_when_adding_then_it_should_fail() {
+        let usd = Money::zero(Currency::new("USD").unwrap());
+        assert!(ars(Decimal::ONE).checked_add(&usd).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt::{Display, Formatter};
+
+use rust_decimal::Decimal;
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// How far below zero the balance of a client may go. Zero by default, so no overdraft is allowed.
+pub struct OverdraftLimit(Decimal);
+
+impl OverdraftLimit {
+    pub fn new(limit: Decimal) -> Result<Self, ClientError> {
+        if limit < Decimal::ZERO {
+            return Err(ClientError::FieldInvalid {
+                field_name: "overdraft_limit".to_string(),
+                value: limit.to_string(),
+            });
+        }
+        Ok(Self(limit))
+    }
+
+    pub fn value(&self) -> &Decimal {
+        &self.0
+    }
+
+    /// Returns if the balance stays above the floor set by the limit.
+    pub fn allows(&self, balance: &Decimal) -> bool {
+        *balance >= -self.0
+    }
+}
+
+impl Display for OverdraftLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_a_positive_limit_when_creating_it_then_it_should_be_created() {
+        let limit = OverdraftLimit::new(Decimal::from(100)).unwrap();
+        assert_eq!(limit.value(), &Decimal::from(100));
+        assert_eq!(limit.to_string(), "100");
+    }
+
+    #[test]
+    fn test_02_given_a_negative_limit_when_creating_it_then_it_should_fail() {
+        assert_eq!(
+            OverdraftLimit::new(Decimal::from(-1)).err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "overdraft_limit".to_string(),
+                value: "-1".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_03_given_the_default_limit_then_it_should_be_zero_and_reject_negative_balances() {
+        let limit = OverdraftLimit::default();
+        assert_eq!(limit.value(), &Decimal::ZERO);
+        assert!(limit.allows(&Decimal::ZERO));
+        assert!(!limit.allows(&Decimal::new(-1, 2)));
+    }
+
+    #[test]
+    fn test_04_given_a_limit_when_checking_balances_then_the_floor_should_be_inclusive() {
+        let limit = OverdraftLimit::new(Decimal::from(50)).unwrap();
+        assert!(limit.allows(&Decimal::from(10)));
+        assert!(limit.allows(&Decimal::from(-50)));
+        assert!(!limit.allows(&Decimal::from(-51)));
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+/// E.164 allows up to 15 digits including the country code.
+const MAX_DIGITS: usize = 15;
+const MIN_DIGITS: usize = 8;
+/// How many of the last digits are left visible when the number is masked.
+const VISIBLE_DIGITS: usize = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A valid phone number in E.164 format, e.g. `+5491123456789`.
+pub struct PhoneNumber(String);
+
+impl PhoneNumber {
+    /// Accepts the number with its country code, starting with `+` or `00`, and with spaces, dashes,
+    /// dots or parentheses between the digits.
+    pub fn new(phone: &str) -> Result<Self, ClientError> {
+        let phone = phone.trim();
+        if phone.is_empty() {
+            return Err(ClientError::FieldEmpty {
+                field_name: "phone".to_string(),
+            });
+        }
+
+        let invalid = || ClientError::FieldInvalid {
+            field_name: "phone".to_string(),
+            value: phone.to_string(),
+        };
+        let number = phone
+            .strip_prefix('+')
+            .or_else(|| phone.strip_prefix("00"))
+            .ok_or_else(invalid)?;
+        let digits: String = number
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+            .collect();
+        if !digits.chars().all(|c| c.is_ascii_digit())
+            || !(MIN_DIGITS..=MAX_DIGITS).contains(&digits.len())
+            || digits.starts_with('0')
+        {
+            return Err(invalid());
+        }
+        Ok(PhoneNumber(format!("+{digits}")))
+    }
+
+    /// Rebuilds a phone number that was valid when it was stored, without checking the current
+    /// rules.
+    pub(crate) fn restore(phone: &str) -> Self {
+        PhoneNumber(phone.to_string())
+    }
+
+    /// The number with all the digits hidden but the last ones, e.g. `+*********6789`.
+    pub fn masked(&self) -> String {
+        let hidden = self.0.len() - 1 - VISIBLE_DIGITS;
+        format!(
+            "+{}{}",
+            "*".repeat(hidden),
+            &self.0[self.0.len() - VISIBLE_DIGITS..]
+        )
+    }
+}
+
+impl Display for PhoneNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for PhoneNumber {
+    type Error = ClientError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        PhoneNumber::new(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_a_formatted_number_when_creating_it_then_it_should_be_normalized_to_e164() {
+        let expected = "+5491123456789";
+        for phone in [
+            "+54 9 11 2345-6789",
+            "+54 (9) 11.2345.6789",
+            "0054 9 11 2345 6789",
+            expected,
+        ] {
+            assert_eq!(PhoneNumber::new(phone).unwrap().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_02_given_an_invalid_number_when_creating_it_then_it_should_fail() {
+        for phone in [
+            "11 2345-6789",
+            "+54 11 abc",
+            "+1234567",
+            "+1234567890123456",
+            "+0123456789",
+        ] {
+            assert_eq!(
+                PhoneNumber::new(phone).err().unwrap(),
+                ClientError::FieldInvalid {
+                    field_name: "phone".to_string(),
+                    value: phone.to_string(),
+                },
+                "{phone}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_03_given_a_number_when_masking_it_then_only_the_last_digits_should_be_shown() {
+        let phone = PhoneNumber::new("+5491123456789").unwrap();
+        assert_eq!(phone.masked(), "+*********6789");
+    }
+}
@@ -0,0 +1,128 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::domain::model::error::ClientError;
+
+/// Characters that are not controls but are invisible or change the direction of the text around them.
+const FORBIDDEN_CHARS: [char; 15] = [
+    '\u{061C}', // arabic letter mark
+    '\u{200B}', // zero width space
+    '\u{200C}', // zero width non-joiner
+    '\u{200D}', // zero width joiner
+    '\u{200E}', // left-to-right mark
+    '\u{200F}', // right-to-left mark
+    '\u{202A}', // left-to-right embedding
+    '\u{202B}', // right-to-left embedding
+    '\u{202C}', // pop directional formatting
+    '\u{202D}', // left-to-right override
+    '\u{202E}', // right-to-left override
+    '\u{2060}', // word joiner
+    '\u{2066}', // left-to-right isolate
+    '\u{2067}', // right-to-left isolate
+    '\u{FEFF}', // zero width no-break space
+];
+
+/// Normalizes a text given by a user: NFC, without leading or trailing whitespace and with every run
+/// of whitespace inside collapsed into a single space. The length is measured in graphemes, so an
+/// accented letter counts as one whatever its encoding.
+///
+/// # Errors
+///
+/// - [ClientError::FieldEmpty] if nothing is left after the normalization.
+/// - [ClientError::FieldInvalid] if it has control, zero-width or bidirectional formatting characters.
+/// - [ClientError::FieldMaxLength] if it has more than `max_length` graphemes.
+pub(crate) fn normalize(
+    field_name: &str,
+    value: &str,
+    max_length: usize,
+) -> Result<String, ClientError> {
+    let normalized = value
+        .nfc()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if normalized.is_empty() {
+        Err(ClientError::FieldEmpty {
+            field_name: field_name.to_string(),
+        })
+    } else if normalized
+        .chars()
+        .any(|c| c.is_control() || FORBIDDEN_CHARS.contains(&c))
+    {
+        Err(ClientError::FieldInvalid {
+            field_name: field_name.to_string(),
+            value: normalized.escape_debug().to_string(),
+        })
+    } else if normalized.graphemes(true).count() > max_length {
+        Err(ClientError::FieldMaxLength {
+            field_name: field_name.to_string(),
+            max_length,
+        })
+    } else {
+        Ok(normalized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_a_decomposed_text_when_normalizing_it_then_it_should_be_composed() {
+        let decomposed = "Jose\u{0301} Pen\u{0303}a";
+        assert_eq!(normalize("name", decomposed, 128).unwrap(), "José Peña");
+    }
+
+    #[test]
+    fn test_02_given_a_text_with_inner_whitespace_when_normalizing_it_then_it_should_be_collapsed()
+    {
+        assert_eq!(
+            normalize("name", "  John \t  Doe\u{00A0}Smith ", 128).unwrap(),
+            "John Doe Smith"
+        );
+    }
+
+    #[test]
+    fn test_03_given_a_text_with_accents_when_checking_its_length_then_graphemes_should_be_counted()
+    {
+        let name = "ñ".repeat(10);
+        assert!(name.len() > 10);
+        assert_eq!(normalize("name", &name, 10).unwrap(), name);
+        assert_eq!(
+            normalize("name", &"n\u{0303}".repeat(11), 10)
+                .err()
+                .unwrap(),
+            ClientError::FieldMaxLength {
+                field_name: "name".to_string(),
+                max_length: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn test_04_given_a_text_with_invisible_or_bidi_characters_when_normalizing_it_then_it_should_fail()
+     {
+        for value in [
+            "John\u{0000}Doe",
+            "John\u{200B}Doe",
+            "John\u{202E}Doe",
+            "\u{FEFF}John",
+        ] {
+            assert!(matches!(
+                normalize("name", value, 128),
+                Err(ClientError::FieldInvalid { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_05_given_only_whitespace_when_normalizing_it_then_it_should_fail() {
+        assert_eq!(
+            normalize("name", " \t\n ", 128).err().unwrap(),
+            ClientError::FieldEmpty {
+                field_name: "name".to_string(),
+            }
+        );
+    }
+}
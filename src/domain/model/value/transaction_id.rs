@@ -0,0 +1,68 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A valid transaction id.
+pub struct TransactionId(usize);
+
+impl TransactionId {
+    pub fn new(id: &str) -> Result<Self, ClientError> {
+        let id_trimmed = id.trim();
+        match id_trimmed.parse::<usize>() {
+            Ok(id) => Ok(Self(id)),
+            Err(_) => Err(ClientError::FieldInvalid {
+                field_name: "transaction_id".to_string(),
+                value: id.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for TransactionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_string())
+    }
+}
+
+impl TryFrom<String> for TransactionId {
+    type Error = ClientError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        TransactionId::new(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_valid_integer_string_when_creating_transaction_id_then_it_should_be_created() {
+        let transaction_id = TransactionId::new("42").unwrap();
+        assert_eq!(transaction_id.to_string(), "42");
+    }
+
+    #[test]
+    fn test_02_given_invalid_string_when_try_from_then_it_should_fail() {
+        let transaction_id = TransactionId::try_from("not-a-number".to_string());
+        assert_eq!(
+            transaction_id.err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "transaction_id".to_string(),
+                value: "not-a-number".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_03_given_empty_string_when_try_from_then_it_should_fail() {
+        assert!(TransactionId::try_from("".to_string()).is_err());
+        assert!(TransactionId::try_from("   ".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_04_given_string_with_spaces_when_try_from_then_it_should_be_trimmed() {
+        let transaction_id = TransactionId::try_from(" 7 ".to_string()).unwrap();
+        assert_eq!(transaction_id.to_string(), "7");
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The kind of movement recorded by a transaction.
+pub enum TransactionKind {
+    Credit,
+    Debit,
+    /// The credited side of a transfer between clients.
+    TransferIn,
+    /// The debited side of a transfer between clients.
+    TransferOut,
+    /// A balance change made by `store_balances`: the reset to zero, or the restoration of the
+    /// previous balance when the export fails.
+    Adjustment,
+}
+
+impl TransactionKind {
+    pub fn new(kind: &str) -> Result<Self, ClientError> {
+        match kind.trim().to_lowercase().as_str() {
+            "credit" => Ok(TransactionKind::Credit),
+            "debit" => Ok(TransactionKind::Debit),
+            "transfer_in" => Ok(TransactionKind::TransferIn),
+            "transfer_out" => Ok(TransactionKind::TransferOut),
+            "adjustment" => Ok(TransactionKind::Adjustment),
+            _ => Err(ClientError::FieldInvalid {
+                field_name: "kind".to_string(),
+                value: kind.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for TransactionKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransactionKind::Credit => f.write_str("credit"),
+            TransactionKind::Debit => f.write_str("debit"),
+            TransactionKind::TransferIn => f.write_str("transfer_in"),
+            TransactionKind::TransferOut => f.write_str("transfer_out"),
+            TransactionKind::Adjustment => f.write_str("adjustment"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_each_kind_when_display_then_it_should_be_snake_case() {
+        assert_eq!(TransactionKind::Credit.to_string(), "credit");
+        assert_eq!(TransactionKind::Debit.to_string(), "debit");
+        assert_eq!(TransactionKind::TransferIn.to_string(), "transfer_in");
+        assert_eq!(TransactionKind::TransferOut.to_string(), "transfer_out");
+        assert_eq!(TransactionKind::Adjustment.to_string(), "adjustment");
+    }
+
+    #[test]
+    fn test_02_given_each_kind_when_parsing_its_display_then_it_should_be_the_same() {
+        for kind in [
+            TransactionKind::Credit,
+            TransactionKind::Debit,
+            TransactionKind::TransferIn,
+            TransactionKind::TransferOut,
+            TransactionKind::Adjustment,
+        ] {
+            assert_eq!(TransactionKind::new(&kind.to_string()).unwrap(), kind);
+        }
+        assert_eq!(
+            TransactionKind::new("unknown").err().unwrap(),
+            ClientError::FieldInvalid {
+                field_name: "kind".to_string(),
+                value: "unknown".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::model::error::ClientError;
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The version of a [Client] and its balances, increased by every change to any of them.
+pub struct Version(u64);
+
+impl Version {
+    pub fn new(version: &str) -> Result<Self, ClientError> {
+        let version_trimmed = version.trim();
+        match version_trimmed.parse::<u64>() {
+            Ok(version) => Ok(Self(version)),
+            Err(_) => Err(ClientError::FieldInvalid {
+                field_name: "version".to_string(),
+                value: version.to_string(),
+            }),
+        }
+    }
+
+    /// The version that follows this one.
+    pub fn next(self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+/// A new [Client] starts at version 1.
+impl Default for Version {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_string())
+    }
+}
+
+impl TryFrom<String> for Version {
+    type Error = ClientError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Version::new(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_01_given_valid_integer_string_when_creating_version_then_it_should_be_created() {
+        let version = Version::new(" 42 ").unwrap();
+        assert_eq!(version.to_string(), "42");
+    }
+
+    #[test]
+    fn test_02_given_invalid_string_when_try_from_then_it_should_fail() {
+        for value in ["not-a-number", "-1", "\"1\""] {
+            assert_eq!(
+                Version::try_from(value.to_string()).err().unwrap(),
+                ClientError::FieldInvalid {
+                    field_name: "version".to_string(),
+                    value: value.to_string(),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_03_given_a_version_when_getting_the_next_one_then_it_should_be_greater() {
+        let version = Version::default();
+        assert_eq!(version.to_string(), "1");
+        assert!(version.next() > version);
+        assert_eq!(version.next().to_string(), "2");
+    }
+}
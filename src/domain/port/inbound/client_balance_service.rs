@@ -2,15 +2,24 @@ use crate::domain::model::entity::client::Client;
 use crate::domain::model::error::ClientError;
 use crate::domain::model::{
     dto::{
+        authorize::AuthorizeRequest, batch_transaction::BatchTransactionRequest,
+        capture_authorization::CaptureAuthorizationRequest, create_account::CreateAccountRequest,
         create_client::CreateClientRequest, credit_transaction::CreditTransactionRequest,
         debit_transaction::DebitTransactionRequest, get_balance::GetClientRequest,
+        list_clients::ListClientsRequest, reverse_transaction::ReverseTransactionRequest,
+        transfer::TransferRequest, update_client::UpdateClientRequest,
+    },
+    entity::{
+        account::Account, authorization::Authorization, balance::Balance,
+        client_change::ClientChange, client_overview::ClientOverview, client_page::ClientPage,
+        transaction::Transaction,
+    },
+    value::{
+        authorization_id::AuthorizationId, client_id::ClientId, document::Document,
+        version::Version,
     },
-    entity::balance::Balance,
 };
 
-#[allow(unused_imports)]
-use crate::domain::model::value::document::Document;
-
 /// `ClientBalanceService` is the public API for the balance client domain.
 pub trait ClientBalanceService: Send + Sync + 'static {
     /// Asynchronously create a new [Client]. Returns the created [Client].
@@ -23,6 +32,59 @@ pub trait ClientBalanceService: Send + Sync + 'static {
         req: &CreateClientRequest,
     ) -> impl Future<Output = Result<Client, ClientError>> + Send;
 
+    /// Asynchronously open a new [Account] for a [Client], such as a savings pocket. Returns the created [Account].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    /// - [ClientError::ClientBlocked] or [ClientError::ClientClosed] if the [Client] cannot operate.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
+    fn create_account(
+        &self,
+        req: &CreateAccountRequest,
+    ) -> impl Future<Output = Result<Account, ClientError>> + Send;
+
+    /// Asynchronously get the [Client] by [Document]. Returns the [Client].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundByDocument] if no [Client] has the [Document].
+    fn get_client_by_document(
+        &self,
+        document: &Document,
+    ) -> impl Future<Output = Result<Client, ClientError>> + Send;
+
+    /// Asynchronously list the [Client]s that match the filters of the request, one page at a time.
+    /// Returns the [ClientPage] with the cursor of the next page, if there is one.
+    fn list_clients(
+        &self,
+        req: &ListClientsRequest,
+    ) -> impl Future<Output = Result<ClientPage, ClientError>> + Send;
+
+    /// Asynchronously correct the details of a [Client], keeping the old and new values of each
+    /// changed one in its audit trail. Returns the updated [Client].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    /// - [ClientError::Duplicate] if another [Client] already has the new [Document].
+    /// - [ClientError::ClientClosed] if the [Client] is closed.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
+    fn update_client(
+        &self,
+        req: &UpdateClientRequest,
+    ) -> impl Future<Output = Result<Client, ClientError>> + Send;
+
+    /// Asynchronously get the audit trail of a [Client]. Returns its [ClientChange]s, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    fn get_client_changes(
+        &self,
+        req: &GetClientRequest,
+    ) -> impl Future<Output = Result<Vec<ClientChange>, ClientError>> + Send;
+
     /// Asynchronously get the [Client] by id. Returns the [Client].
     ///
     /// # Errors
@@ -33,31 +95,154 @@ pub trait ClientBalanceService: Send + Sync + 'static {
         req: &GetClientRequest,
     ) -> impl Future<Output = Result<Client, ClientError>> + Send;
 
-    /// Asynchronously credit the balance of a [Client]. Returns the updated [Balance].
+    /// Asynchronously get the [Client] by id with its [Balance]s and [Account]s, all read at once. Returns the
+    /// [ClientOverview].
     ///
     /// # Errors
     ///
     /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    fn get_client_overview(
+        &self,
+        req: &GetClientRequest,
+    ) -> impl Future<Output = Result<ClientOverview, ClientError>> + Send;
+
+    /// Asynchronously credit the balance of an [Account] of a [Client], its main one unless the request
+    /// sets another. Returns the updated [Balance] of the [Account].
+    ///
+    /// A retry with the same idempotency key and payload returns the originally updated [Balance]
+    /// without applying the amount again.
+    ///
+    /// If the request has a balance currency other than the currency of the amount, the amount is
+    /// converted with the current exchange rate and the [Balance] carries the applied conversion.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    /// - [ClientError::AccountNotFound] if the [Account] does not exist or belongs to another [Client].
+    /// - [ClientError::ClientBlocked] if the [Client] is blocked.
+    /// - [ClientError::ClientClosed] if the [Client] is closed.
     /// - [ClientError::NegativeAmount] if the amount is negative.
     /// - [ClientError::ZeroAmount] if the amount is zero.
+    /// - [ClientError::IdempotencyKeyConflict] if the idempotency key was already used with a different request.
+    /// - [ClientError::ExchangeRateNotFound] if there is no exchange rate between both currencies.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
     fn credit_balance(
         &self,
         req: &CreditTransactionRequest,
     ) -> impl Future<Output = Result<Balance, ClientError>> + Send;
 
-    /// Asynchronously debit the balance of a [Client]. Returns the updated [Balance].
+    /// Asynchronously debit the balance of an [Account] of a [Client], its main one unless the request
+    /// sets another. Returns the updated [Balance] of the [Account].
+    ///
+    /// A retry with the same idempotency key and payload returns the originally updated [Balance]
+    /// without applying the amount again.
+    ///
+    /// If the request has a balance currency other than the currency of the amount, the amount is
+    /// converted with the current exchange rate and the [Balance] carries the applied conversion.
     ///
     /// # Errors
     ///
     /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    /// - [ClientError::AccountNotFound] if the [Account] does not exist or belongs to another [Client].
+    /// - [ClientError::ClientBlocked] if the [Client] is blocked.
+    /// - [ClientError::ClientClosed] if the [Client] is closed.
     /// - [ClientError::PositiveAmount] if the amount is positive.
     /// - [ClientError::ZeroAmount] if the amount is zero.
+    /// - [ClientError::InsufficientFunds] if the debit would leave the balance below the overdraft limit of the [Client].
+    /// - [ClientError::IdempotencyKeyConflict] if the idempotency key was already used with a different request.
+    /// - [ClientError::ExchangeRateNotFound] if there is no exchange rate between both currencies.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
     fn debit_balance(
         &self,
         req: &DebitTransactionRequest,
     ) -> impl Future<Output = Result<Balance, ClientError>> + Send;
 
-    /// Asynchronously get the balance of a [Client]. Returns the [Balance].
+    /// Asynchronously transfer balance from one [Client] to another as a single atomic operation.
+    /// Returns the updated [Balance]s of the origin and destination [Client]s, in that order.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if any of the [Client]s does not exist.
+    /// - [ClientError::InsufficientFunds] if the debit would leave the origin balance below its overdraft limit.
+    /// - [ClientError::SelfTransfer] if the origin and destination [Client]s are the same.
+    /// - [ClientError::NegativeAmount] if the amount is negative.
+    /// - [ClientError::ZeroAmount] if the amount is zero.
+    fn transfer(
+        &self,
+        req: &TransferRequest,
+    ) -> impl Future<Output = Result<(Balance, Balance), ClientError>> + Send;
+
+    /// Asynchronously apply a batch of credits and debits as a single atomic operation.
+    /// Returns the [Balance] left by each item, in the order of the items.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::BatchItemRejected] with the index of the first item that cannot be applied,
+    ///   and the reason, such as [ClientError::NotFoundById] or [ClientError::InsufficientFunds].
+    fn apply_batch(
+        &self,
+        req: &BatchTransactionRequest,
+    ) -> impl Future<Output = Result<Vec<Balance>, ClientError>> + Send;
+
+    /// Asynchronously reverse a credit or debit [Transaction], fully or partially, with a compensating
+    /// [Transaction] of the opposite sign linked to it, in the same [Account]. Returns the updated [Balance] of the [Account].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::TransactionNotFound] if the [Transaction] does not exist.
+    /// - [ClientError::TransactionAlreadyReversed] if the [Transaction] was already reversed.
+    /// - [ClientError::TransactionNotReversible] if the [Transaction] is not a credit or a debit.
+    /// - [ClientError::ReversalExceedsOriginal] if the amount exceeds the amount of the [Transaction].
+    /// - [ClientError::InsufficientFunds] if reversing a credit would leave the balance below the overdraft limit.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
+    fn reverse_transaction(
+        &self,
+        req: &ReverseTransactionRequest,
+    ) -> impl Future<Output = Result<Balance, ClientError>> + Send;
+
+    /// Asynchronously reserve funds of a [Client] without moving them. Returns the pending [Authorization].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    /// - [ClientError::InsufficientFunds] if the hold would leave the available balance below the overdraft limit.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
+    fn authorize(
+        &self,
+        req: &AuthorizeRequest,
+    ) -> impl Future<Output = Result<Authorization, ClientError>> + Send;
+
+    /// Asynchronously capture a pending [Authorization] as a debit, possibly for a lower amount.
+    /// Returns the updated [Balance].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::AuthorizationNotFound] if the [Authorization] does not exist.
+    /// - [ClientError::AuthorizationExpired] if the [Authorization] has expired.
+    /// - [ClientError::AuthorizationNotPending] if the [Authorization] was already captured or voided.
+    /// - [ClientError::CaptureExceedsAuthorization] if the amount exceeds the reserved one.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
+    fn capture_authorization(
+        &self,
+        req: &CaptureAuthorizationRequest,
+    ) -> impl Future<Output = Result<Balance, ClientError>> + Send;
+
+    /// Asynchronously void a pending [Authorization], releasing its funds. Returns the voided [Authorization].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::AuthorizationNotFound] if the [Authorization] does not exist.
+    /// - [ClientError::AuthorizationExpired] if the [Authorization] has expired.
+    /// - [ClientError::AuthorizationNotPending] if the [Authorization] was already captured or voided.
+    /// - [ClientError::VersionConflict] if it expects a version of the [Client] it is no longer at.
+    fn void_authorization(
+        &self,
+        authorization_id: &AuthorizationId,
+        expected_version: Option<Version>,
+    ) -> impl Future<Output = Result<Authorization, ClientError>> + Send;
+
+    /// Asynchronously get the balance of a [Client] in the currency of the request. Returns the [Balance],
+    /// with its ledger and available amounts.
     ///
     /// # Errors
     ///
@@ -67,7 +252,81 @@ pub trait ClientBalanceService: Send + Sync + 'static {
         req: &GetClientRequest,
     ) -> impl Future<Output = Result<Balance, ClientError>> + Send;
 
-    /// Asynchronously set the balances of all [Balance]s to zero and export the previous balances to the external system.
+    /// Asynchronously get the balances of a [Client], one per currency added up across its [Account]s.
+    /// Returns the [Balance]s sorted by currency.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    fn get_balances_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> impl Future<Output = Result<Vec<Balance>, ClientError>> + Send;
+
+    /// Asynchronously get the [Account]s of a [Client], the main one first. Returns each [Account] with its
+    /// [Balance]s, one per currency.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    fn get_accounts_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> impl Future<Output = Result<Vec<(Account, Vec<Balance>)>, ClientError>> + Send;
+
+    /// Asynchronously get the ledger of a [Client]. Returns its [Transaction]s, newest first.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    fn get_transactions_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> impl Future<Output = Result<Vec<Transaction>, ClientError>> + Send;
+
+    /// Asynchronously block a [Client], so its balances cannot be credited or debited until it is unblocked.
+    /// Returns the blocked [Client].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    /// - [ClientError::InvalidStatusTransition] if the [Client] is not active.
+    /// - [ClientError::VersionConflict] if it expects a version of the [Client] it is no longer at.
+    fn block_client(
+        &self,
+        client_id: &ClientId,
+        expected_version: Option<Version>,
+    ) -> impl Future<Output = Result<Client, ClientError>> + Send;
+
+    /// Asynchronously unblock a blocked [Client]. Returns the active [Client].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    /// - [ClientError::InvalidStatusTransition] if the [Client] is not blocked.
+    /// - [ClientError::VersionConflict] if it expects a version of the [Client] it is no longer at.
+    fn unblock_client(
+        &self,
+        client_id: &ClientId,
+        expected_version: Option<Version>,
+    ) -> impl Future<Output = Result<Client, ClientError>> + Send;
+
+    /// Asynchronously close a [Client] for good. Returns the closed [Client].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if the [Client] does not exist.
+    /// - [ClientError::InvalidStatusTransition] if the [Client] is already closed.
+    /// - [ClientError::BalanceNotZero] if any of its balances is not zero or it has funds on hold.
+    /// - [ClientError::VersionConflict] if it expects a version of the [Client] it is no longer at.
+    fn close_client(
+        &self,
+        client_id: &ClientId,
+        expected_version: Option<Version>,
+    ) -> impl Future<Output = Result<Client, ClientError>> + Send;
+
+    /// Asynchronously set the balances of all [Balance]s to zero and export the previous balances to the external system,
+    /// one per [Account] and currency. The balances of closed [Client]s are skipped.
     ///
     /// # Errors
     ///
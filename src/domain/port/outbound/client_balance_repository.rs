@@ -1,10 +1,21 @@
-use crate::domain::model::entity::balance::Balance;
+use crate::domain::model::entity::{
+    account::Account, authorization::Authorization, balance::Balance, balance_reset::BalanceReset,
+    client_change::ClientChange, client_overview::ClientOverview, client_page::ClientPage,
+    transaction::Transaction,
+};
 use crate::domain::model::error::ClientError;
-use crate::domain::model::value::client_id::ClientId;
+use crate::domain::model::value::{
+    authorization_id::AuthorizationId, client_id::ClientId, client_status::ClientStatus,
+    transaction_id::TransactionId, version::Version,
+};
 use crate::domain::model::{
     dto::{
+        authorize::AuthorizeRequest, batch_transaction::BatchTransactionRequest,
+        capture_authorization::CaptureAuthorizationRequest, create_account::CreateAccountRequest,
         create_client::CreateClientRequest, credit_transaction::CreditTransactionRequest,
         debit_transaction::DebitTransactionRequest, get_balance::GetClientRequest,
+        list_clients::ListClientsRequest, transfer::TransferRequest,
+        update_client::UpdateClientRequest,
     },
     entity::client::Client,
 };
@@ -13,19 +24,76 @@ use crate::domain::model::{
 use crate::domain::model::value::document::Document;
 
 /// `ClientRepository` represents a store of all [Client]s.
+///
+/// Every change to a [Client] or to the balances of its [Account]s moves the [Client] to its next [Version],
+/// which the [Balance]s it returns carry along.
 #[cfg_attr(test, mockall::automock)]
 pub trait ClientBalanceRepository: Send + Sync + 'static {
-    /// Asynchronously persist a new [Client]. Returns the created [Client].
+    /// Asynchronously persist a new [Client], along with its main [Account]. Returns the created [Client].
     ///
     /// # Errors
     ///
     /// - [ClientError::Duplicate] if an [Client] with the same [Document] already exists.
+    /// - [ClientError::DuplicateContact] if contacts must be unique and another [Client] already has
+    ///   the same email or phone.
     /// - [ClientError::Unknown] if the [Client] cannot be created.
     fn create_client(
         &self,
         req: &CreateClientRequest,
     ) -> impl Future<Output = Result<Client, ClientError>> + Send;
 
+    /// Asynchronously open a new [Account] for a [Client], with a zero balance. Returns the created [Account].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::ClientBlocked] or [ClientError::ClientClosed] if the [Client] cannot operate.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
+    /// - [ClientError::Unknown] if the [Account] cannot be created.
+    fn create_account(
+        &self,
+        req: &CreateAccountRequest,
+    ) -> impl Future<Output = Result<Account, ClientError>> + Send;
+
+    /// Asynchronously correct the details of a [Client] set in the request, appending a [ClientChange]
+    /// to its audit trail for each one that changed. Returns the updated [Client].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::Duplicate] if another [Client] already has the new [Document].
+    /// - [ClientError::DuplicateContact] if contacts must be unique and another [Client] already has
+    ///   the new email or phone.
+    /// - [ClientError::ClientClosed] if the [Client] is closed.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
+    /// - [ClientError::Unknown] if the [Client] cannot be updated.
+    fn update_client(
+        &self,
+        req: &UpdateClientRequest,
+    ) -> impl Future<Output = Result<Client, ClientError>> + Send;
+
+    /// Asynchronously get the audit trail of a [Client], in the order the changes were made.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::Unknown] if the [ClientChange]s cannot be found.
+    fn get_client_changes(
+        &self,
+        req: &GetClientRequest,
+    ) -> impl Future<Output = Result<Vec<ClientChange>, ClientError>> + Send;
+
+    /// Asynchronously get the page of [Client]s that match every filter of the request, in its order,
+    /// starting right after its cursor. Each [Client] comes with its [Balance] in the currency of the request.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::Unknown] if the [Client]s cannot be listed.
+    fn list_clients(
+        &self,
+        req: &ListClientsRequest,
+    ) -> impl Future<Output = Result<ClientPage, ClientError>> + Send;
+
     /// Asynchronously check if a [ClientId] exists.
     ///
     /// # Errors
@@ -48,33 +116,143 @@ pub trait ClientBalanceRepository: Send + Sync + 'static {
         document: &Document,
     ) -> impl Future<Output = Result<Client, ClientError>> + Send;
 
-    /// Asynchronously credit the balance of a [Client]. Returns the updated [Balance].
+    /// Asynchronously credit the balance of an [Account] of a [Client], its main one unless the request
+    /// sets another, and append the [Transaction] to its ledger. Returns the updated [Balance] of the [Account].
+    ///
+    /// A reversal can only target the [Account] of the reversed [Transaction].
+    ///
+    /// If the request carries a conversion, the converted amount is applied to the balance in the
+    /// converted currency, and the conversion is kept in the [Transaction] and the [Balance].
     ///
     /// # Errors
     ///
     /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::AccountNotFound] if the [Account] does not exist or belongs to another [Client].
+    /// - [ClientError::ClientBlocked] if the [Client] is blocked.
+    /// - [ClientError::ClientClosed] if the [Client] is closed.
     /// - [ClientError::NegativeAmount] if the amount is negative.
     /// - [ClientError::ZeroAmount] if the amount is zero.
+    /// - [ClientError::IdempotencyKeyConflict] if the idempotency key was already used with a different request.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at. A retry
+    ///   of an already applied request returns its [Balance] instead.
+    /// - [ClientError::TransactionNotFound] if it reverses a [Transaction] that does not exist.
+    /// - [ClientError::TransactionAlreadyReversed] if it reverses a [Transaction] that was already reversed.
+    /// - [ClientError::TransactionNotReversible] if it cannot compensate the reversed [Transaction].
+    /// - [ClientError::ReversalExceedsOriginal] if it reverses more than the amount of the reversed [Transaction].
     /// - [ClientError::Unknown] if the [Client] cannot be credited.
     fn credit_balance(
         &self,
         req: &CreditTransactionRequest,
     ) -> impl Future<Output = Result<Balance, ClientError>> + Send;
 
-    /// Asynchronously debit the balance of a [Client]. Returns the updated [Balance].
+    /// Asynchronously debit the balance of an [Account] of a [Client], its main one unless the request
+    /// sets another, and append the [Transaction] to its ledger. Returns the updated [Balance] of the [Account].
+    ///
+    /// A reversal can only target the [Account] of the reversed [Transaction].
+    ///
+    /// If the request carries a conversion, the converted amount is applied to the balance in the
+    /// converted currency, and the conversion is kept in the [Transaction] and the [Balance].
     ///
     /// # Errors
     ///
     /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::AccountNotFound] if the [Account] does not exist or belongs to another [Client].
+    /// - [ClientError::ClientBlocked] if the [Client] is blocked.
+    /// - [ClientError::ClientClosed] if the [Client] is closed.
     /// - [ClientError::PositiveAmount] if the amount is positive.
     /// - [ClientError::ZeroAmount] if the amount is zero.
+    /// - [ClientError::InsufficientFunds] if the debit would leave the balance below the overdraft limit of the
+    ///   [Client] in its main [Account], or below zero in any other.
+    /// - [ClientError::IdempotencyKeyConflict] if the idempotency key was already used with a different request.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at. A retry
+    ///   of an already applied request returns its [Balance] instead.
+    /// - [ClientError::TransactionNotFound] if it reverses a [Transaction] that does not exist.
+    /// - [ClientError::TransactionAlreadyReversed] if it reverses a [Transaction] that was already reversed.
+    /// - [ClientError::TransactionNotReversible] if it cannot compensate the reversed [Transaction].
+    /// - [ClientError::ReversalExceedsOriginal] if it reverses more than the amount of the reversed [Transaction].
     /// - [ClientError::Unknown] if the [Client] cannot be debited.
     fn debit_balance(
         &self,
         req: &DebitTransactionRequest,
     ) -> impl Future<Output = Result<Balance, ClientError>> + Send;
 
-    /// Asynchronously get the [Balance] of a [Client].
+    /// Asynchronously and atomically debit the main [Account] of a [Client] and credit the main [Account] of another one,
+    /// appending both [Transaction]s to their ledgers. Either both balances are updated or none of them.
+    /// Returns the updated [Balance]s of the origin and destination [Client]s, in that order.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if any of the [Client]s does not exist.
+    /// - [ClientError::ClientBlocked] or [ClientError::ClientClosed] if any of the [Client]s cannot operate.
+    /// - [ClientError::InsufficientFunds] if the debit would leave the origin balance below its overdraft limit.
+    /// - [ClientError::Unknown] if the transfer cannot be applied.
+    fn transfer(
+        &self,
+        req: &TransferRequest,
+    ) -> impl Future<Output = Result<(Balance, Balance), ClientError>> + Send;
+
+    /// Asynchronously and atomically apply every credit and debit of the batch to the main [Account]s, in order, appending
+    /// their [Transaction]s to the ledgers. Either all of them are applied or none is.
+    /// Returns the [Balance] left by each item, in the order of the items.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::BatchItemRejected] with the index of the first item that cannot be applied,
+    ///   and the reason, such as [ClientError::NotFoundById] or [ClientError::InsufficientFunds].
+    /// - [ClientError::Unknown] if the batch cannot be applied.
+    fn apply_batch(
+        &self,
+        req: &BatchTransactionRequest,
+    ) -> impl Future<Output = Result<Vec<Balance>, ClientError>> + Send;
+
+    /// Asynchronously reserve funds of the main [Account] of a [Client] without moving them. Returns the pending [Authorization],
+    /// which holds the amount until it is captured, voided or it expires.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::ClientBlocked] or [ClientError::ClientClosed] if the [Client] cannot operate.
+    /// - [ClientError::InsufficientFunds] if the hold would leave the available balance below the overdraft limit.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
+    /// - [ClientError::Unknown] if the funds cannot be reserved.
+    fn authorize(
+        &self,
+        req: &AuthorizeRequest,
+    ) -> impl Future<Output = Result<Authorization, ClientError>> + Send;
+
+    /// Asynchronously turn a pending [Authorization] into a debit, releasing the rest of the hold,
+    /// and append the [Transaction] to the ledger. Returns the updated [Balance].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::AuthorizationNotFound] if the [Authorization] does not exist.
+    /// - [ClientError::AuthorizationExpired] if the [Authorization] has expired.
+    /// - [ClientError::AuthorizationNotPending] if the [Authorization] was already captured or voided.
+    /// - [ClientError::CaptureExceedsAuthorization] if the amount exceeds the reserved one.
+    /// - [ClientError::VersionConflict] if the request expects a version of the [Client] it is no longer at.
+    /// - [ClientError::Unknown] if the [Authorization] cannot be captured.
+    fn capture_authorization(
+        &self,
+        req: &CaptureAuthorizationRequest,
+    ) -> impl Future<Output = Result<Balance, ClientError>> + Send;
+
+    /// Asynchronously release the funds held by a pending [Authorization]. Returns the voided [Authorization].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::AuthorizationNotFound] if the [Authorization] does not exist.
+    /// - [ClientError::AuthorizationExpired] if the [Authorization] has expired.
+    /// - [ClientError::AuthorizationNotPending] if the [Authorization] was already captured or voided.
+    /// - [ClientError::VersionConflict] if it expects a version of the [Client] it is no longer at.
+    /// - [ClientError::Unknown] if the [Authorization] cannot be voided.
+    fn void_authorization(
+        &self,
+        authorization_id: &AuthorizationId,
+        expected_version: Option<Version>,
+    ) -> impl Future<Output = Result<Authorization, ClientError>> + Send;
+
+    /// Asynchronously get the [Balance] of a [Client] in the currency of the request, added up across its [Account]s
+    /// and including the amount held by its pending [Authorization]s. It is zero if the [Client] never used that currency.
     ///
     /// # Errors
     ///
@@ -85,6 +263,67 @@ pub trait ClientBalanceRepository: Send + Sync + 'static {
         req: &GetClientRequest,
     ) -> impl Future<Output = Result<Balance, ClientError>> + Send;
 
+    /// Asynchronously get the [Balance]s of a [Client], one per currency added up across its [Account]s,
+    /// sorted by currency.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::Unknown] if the [Client] cannot be found.
+    fn get_balances_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> impl Future<Output = Result<Vec<Balance>, ClientError>> + Send;
+
+    /// Asynchronously get the [Account]s of a [Client], the main one first and the rest in the order they
+    /// were opened, each with its [Balance]s, one per currency, sorted by currency.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::Unknown] if the [Account]s cannot be found.
+    fn get_accounts_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> impl Future<Output = Result<Vec<(Account, Vec<Balance>)>, ClientError>> + Send;
+
+    /// Asynchronously get a [Client] with its [Balance]s and [Account]s, as [get_balances_by_client_id] and
+    /// [get_accounts_by_client_id] return them, all read at once so they match the version of the [Client].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::Unknown] if the [Client] cannot be found.
+    ///
+    /// [get_balances_by_client_id]: ClientBalanceRepository::get_balances_by_client_id
+    /// [get_accounts_by_client_id]: ClientBalanceRepository::get_accounts_by_client_id
+    fn get_client_overview(
+        &self,
+        req: &GetClientRequest,
+    ) -> impl Future<Output = Result<ClientOverview, ClientError>> + Send;
+
+    /// Asynchronously get the [Transaction]s of a [Client], in no particular order.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::Unknown] if the [Transaction]s cannot be found.
+    fn get_transactions_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> impl Future<Output = Result<Vec<Transaction>, ClientError>> + Send;
+
+    /// Asynchronously get a [Transaction] by id.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::TransactionNotFound] if a [Transaction] with the given [TransactionId] does not exist.
+    /// - [ClientError::Unknown] if the [Transaction] cannot be found.
+    fn get_transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> impl Future<Output = Result<Transaction, ClientError>> + Send;
+
     /// Asynchronously get the [Client] by id. Returns the [Client].
     ///
     /// # Errors
@@ -96,29 +335,51 @@ pub trait ClientBalanceRepository: Send + Sync + 'static {
         req: &GetClientRequest,
     ) -> impl Future<Output = Result<Client, ClientError>> + Send;
 
-    /// Asynchronously returns if balances are empty.
+    /// Asynchronously move a [Client] to the given [ClientStatus]. Returns the updated [Client].
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::NotFoundById] if an [Client] with the given [ClientId] does not exist.
+    /// - [ClientError::InvalidStatusTransition] if the current status cannot change to the given one.
+    /// - [ClientError::BalanceNotZero] if it is closed while any of the [Balance]s of its [Account]s, or the funds held by its
+    ///   pending [Authorization]s, are not zero.
+    /// - [ClientError::VersionConflict] if it expects a version of the [Client] it is no longer at.
+    /// - [ClientError::Unknown] if the status cannot be changed.
+    fn change_client_status(
+        &self,
+        client_id: &ClientId,
+        status: ClientStatus,
+        expected_version: Option<Version>,
+    ) -> impl Future<Output = Result<Client, ClientError>> + Send;
+
+    /// Asynchronously returns if balances are empty, ignoring the ones of closed [Client]s.
     ///
     /// # Errors
     ///
     /// - [ClientError::Unknown] if the balances cannot be checked.
     fn are_balances_empty(&self) -> impl Future<Output = Result<bool, ClientError>> + Send;
 
-    /// Asynchronously resets balances of all [Client]s to zero and returns the previous [Balance]s with their old balances.
+    /// Asynchronously resets balances of all [Client]s to zero and returns a [BalanceReset] with the previous [Balance]s,
+    /// one per [Account] and currency. Each balance that changes is recorded as an adjustment [Transaction], and pending
+    /// [Authorization]s are voided, so no hold is left on a zero balance; their ids are returned too. Closed [Client]s are
+    /// skipped.
     ///
     /// # Errors
     ///
     /// - [ClientError::Unknown] if the balances cannot be reset.
     fn reset_all_balances_to_zero(
         &self,
-    ) -> impl Future<Output = Result<Vec<Balance>, ClientError>> + Send;
+    ) -> impl Future<Output = Result<BalanceReset, ClientError>> + Send;
 
-    /// Asynchronously given a old list of [Balance]s, merge them with the actual balances of the [Client]s.
+    /// Asynchronously given a [BalanceReset], merge its old [Balance]s with the actual balances of the [Account]s they
+    /// belong to, and set the [Authorization]s it voided back to pending. Each balance that changes is recorded as an
+    /// adjustment [Transaction].
     ///
     /// # Errors
     ///
     /// - [ClientError::Unknown] if the balances cannot be merged.
     fn merge_old_balances(
         &self,
-        old_balances: Vec<Balance>,
+        reset: BalanceReset,
     ) -> impl Future<Output = Result<(), ClientError>> + Send;
 }
@@ -0,0 +1,8 @@
+use chrono::{DateTime, Utc};
+
+/// `Clock` represents the source of the current time, so the rules that depend on it can be tested.
+#[cfg_attr(test, mockall::automock)]
+pub trait Clock: Send + Sync + 'static {
+    /// Get the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
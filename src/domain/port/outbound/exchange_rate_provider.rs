@@ -0,0 +1,19 @@
+use crate::domain::model::{
+    entity::exchange_rate::ExchangeRate, error::ClientError, value::currency::Currency,
+};
+
+/// `ExchangeRateProvider` represents a source of [ExchangeRate]s between currencies.
+#[cfg_attr(test, mockall::automock)]
+pub trait ExchangeRateProvider: Send + Sync + 'static {
+    /// Asynchronously get the [ExchangeRate] to convert amounts from a [Currency] into another one.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::ExchangeRateNotFound] if there is no rate for the pair.
+    /// - [ClientError::Unknown] if the rate cannot be retrieved.
+    fn get_exchange_rate(
+        &self,
+        from: &Currency,
+        to: &Currency,
+    ) -> impl Future<Output = Result<ExchangeRate, ClientError>> + Send;
+}
@@ -0,0 +1,15 @@
+use crate::domain::model::{error::ClientError, value::client_id::ClientId};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// `IdGenerator` mints the ids of new [Client]s, so the strategy can be chosen without changing the repository.
+#[cfg_attr(test, mockall::automock)]
+pub trait IdGenerator: Send + Sync + 'static {
+    /// Get a new [ClientId], never returned before.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::Unknown] if the id cannot be generated.
+    fn next_client_id(&self) -> Result<ClientId, ClientError>;
+}
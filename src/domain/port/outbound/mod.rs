@@ -1,2 +1,5 @@
 pub mod balance_exporter;
 pub mod client_balance_repository;
+pub mod clock;
+pub mod exchange_rate_provider;
+pub mod id_generator;
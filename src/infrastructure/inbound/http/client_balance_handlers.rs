@@ -1,25 +1,57 @@
 use actix_web::{
-    HttpResponse,
-    web::{Data, Json, Path},
+    HttpRequest, HttpResponse,
+    web::{Data, Json, Path, Query},
 };
 
 use crate::{
-    domain::port::inbound::client_balance_service::ClientBalanceService,
+    domain::{
+        model::dto::get_balance::GetClientRequest,
+        port::inbound::client_balance_service::ClientBalanceService,
+    },
     infrastructure::inbound::http::{
         dto::{
+            authorization::{
+                AuthorizationHttpRequestPath, AuthorizationHttpResponseBody,
+                AuthorizeHttpRequestBody, CaptureAuthorizationHttpRequestBody,
+                CaptureAuthorizationHttpResponseBody,
+            },
+            batch_transaction::{
+                BatchTransactionHttpRequestBody, BatchTransactionHttpResponseBody,
+            },
+            client_status::{ClientStatusHttpRequestPath, ClientStatusHttpResponseBody},
+            create_account::{
+                CreateAccountHttpRequestBody, CreateAccountHttpRequestPath,
+                CreateAccountHttpResponseBody,
+            },
             create_client::{CreateClientHttpRequestBody, CreateClientHttpResponseBody},
             get_client_balance::{
                 GetClientBalanceHttpRequestPath, GetClientBalanceHttpResponseBody,
+                GetClientByDocumentHttpRequestPath, GetClientByDocumentHttpRequestQuery,
             },
+            get_client_transactions::{
+                GetClientTransactionsHttpRequestPath, GetClientTransactionsHttpResponseBody,
+            },
+            list_clients::{ListClientsHttpRequestQuery, ListClientsHttpResponseBody},
             new_credit_transaction::{
                 NewCreditTransactionHttpRequestBody, NewCreditTransactionHttpResponseBody,
             },
             new_debit_transaction::{
                 NewDebitTransactionHttpRequestBody, NewDebitTransactionHttpResponseBody,
             },
+            reverse_transaction::{
+                ReverseTransactionHttpRequestBody, ReverseTransactionHttpRequestPath,
+                ReverseTransactionHttpResponseBody,
+            },
             store_balances::StoreBalancesHttpResponseBody,
+            transfer::{TransferHttpRequestBody, TransferHttpResponseBody},
+            update_client::{
+                GetClientChangesHttpResponseBody, UpdateClientHttpRequestBody,
+                UpdateClientHttpRequestPath, UpdateClientHttpResponseBody,
+            },
         },
         error::ApiError,
+        idempotency::idempotency_key_header,
+        precondition::{etag, if_match_header},
     },
 };
 
@@ -35,6 +67,20 @@ pub async fn create_client<T: ClientBalanceService>(
     Ok(HttpResponse::Created().json(response))
 }
 
+pub async fn create_account<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<CreateAccountHttpRequestPath>,
+    body: Json<CreateAccountHttpRequestBody>,
+    request: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Creating account");
+    let req = body.into_inner();
+    let req = req.try_into_domain(path.into_inner(), if_match_header(&request)?)?;
+    let account = app_state.get_ref().create_account(&req).await?;
+    let response = CreateAccountHttpResponseBody::from(account);
+    Ok(HttpResponse::Created().json(response))
+}
+
 pub async fn get_client_balance<T: ClientBalanceService>(
     app_state: Data<T>,
     path: Path<GetClientBalanceHttpRequestPath>,
@@ -42,36 +88,252 @@ pub async fn get_client_balance<T: ClientBalanceService>(
     tracing::info!("Getting client info with balance info");
     let path = path.into_inner();
     let req = path.try_into_domain()?;
-    let client = app_state.get_client_by_id(&req).await?;
-    let client_balance = app_state.get_balance_by_client_id(&req).await?;
-    let response = GetClientBalanceHttpResponseBody::from((client, client_balance));
+    let overview = app_state.get_client_overview(&req).await?;
+    let etag = etag(overview.client().version());
+    let response = GetClientBalanceHttpResponseBody::try_from(overview)?;
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
+}
+
+pub async fn get_client_by_document<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<GetClientByDocumentHttpRequestPath>,
+    query: Query<GetClientByDocumentHttpRequestQuery>,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Getting client info with balance info by document");
+    let path = path.into_inner();
+    let document = path.try_into_domain(query.into_inner())?;
+    let client = app_state.get_client_by_document(&document).await?;
+    let req = GetClientRequest::new(client.id().clone());
+    let overview = app_state.get_client_overview(&req).await?;
+    let etag = etag(overview.client().version());
+    let response = GetClientBalanceHttpResponseBody::try_from(overview)?;
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
+}
+
+pub async fn get_client_transactions<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<GetClientTransactionsHttpRequestPath>,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Getting client transactions");
+    let path = path.into_inner();
+    let req = path.try_into_domain()?;
+    let transactions = app_state.get_transactions_by_client_id(&req).await?;
+    let response = GetClientTransactionsHttpResponseBody::from(transactions);
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn list_clients<T: ClientBalanceService>(
+    app_state: Data<T>,
+    query: Query<ListClientsHttpRequestQuery>,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Listing clients");
+    let query = query.into_inner();
+    let req = query.try_into_domain()?;
+    let page = app_state.get_ref().list_clients(&req).await?;
+    let response = ListClientsHttpResponseBody::try_from(page)?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn update_client<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<UpdateClientHttpRequestPath>,
+    body: Json<UpdateClientHttpRequestBody>,
+    request: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Updating client");
+    let req = body.into_inner();
+    let req = req.try_into_domain(path.into_inner(), if_match_header(&request)?)?;
+    let client = app_state.get_ref().update_client(&req).await?;
+    let etag = etag(client.version());
+    let response = UpdateClientHttpResponseBody::from(client);
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
+}
+
+pub async fn get_client_changes<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<UpdateClientHttpRequestPath>,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Getting client changes");
+    let path = path.into_inner();
+    let req = path.try_into_domain()?;
+    let changes = app_state.get_ref().get_client_changes(&req).await?;
+    let response = GetClientChangesHttpResponseBody::from(changes);
     Ok(HttpResponse::Ok().json(response))
 }
 
 pub async fn new_credit_transaction<T: ClientBalanceService>(
     app_state: Data<T>,
     body: Json<NewCreditTransactionHttpRequestBody>,
+    request: HttpRequest,
 ) -> Result<HttpResponse, ApiError> {
     tracing::info!("Creating credit transaction");
     let req = body.into_inner();
-    let req = req.try_into_domain()?;
+    let req = req.try_into_domain(
+        idempotency_key_header(&request)?,
+        if_match_header(&request)?,
+    )?;
     let client = app_state.get_ref().credit_balance(&req).await?;
+    let etag = etag(client.version());
     let response = NewCreditTransactionHttpResponseBody::from(client);
-    Ok(HttpResponse::Ok().json(response))
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
 }
 
 pub async fn new_debit_transaction<T: ClientBalanceService>(
     app_state: Data<T>,
     body: Json<NewDebitTransactionHttpRequestBody>,
+    request: HttpRequest,
 ) -> Result<HttpResponse, ApiError> {
     tracing::info!("Creating debit transaction");
     let req = body.into_inner();
-    let req = req.try_into_domain()?;
+    let req = req.try_into_domain(
+        idempotency_key_header(&request)?,
+        if_match_header(&request)?,
+    )?;
     let client = app_state.get_ref().debit_balance(&req).await?;
+    let etag = etag(client.version());
     let response = NewDebitTransactionHttpResponseBody::from(client);
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
+}
+
+// Transfers and batches touch several clients, so a single `If-Match` cannot name the version
+// of each one. They only add or subtract amounts under the clients' locks, checking the funds
+// there, so they never overwrite a concurrent write and don't need one.
+pub async fn transfer<T: ClientBalanceService>(
+    app_state: Data<T>,
+    body: Json<TransferHttpRequestBody>,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Creating transfer");
+    let req = body.into_inner();
+    let req = req.try_into_domain()?;
+    let balances = app_state.get_ref().transfer(&req).await?;
+    let response = TransferHttpResponseBody::from(balances);
     Ok(HttpResponse::Ok().json(response))
 }
 
+pub async fn batch_transactions<T: ClientBalanceService>(
+    app_state: Data<T>,
+    body: Json<BatchTransactionHttpRequestBody>,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Applying transaction batch");
+    let req = body.into_inner();
+    let req = req.try_into_domain()?;
+    let balances = app_state.get_ref().apply_batch(&req).await?;
+    let response = BatchTransactionHttpResponseBody::from(balances);
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn reverse_transaction<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<ReverseTransactionHttpRequestPath>,
+    body: Json<ReverseTransactionHttpRequestBody>,
+    request: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Reversing transaction");
+    let req = body.into_inner();
+    let req = req.try_into_domain(path.into_inner(), if_match_header(&request)?)?;
+    let balance = app_state.get_ref().reverse_transaction(&req).await?;
+    let etag = etag(balance.version());
+    let response = ReverseTransactionHttpResponseBody::from(balance);
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
+}
+
+pub async fn authorize<T: ClientBalanceService>(
+    app_state: Data<T>,
+    body: Json<AuthorizeHttpRequestBody>,
+    request: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Creating authorization");
+    let req = body.into_inner();
+    let req = req.try_into_domain(if_match_header(&request)?)?;
+    let authorization = app_state.get_ref().authorize(&req).await?;
+    let etag = etag(authorization.version());
+    let response = AuthorizationHttpResponseBody::from(authorization);
+    Ok(HttpResponse::Created().insert_header(etag).json(response))
+}
+
+pub async fn capture_authorization<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<AuthorizationHttpRequestPath>,
+    body: Json<CaptureAuthorizationHttpRequestBody>,
+    request: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Capturing authorization");
+    let req = body.into_inner();
+    let req = req.try_into_domain(path.into_inner(), if_match_header(&request)?)?;
+    let balance = app_state.get_ref().capture_authorization(&req).await?;
+    let etag = etag(balance.version());
+    let response = CaptureAuthorizationHttpResponseBody::try_from(balance)?;
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
+}
+
+pub async fn void_authorization<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<AuthorizationHttpRequestPath>,
+    request: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Voiding authorization");
+    let path = path.into_inner();
+    let authorization_id = path.try_into_domain()?;
+    let authorization = app_state
+        .get_ref()
+        .void_authorization(&authorization_id, if_match_header(&request)?)
+        .await?;
+    let etag = etag(authorization.version());
+    let response = AuthorizationHttpResponseBody::from(authorization);
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
+}
+
+pub async fn block_client<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<ClientStatusHttpRequestPath>,
+    request: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Blocking client");
+    let path = path.into_inner();
+    let client_id = path.try_into_domain()?;
+    let client = app_state
+        .get_ref()
+        .block_client(&client_id, if_match_header(&request)?)
+        .await?;
+    let etag = etag(client.version());
+    let response = ClientStatusHttpResponseBody::from(client);
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
+}
+
+pub async fn unblock_client<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<ClientStatusHttpRequestPath>,
+    request: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Unblocking client");
+    let path = path.into_inner();
+    let client_id = path.try_into_domain()?;
+    let client = app_state
+        .get_ref()
+        .unblock_client(&client_id, if_match_header(&request)?)
+        .await?;
+    let etag = etag(client.version());
+    let response = ClientStatusHttpResponseBody::from(client);
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
+}
+
+pub async fn close_client<T: ClientBalanceService>(
+    app_state: Data<T>,
+    path: Path<ClientStatusHttpRequestPath>,
+    request: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    tracing::info!("Closing client");
+    let path = path.into_inner();
+    let client_id = path.try_into_domain()?;
+    let client = app_state
+        .get_ref()
+        .close_client(&client_id, if_match_header(&request)?)
+        .await?;
+    let etag = etag(client.version());
+    let response = ClientStatusHttpResponseBody::from(client);
+    Ok(HttpResponse::Ok().insert_header(etag).json(response))
+}
+
 pub async fn store_balances<T: ClientBalanceService>(
     app_state: Data<T>,
 ) -> Result<HttpResponse, ApiError> {
@@ -93,6 +355,18 @@ macro_rules! CREATE_CLIENT_METHOD {
 }
 pub const CREATE_CLIENT_ROUTE: &str = "/create_client";
 
+#[macro_export]
+macro_rules! CREATE_ACCOUNT_METHOD {
+    ($service:ident) => {
+        web::post().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::create_account::<
+                $service,
+            >,
+        )
+    };
+}
+pub const CREATE_ACCOUNT_ROUTE: &str = "/client/{user_id}/accounts";
+
 #[macro_export]
 macro_rules! GET_CLIENT_BALANCE_METHOD {
     ($service:ident) => {
@@ -105,6 +379,60 @@ macro_rules! GET_CLIENT_BALANCE_METHOD {
 }
 pub const GET_CLIENT_BALANCE_ROUTE: &str = "/client_balance/{user_id}";
 
+#[macro_export]
+macro_rules! GET_CLIENT_TRANSACTIONS_METHOD {
+    ($service:ident) => {
+        web::get().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::get_client_transactions::<
+                $service,
+            >,
+        )
+    };
+}
+pub const GET_CLIENT_TRANSACTIONS_ROUTE: &str = "/client/{user_id}/transactions";
+
+#[macro_export]
+macro_rules! GET_CLIENT_BY_DOCUMENT_METHOD {
+    ($service:ident) => {
+        web::get().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::get_client_by_document::<
+                $service,
+            >,
+        )
+    };
+}
+pub const GET_CLIENT_BY_DOCUMENT_ROUTE: &str = "/clients/by-document/{document}";
+
+#[macro_export]
+macro_rules! LIST_CLIENTS_METHOD {
+    ($service:ident) => {
+        web::get()
+            .to($crate::infrastructure::inbound::http::client_balance_handlers::list_clients::<$service>)
+    };
+}
+pub const LIST_CLIENTS_ROUTE: &str = "/clients";
+
+#[macro_export]
+macro_rules! UPDATE_CLIENT_METHOD {
+    ($service:ident) => {
+        web::patch()
+            .to($crate::infrastructure::inbound::http::client_balance_handlers::update_client::<$service>)
+    };
+}
+pub const UPDATE_CLIENT_ROUTE: &str = "/clients/{id}";
+
+#[macro_export]
+macro_rules! GET_CLIENT_CHANGES_METHOD {
+    ($service:ident) => {
+        web::get().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::get_client_changes::<
+                $service,
+            >,
+        )
+    };
+}
+pub const GET_CLIENT_CHANGES_ROUTE: &str = "/clients/{id}/changes";
+
 #[macro_export]
 macro_rules! NEW_CREDIT_TRANSACTION_METHOD {
     ($service:ident) => {
@@ -129,6 +457,106 @@ macro_rules! NEW_DEBIT_TRANSACTION_METHOD {
 }
 pub const NEW_DEBIT_TRANSACTION_ROUTE: &str = "/new_debit_transaction";
 
+#[macro_export]
+macro_rules! TRANSFER_METHOD {
+    ($service:ident) => {
+        web::post().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::transfer::<$service>,
+        )
+    };
+}
+pub const TRANSFER_ROUTE: &str = "/transfer";
+
+#[macro_export]
+macro_rules! BATCH_TRANSACTIONS_METHOD {
+    ($service:ident) => {
+        web::post().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::batch_transactions::<
+                $service,
+            >,
+        )
+    };
+}
+pub const BATCH_TRANSACTIONS_ROUTE: &str = "/transactions/batch";
+/// A batch carries many more items than any other body, so it gets a larger payload limit.
+pub const BATCH_TRANSACTIONS_PAYLOAD_LIMIT: usize = 16 * 1024 * 1024;
+
+#[macro_export]
+macro_rules! REVERSE_TRANSACTION_METHOD {
+    ($service:ident) => {
+        web::post().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::reverse_transaction::<
+                $service,
+            >,
+        )
+    };
+}
+pub const REVERSE_TRANSACTION_ROUTE: &str = "/transactions/{id}/reverse";
+
+#[macro_export]
+macro_rules! AUTHORIZE_METHOD {
+    ($service:ident) => {
+        web::post().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::authorize::<$service>,
+        )
+    };
+}
+pub const AUTHORIZE_ROUTE: &str = "/authorizations";
+
+#[macro_export]
+macro_rules! CAPTURE_AUTHORIZATION_METHOD {
+    ($service:ident) => {
+        web::post().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::capture_authorization::<
+                $service,
+            >,
+        )
+    };
+}
+pub const CAPTURE_AUTHORIZATION_ROUTE: &str = "/authorizations/{id}/capture";
+
+#[macro_export]
+macro_rules! VOID_AUTHORIZATION_METHOD {
+    ($service:ident) => {
+        web::post().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::void_authorization::<
+                $service,
+            >,
+        )
+    };
+}
+pub const VOID_AUTHORIZATION_ROUTE: &str = "/authorizations/{id}/void";
+
+#[macro_export]
+macro_rules! BLOCK_CLIENT_METHOD {
+    ($service:ident) => {
+        web::post()
+            .to($crate::infrastructure::inbound::http::client_balance_handlers::block_client::<$service>)
+    };
+}
+pub const BLOCK_CLIENT_ROUTE: &str = "/client/{user_id}/block";
+
+#[macro_export]
+macro_rules! UNBLOCK_CLIENT_METHOD {
+    ($service:ident) => {
+        web::post().to(
+            $crate::infrastructure::inbound::http::client_balance_handlers::unblock_client::<
+                $service,
+            >,
+        )
+    };
+}
+pub const UNBLOCK_CLIENT_ROUTE: &str = "/client/{user_id}/unblock";
+
+#[macro_export]
+macro_rules! CLOSE_CLIENT_METHOD {
+    ($service:ident) => {
+        web::post()
+            .to($crate::infrastructure::inbound::http::client_balance_handlers::close_client::<$service>)
+    };
+}
+pub const CLOSE_CLIENT_ROUTE: &str = "/client/{user_id}/close";
+
 #[macro_export]
 macro_rules! STORE_BALANCES_METHOD {
     ($service:ident) => {
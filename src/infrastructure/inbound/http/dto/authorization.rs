@@ -0,0 +1,129 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::model::{
+        dto::{authorize::AuthorizeRequest, capture_authorization::CaptureAuthorizationRequest},
+        entity::{authorization::Authorization, balance::Balance},
+        error::ClientError,
+        value::{
+            authorization_id::AuthorizationId, client_id::ClientId, currency::Currency,
+            version::Version,
+        },
+    },
+    infrastructure::inbound::http::error::ApiError,
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// The body of a request to hold funds of a [Client].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AuthorizeHttpRequestBody {
+    client_id: String,
+    amount: Decimal,
+    /// ISO 4217 code of the balance to hold. The default currency if not sent.
+    currency: Option<String>,
+}
+
+impl AuthorizeHttpRequestBody {
+    /// Converts the HTTP request body and the `If-Match` header into a domain request.
+    pub fn try_into_domain(
+        self,
+        if_match_header: Option<Version>,
+    ) -> Result<AuthorizeRequest, ApiError> {
+        let client_id = ClientId::try_from(self.client_id)?;
+        let mut authorize_request = AuthorizeRequest::new(client_id, self.amount)?;
+        if let Some(currency) = self.currency {
+            authorize_request = authorize_request.with_currency(Currency::try_from(currency)?);
+        }
+        if let Some(expected_version) = if_match_header {
+            authorize_request = authorize_request.with_expected_version(expected_version);
+        }
+        Ok(authorize_request)
+    }
+}
+
+/// The path of an [Authorization].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AuthorizationHttpRequestPath {
+    id: String,
+}
+
+impl AuthorizationHttpRequestPath {
+    /// Converts the HTTP request path into a domain id.
+    pub fn try_into_domain(self) -> Result<AuthorizationId, ApiError> {
+        let authorization_id = AuthorizationId::try_from(self.id)?;
+        Ok(authorization_id)
+    }
+}
+
+/// The body of an [Authorization] capture request.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CaptureAuthorizationHttpRequestBody {
+    /// The amount to debit. The whole reserved amount if not sent.
+    amount: Option<Decimal>,
+}
+
+impl CaptureAuthorizationHttpRequestBody {
+    /// Converts the HTTP request path, body and `If-Match` header into a domain request.
+    pub fn try_into_domain(
+        self,
+        path: AuthorizationHttpRequestPath,
+        if_match_header: Option<Version>,
+    ) -> Result<CaptureAuthorizationRequest, ApiError> {
+        let authorization_id = path.try_into_domain()?;
+        let mut capture_request = CaptureAuthorizationRequest::new(authorization_id, self.amount)?;
+        if let Some(expected_version) = if_match_header {
+            capture_request = capture_request.with_expected_version(expected_version);
+        }
+        Ok(capture_request)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuthorizationHttpResponseBody {
+    id: String,
+    client_id: String,
+    amount: String,
+    currency: String,
+    status: String,
+    created_at: String,
+    expires_at: String,
+    captured_amount: Option<String>,
+}
+
+impl From<Authorization> for AuthorizationHttpResponseBody {
+    fn from(authorization: Authorization) -> Self {
+        Self {
+            id: authorization.id().to_string(),
+            client_id: authorization.client_id().to_string(),
+            amount: authorization.amount().to_string(),
+            currency: authorization.currency().to_string(),
+            status: authorization.status().to_string(),
+            created_at: authorization.created_at().to_rfc3339(),
+            expires_at: authorization.expires_at().to_rfc3339(),
+            captured_amount: authorization.captured_amount().map(ToString::to_string),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CaptureAuthorizationHttpResponseBody {
+    id: String,
+    currency: String,
+    available: String,
+    ledger: String,
+}
+
+impl TryFrom<Balance> for CaptureAuthorizationHttpResponseBody {
+    type Error = ClientError;
+    fn try_from(client_balance: Balance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: client_balance.client_id().to_string(),
+            currency: client_balance.currency().to_string(),
+            available: client_balance.available()?.to_string(),
+            ledger: client_balance.balance().to_string(),
+        })
+    }
+}
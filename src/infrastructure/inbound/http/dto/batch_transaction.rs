@@ -0,0 +1,122 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::model::{
+        dto::{
+            batch_transaction::{BatchTransactionItem, BatchTransactionRequest},
+            credit_transaction::CreditTransactionRequest,
+            debit_transaction::DebitTransactionRequest,
+        },
+        entity::balance::Balance,
+        error::ClientError,
+        value::{client_id::ClientId, currency::Currency},
+    },
+    infrastructure::inbound::http::error::ApiError,
+};
+
+/// A credit or a debit of a batch, tagged by its `kind`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BatchTransactionItemHttpRequestBody {
+    Credit {
+        client_id: String,
+        amount: Decimal,
+        /// ISO 4217 code of the balance. The default currency if not sent.
+        currency: Option<String>,
+    },
+    Debit {
+        client_id: String,
+        amount: Decimal,
+        /// ISO 4217 code of the balance. The default currency if not sent.
+        currency: Option<String>,
+    },
+}
+
+impl BatchTransactionItemHttpRequestBody {
+    fn try_into_domain(self) -> Result<BatchTransactionItem, ClientError> {
+        let item = match self {
+            Self::Credit {
+                client_id,
+                amount,
+                currency,
+            } => {
+                let mut req =
+                    CreditTransactionRequest::new(ClientId::try_from(client_id)?, amount)?;
+                if let Some(currency) = currency {
+                    req = req.with_currency(Currency::try_from(currency)?);
+                }
+                BatchTransactionItem::Credit(req)
+            }
+            Self::Debit {
+                client_id,
+                amount,
+                currency,
+            } => {
+                let mut req = DebitTransactionRequest::new(ClientId::try_from(client_id)?, amount)?;
+                if let Some(currency) = currency {
+                    req = req.with_currency(Currency::try_from(currency)?);
+                }
+                BatchTransactionItem::Debit(req)
+            }
+        };
+        Ok(item)
+    }
+}
+
+/// The body of a batch of credits and debits.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BatchTransactionHttpRequestBody {
+    transactions: Vec<BatchTransactionItemHttpRequestBody>,
+}
+
+impl BatchTransactionHttpRequestBody {
+    /// Converts the HTTP request body into a domain request. An invalid item rejects the whole
+    /// batch with its index.
+    pub fn try_into_domain(self) -> Result<BatchTransactionRequest, ApiError> {
+        let items = self
+            .transactions
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                item.try_into_domain()
+                    .map_err(|reason| ClientError::BatchItemRejected {
+                        index,
+                        reason: Box::new(reason),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(BatchTransactionRequest::new(items)?)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchTransactionResultHttpResponseBody {
+    index: usize,
+    id: String,
+    currency: String,
+    balance: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchTransactionHttpResponseBody {
+    /// The balance left by each item, in the order of the request.
+    results: Vec<BatchTransactionResultHttpResponseBody>,
+}
+
+impl From<Vec<Balance>> for BatchTransactionHttpResponseBody {
+    fn from(balances: Vec<Balance>) -> Self {
+        Self {
+            results: balances
+                .into_iter()
+                .enumerate()
+                .map(|(index, balance)| BatchTransactionResultHttpResponseBody {
+                    index,
+                    id: balance.client_id().to_string(),
+                    currency: balance.currency().to_string(),
+                    balance: balance.balance().to_string(),
+                })
+                .collect(),
+        }
+    }
+}
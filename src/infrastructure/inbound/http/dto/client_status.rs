@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::model::{entity::client::Client, value::client_id::ClientId},
+    infrastructure::inbound::http::error::ApiError,
+};
+
+/// The path of a [Client] whose status is changed.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ClientStatusHttpRequestPath {
+    user_id: String,
+}
+
+impl ClientStatusHttpRequestPath {
+    /// Converts the HTTP request path into a domain id.
+    pub fn try_into_domain(self) -> Result<ClientId, ApiError> {
+        let client_id = ClientId::try_from(self.user_id)?;
+        Ok(client_id)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClientStatusHttpResponseBody {
+    id: String,
+    status: String,
+}
+
+impl From<Client> for ClientStatusHttpResponseBody {
+    fn from(client: Client) -> Self {
+        Self {
+            id: client.id().to_string(),
+            status: client.status().to_string(),
+        }
+    }
+}
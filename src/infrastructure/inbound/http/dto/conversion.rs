@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+use crate::domain::model::entity::conversion::Conversion;
+
+/// The conversion applied to an amount requested in another currency.
+#[derive(Debug, Serialize)]
+pub struct ConversionHttpResponseBody {
+    original_amount: String,
+    original_currency: String,
+    rate: String,
+    spread: String,
+    applied_rate: String,
+    converted_amount: String,
+}
+
+impl From<&Conversion> for ConversionHttpResponseBody {
+    fn from(conversion: &Conversion) -> Self {
+        Self {
+            original_amount: conversion.original_amount().to_string(),
+            original_currency: conversion.original_currency().to_string(),
+            rate: conversion.rate().to_string(),
+            spread: conversion.spread().to_string(),
+            applied_rate: conversion.applied_rate().to_string(),
+            converted_amount: conversion.converted_amount().to_string(),
+        }
+    }
+}
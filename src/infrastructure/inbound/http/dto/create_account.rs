@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::model::{
+        dto::create_account::CreateAccountRequest,
+        entity::account::Account,
+        value::{account_name::AccountName, client_id::ClientId, version::Version},
+    },
+    infrastructure::inbound::http::error::ApiError,
+};
+
+/// The path of the client that opens the [Account].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CreateAccountHttpRequestPath {
+    user_id: String,
+}
+
+/// The body of an [Account] creation request.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CreateAccountHttpRequestBody {
+    /// e.g. `savings`.
+    name: String,
+}
+
+impl CreateAccountHttpRequestBody {
+    /// Converts the HTTP request path and body into a domain request.
+    pub fn try_into_domain(
+        self,
+        path: CreateAccountHttpRequestPath,
+        if_match_header: Option<Version>,
+    ) -> Result<CreateAccountRequest, ApiError> {
+        let client_id = ClientId::try_from(path.user_id)?;
+        let name = AccountName::try_from(self.name)?;
+        let mut create_account_request = CreateAccountRequest::new(client_id, name);
+        if let Some(expected_version) = if_match_header {
+            create_account_request = create_account_request.with_expected_version(expected_version);
+        }
+        Ok(create_account_request)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateAccountHttpResponseBody {
+    id: String,
+    client_id: String,
+    name: String,
+    main: bool,
+}
+
+impl From<Account> for CreateAccountHttpResponseBody {
+    fn from(account: Account) -> Self {
+        Self {
+            id: account.id().to_string(),
+            client_id: account.client_id().to_string(),
+            name: account.name().to_string(),
+            main: account.is_main(),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -6,6 +7,8 @@ use crate::{
         entity::client::Client,
         value::{
             birth_date::BirthDate, client_name::ClientName, country::Country, document::Document,
+            document_type::DocumentType, email::Email, overdraft_limit::OverdraftLimit,
+            phone_number::PhoneNumber,
         },
     },
     infrastructure::inbound::http::error::ApiError,
@@ -16,20 +19,42 @@ use crate::{
 pub struct CreateClientHttpRequestBody {
     name: String,
     birth_date: String,
+    /// `dni`, `cuit`, `cuil` or `passport`. `dni` if not sent.
+    document_type: Option<String>,
     document: String,
     country: String,
+    /// How far below zero the balance may go. Zero if not sent.
+    overdraft_limit: Option<Decimal>,
+    email: Option<String>,
+    /// With its country code, e.g. `+54 9 11 2345-6789`.
+    phone: Option<String>,
 }
 
 impl CreateClientHttpRequestBody {
     /// Converts the HTTP request body into a domain request.
     pub fn try_into_domain(self) -> Result<CreateClientRequest, ApiError> {
         let name = ClientName::new(&self.name)?;
-        let document = Document::new(&self.document)?;
+        let document_type = self
+            .document_type
+            .map(DocumentType::try_from)
+            .transpose()?
+            .unwrap_or_default();
+        let document = Document::new(document_type, &self.document)?;
         let country = Country::new(&self.country)?;
         let birth_date = BirthDate::new(&self.birth_date)?;
-        Ok(CreateClientRequest::new(
-            name, birth_date, document, country,
-        ))
+        let mut create_client_request =
+            CreateClientRequest::new(name, birth_date, document, country);
+        if let Some(overdraft_limit) = self.overdraft_limit {
+            create_client_request =
+                create_client_request.with_overdraft_limit(OverdraftLimit::new(overdraft_limit)?);
+        }
+        if let Some(email) = self.email {
+            create_client_request = create_client_request.with_email(Email::try_from(email)?);
+        }
+        if let Some(phone) = self.phone {
+            create_client_request = create_client_request.with_phone(PhoneNumber::try_from(phone)?);
+        }
+        Ok(create_client_request)
     }
 }
 
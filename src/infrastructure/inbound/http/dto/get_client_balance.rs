@@ -3,8 +3,12 @@ use serde::{Deserialize, Serialize};
 use crate::{
     domain::model::{
         dto::get_balance::GetClientRequest,
-        entity::{balance::Balance, client::Client},
-        value::client_id::ClientId,
+        entity::{account::Account, balance::Balance, client_overview::ClientOverview},
+        error::ClientError,
+        value::{
+            client_id::ClientId, document::Document, document_type::DocumentType, email::Email,
+            phone_number::PhoneNumber,
+        },
     },
     infrastructure::inbound::http::error::ApiError,
 };
@@ -22,25 +26,129 @@ impl GetClientBalanceHttpRequestPath {
     }
 }
 
+/// The path to get the client balance by its document.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GetClientByDocumentHttpRequestPath {
+    document: String,
+}
+
+/// The query to get the client balance by its document.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GetClientByDocumentHttpRequestQuery {
+    /// `dni`, `cuit`, `cuil` or `passport`. `dni` if not sent.
+    document_type: Option<String>,
+}
+
+impl GetClientByDocumentHttpRequestPath {
+    /// Converts the HTTP request path and query into a domain document.
+    pub fn try_into_domain(
+        self,
+        query: GetClientByDocumentHttpRequestQuery,
+    ) -> Result<Document, ApiError> {
+        let document_type = query
+            .document_type
+            .map(DocumentType::try_from)
+            .transpose()?
+            .unwrap_or_default();
+        let document = Document::new(document_type, &self.document)?;
+        Ok(document)
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct GetClientBalanceHttpResponseBody {
     id: String,
     name: String,
     birth_date: String,
+    document_type: String,
     document: String,
+    /// The ISO 3166-1 alpha-2 code.
     country: String,
-    balance: String,
+    country_name: String,
+    /// Masked, e.g. `j***@example.com`.
+    email: Option<String>,
+    /// Masked, e.g. `+*********6789`.
+    phone: Option<String>,
+    overdraft_limit: String,
+    status: String,
+    /// One entry per currency the client holds, added up across all its accounts.
+    balances: Vec<CurrencyBalanceHttpResponseBody>,
+    /// The main account first, then the rest in the order they were opened.
+    accounts: Vec<AccountHttpResponseBody>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AccountHttpResponseBody {
+    id: String,
+    name: String,
+    main: bool,
+    /// One entry per currency the account holds.
+    balances: Vec<CurrencyBalanceHttpResponseBody>,
+}
+
+impl TryFrom<(Account, Vec<Balance>)> for AccountHttpResponseBody {
+    type Error = ClientError;
+    fn try_from((account, account_balances): (Account, Vec<Balance>)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: account.id().to_string(),
+            name: account.name().to_string(),
+            main: account.is_main(),
+            balances: account_balances
+                .into_iter()
+                .map(CurrencyBalanceHttpResponseBody::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CurrencyBalanceHttpResponseBody {
+    currency: String,
+    /// The balance that can still be spent, excluding the funds held by pending authorizations.
+    available: String,
+    /// The balance of the ledger, including the funds held by pending authorizations.
+    ledger: String,
+}
+
+impl TryFrom<Balance> for CurrencyBalanceHttpResponseBody {
+    type Error = ClientError;
+    fn try_from(client_balance: Balance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            currency: client_balance.currency().to_string(),
+            available: client_balance.available()?.to_string(),
+            ledger: client_balance.balance().to_string(),
+        })
+    }
 }
 
-impl From<(Client, Balance)> for GetClientBalanceHttpResponseBody {
-    fn from((client, client_balance): (Client, Balance)) -> Self {
-        Self {
-            id: client_balance.client_id().to_string(),
+impl TryFrom<ClientOverview> for GetClientBalanceHttpResponseBody {
+    type Error = ClientError;
+    fn try_from(overview: ClientOverview) -> Result<Self, Self::Error> {
+        let client = overview.client();
+        Ok(Self {
+            id: client.id().to_string(),
             name: client.name().to_string(),
             birth_date: client.birth_date().to_string(),
-            document: client.document().to_string(),
+            document_type: client.document().document_type().to_string(),
+            document: client.document().number().to_string(),
             country: client.country().to_string(),
-            balance: client_balance.balance().to_string(),
-        }
+            country_name: client.country().name().to_string(),
+            email: client.email().map(Email::masked),
+            phone: client.phone().map(PhoneNumber::masked),
+            overdraft_limit: client.overdraft_limit().to_string(),
+            status: client.status().to_string(),
+            balances: overview
+                .balances()
+                .iter()
+                .cloned()
+                .map(CurrencyBalanceHttpResponseBody::try_from)
+                .collect::<Result<_, _>>()?,
+            accounts: overview
+                .accounts()
+                .iter()
+                .cloned()
+                .map(AccountHttpResponseBody::try_from)
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
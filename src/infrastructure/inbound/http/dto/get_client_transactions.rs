@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::model::{
+        dto::get_balance::GetClientRequest, entity::transaction::Transaction,
+        value::client_id::ClientId,
+    },
+    infrastructure::inbound::http::{dto::conversion::ConversionHttpResponseBody, error::ApiError},
+};
+
+/// The path to get the client transactions.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GetClientTransactionsHttpRequestPath {
+    user_id: String,
+}
+
+impl GetClientTransactionsHttpRequestPath {
+    /// Converts the HTTP request path into a domain request.
+    pub fn try_into_domain(self) -> Result<GetClientRequest, ApiError> {
+        let client_id = ClientId::try_from(self.user_id)?;
+        Ok(GetClientRequest::new(client_id))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransactionHttpResponseBody {
+    id: String,
+    client_id: String,
+    account_id: Option<String>,
+    amount: String,
+    currency: String,
+    kind: String,
+    created_at: String,
+    resulting_balance: String,
+    reversal_of: Option<String>,
+    conversion: Option<ConversionHttpResponseBody>,
+}
+
+impl From<Transaction> for TransactionHttpResponseBody {
+    fn from(transaction: Transaction) -> Self {
+        Self {
+            id: transaction.id().to_string(),
+            client_id: transaction.client_id().to_string(),
+            account_id: transaction.account_id().map(ToString::to_string),
+            amount: transaction.amount().to_string(),
+            currency: transaction.currency().to_string(),
+            kind: transaction.kind().to_string(),
+            created_at: transaction.created_at().to_rfc3339(),
+            resulting_balance: transaction.resulting_balance().to_string(),
+            reversal_of: transaction.reversal_of().map(ToString::to_string),
+            conversion: transaction
+                .conversion()
+                .map(ConversionHttpResponseBody::from),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetClientTransactionsHttpResponseBody {
+    transactions: Vec<TransactionHttpResponseBody>,
+}
+
+impl From<Vec<Transaction>> for GetClientTransactionsHttpResponseBody {
+    fn from(transactions: Vec<Transaction>) -> Self {
+        Self {
+            transactions: transactions
+                .into_iter()
+                .map(TransactionHttpResponseBody::from)
+                .collect(),
+        }
+    }
+}
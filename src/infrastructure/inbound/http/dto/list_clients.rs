@@ -0,0 +1,131 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::model::{
+        dto::list_clients::{DEFAULT_PAGE_SIZE, ListClientsRequest},
+        entity::{balance::Balance, client::Client, client_page::ClientPage},
+        error::ClientError,
+        value::{
+            balance_range::BalanceRange,
+            client_cursor::ClientCursor,
+            client_name::ClientName,
+            client_sort::{ClientSort, SortDirection},
+            country::Country,
+            currency::Currency,
+        },
+    },
+    infrastructure::inbound::http::error::ApiError,
+};
+
+/// The query of a [Client] listing. Every filter is optional.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ListClientsHttpRequestQuery {
+    /// `id` or `balance`. `id` if not sent.
+    sort: Option<String>,
+    /// `asc` or `desc`. `asc` if not sent.
+    order: Option<String>,
+    /// The `next_cursor` of the previous page.
+    cursor: Option<String>,
+    limit: Option<usize>,
+    country: Option<String>,
+    /// Matched against the number of the document, whatever its type.
+    document_prefix: Option<String>,
+    name: Option<String>,
+    min_balance: Option<Decimal>,
+    max_balance: Option<Decimal>,
+    /// ISO 4217 code of the balance to filter, sort and return. The default currency if not sent.
+    currency: Option<String>,
+}
+
+impl ListClientsHttpRequestQuery {
+    /// Converts the HTTP request query into a domain request.
+    pub fn try_into_domain(self) -> Result<ListClientsRequest, ApiError> {
+        let sort = self
+            .sort
+            .as_deref()
+            .map(ClientSort::new)
+            .transpose()?
+            .unwrap_or_default();
+        let direction = self
+            .order
+            .as_deref()
+            .map(SortDirection::new)
+            .transpose()?
+            .unwrap_or_default();
+        let cursor = self.cursor.as_deref().map(ClientCursor::new).transpose()?;
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        let mut list_clients_request = ListClientsRequest::new(sort, direction, cursor, limit)?
+            .with_balance_range(BalanceRange::new(self.min_balance, self.max_balance)?);
+        if let Some(country) = self.country {
+            list_clients_request = list_clients_request.with_country(Country::new(&country)?);
+        }
+        if let Some(document_prefix) = self.document_prefix {
+            list_clients_request = list_clients_request.with_document_prefix(document_prefix);
+        }
+        if let Some(name) = self.name {
+            list_clients_request = list_clients_request.with_name_contains(ClientName::new(&name)?);
+        }
+        if let Some(currency) = self.currency {
+            list_clients_request =
+                list_clients_request.with_currency(Currency::try_from(currency)?);
+        }
+        Ok(list_clients_request)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClientSummaryHttpResponseBody {
+    id: String,
+    name: String,
+    birth_date: String,
+    document_type: String,
+    document: String,
+    /// The ISO 3166-1 alpha-2 code.
+    country: String,
+    country_name: String,
+    status: String,
+    currency: String,
+    available: String,
+    ledger: String,
+}
+
+impl TryFrom<(Client, Balance)> for ClientSummaryHttpResponseBody {
+    type Error = ClientError;
+    fn try_from((client, balance): (Client, Balance)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: client.id().to_string(),
+            name: client.name().to_string(),
+            birth_date: client.birth_date().to_string(),
+            document_type: client.document().document_type().to_string(),
+            document: client.document().number().to_string(),
+            country: client.country().to_string(),
+            country_name: client.country().name().to_string(),
+            status: client.status().to_string(),
+            currency: balance.currency().to_string(),
+            available: balance.available()?.to_string(),
+            ledger: balance.balance().to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListClientsHttpResponseBody {
+    clients: Vec<ClientSummaryHttpResponseBody>,
+    /// The cursor to get the next page. There are no more clients if it is null.
+    next_cursor: Option<String>,
+}
+
+impl TryFrom<ClientPage> for ListClientsHttpResponseBody {
+    type Error = ClientError;
+    fn try_from(page: ClientPage) -> Result<Self, Self::Error> {
+        let (clients, next_cursor) = page.into_parts();
+        Ok(Self {
+            clients: clients
+                .into_iter()
+                .map(ClientSummaryHttpResponseBody::try_from)
+                .collect::<Result<_, _>>()?,
+            next_cursor: next_cursor.map(|cursor| cursor.to_string()),
+        })
+    }
+}
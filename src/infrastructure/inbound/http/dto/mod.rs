@@ -1,5 +1,15 @@
+pub mod authorization;
+pub mod batch_transaction;
+pub mod client_status;
+pub mod conversion;
+pub mod create_account;
 pub mod create_client;
 pub mod get_client_balance;
+pub mod get_client_transactions;
+pub mod list_clients;
 pub mod new_credit_transaction;
 pub mod new_debit_transaction;
+pub mod reverse_transaction;
 pub mod store_balances;
+pub mod transfer;
+pub mod update_client;
@@ -3,10 +3,17 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     domain::model::{
-        dto::debit_transaction::DebitTransactionRequest, entity::balance::Balance,
-        value::client_id::ClientId,
+        dto::debit_transaction::DebitTransactionRequest,
+        entity::balance::Balance,
+        value::{
+            account_id::AccountId, client_id::ClientId, currency::Currency,
+            idempotency_key::IdempotencyKey, version::Version,
+        },
+    },
+    infrastructure::inbound::http::{
+        dto::conversion::ConversionHttpResponseBody, error::ApiError,
+        idempotency::resolve_idempotency_key,
     },
-    infrastructure::inbound::http::error::ApiError,
 };
 
 #[allow(unused_imports)]
@@ -16,14 +23,48 @@ use crate::domain::model::entity::client::Client;
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct NewDebitTransactionHttpRequestBody {
     client_id: String,
+    /// The account of the client to debit, its main account if not sent.
+    account_id: Option<String>,
     amount: Decimal,
+    /// ISO 4217 code of the amount. The default currency if not sent.
+    currency: Option<String>,
+    /// ISO 4217 code of the balance, when the amount has to be converted into it.
+    balance_currency: Option<String>,
+    /// Reference of the caller for this transaction, used as idempotency key.
+    external_reference: Option<String>,
 }
 
 impl NewDebitTransactionHttpRequestBody {
     /// Converts the HTTP request body into a domain request.
-    pub fn try_into_domain(self) -> Result<DebitTransactionRequest, ApiError> {
+    pub fn try_into_domain(
+        self,
+        idempotency_key_header: Option<IdempotencyKey>,
+        if_match_header: Option<Version>,
+    ) -> Result<DebitTransactionRequest, ApiError> {
         let client_id = ClientId::try_from(self.client_id)?;
-        let debit_transaction_request = DebitTransactionRequest::new(client_id, self.amount)?;
+        let mut debit_transaction_request = DebitTransactionRequest::new(client_id, self.amount)?;
+        if let Some(account_id) = self.account_id {
+            debit_transaction_request =
+                debit_transaction_request.with_account_id(AccountId::try_from(account_id)?);
+        }
+        if let Some(currency) = self.currency {
+            debit_transaction_request =
+                debit_transaction_request.with_currency(Currency::try_from(currency)?);
+        }
+        if let Some(balance_currency) = self.balance_currency {
+            debit_transaction_request = debit_transaction_request
+                .with_balance_currency(Currency::try_from(balance_currency)?);
+        }
+        if let Some(idempotency_key) =
+            resolve_idempotency_key(idempotency_key_header, self.external_reference)?
+        {
+            debit_transaction_request =
+                debit_transaction_request.with_idempotency_key(idempotency_key);
+        }
+        if let Some(expected_version) = if_match_header {
+            debit_transaction_request =
+                debit_transaction_request.with_expected_version(expected_version);
+        }
         Ok(debit_transaction_request)
     }
 }
@@ -31,14 +72,23 @@ impl NewDebitTransactionHttpRequestBody {
 #[derive(Debug, Serialize)]
 pub struct NewDebitTransactionHttpResponseBody {
     id: String,
+    account_id: Option<String>,
+    currency: String,
     balance: String,
+    /// The conversion applied to the amount, if it was requested in another currency.
+    conversion: Option<ConversionHttpResponseBody>,
 }
 
 impl From<Balance> for NewDebitTransactionHttpResponseBody {
     fn from(client_balance: Balance) -> Self {
         Self {
             id: client_balance.client_id().to_string(),
+            account_id: client_balance.account_id().map(ToString::to_string),
+            currency: client_balance.currency().to_string(),
             balance: client_balance.balance().to_string(),
+            conversion: client_balance
+                .conversion()
+                .map(ConversionHttpResponseBody::from),
         }
     }
 }
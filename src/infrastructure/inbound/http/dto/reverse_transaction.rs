@@ -0,0 +1,64 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::model::{
+        dto::reverse_transaction::ReverseTransactionRequest,
+        entity::balance::Balance,
+        value::{transaction_id::TransactionId, version::Version},
+    },
+    infrastructure::inbound::http::error::ApiError,
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::transaction::Transaction;
+
+/// The path to reverse a [Transaction].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReverseTransactionHttpRequestPath {
+    id: String,
+}
+
+/// The body of a [Transaction] reversal request.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReverseTransactionHttpRequestBody {
+    /// The amount to reverse. The whole amount of the [Transaction] if not sent.
+    amount: Option<Decimal>,
+}
+
+impl ReverseTransactionHttpRequestBody {
+    /// Converts the HTTP request path, body and `If-Match` header into a domain request.
+    pub fn try_into_domain(
+        self,
+        path: ReverseTransactionHttpRequestPath,
+        if_match_header: Option<Version>,
+    ) -> Result<ReverseTransactionRequest, ApiError> {
+        let transaction_id = TransactionId::try_from(path.id)?;
+        let mut reverse_transaction_request =
+            ReverseTransactionRequest::new(transaction_id, self.amount)?;
+        if let Some(expected_version) = if_match_header {
+            reverse_transaction_request =
+                reverse_transaction_request.with_expected_version(expected_version);
+        }
+        Ok(reverse_transaction_request)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReverseTransactionHttpResponseBody {
+    id: String,
+    account_id: Option<String>,
+    currency: String,
+    balance: String,
+}
+
+impl From<Balance> for ReverseTransactionHttpResponseBody {
+    fn from(client_balance: Balance) -> Self {
+        Self {
+            id: client_balance.client_id().to_string(),
+            account_id: client_balance.account_id().map(ToString::to_string),
+            currency: client_balance.currency().to_string(),
+            balance: client_balance.balance().to_string(),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::model::{
+        dto::transfer::TransferRequest,
+        entity::balance::Balance,
+        value::{client_id::ClientId, currency::Currency},
+    },
+    infrastructure::inbound::http::error::ApiError,
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// The body of a transfer request between two [Client]s.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TransferHttpRequestBody {
+    from_client_id: String,
+    to_client_id: String,
+    amount: Decimal,
+    /// ISO 4217 code of the balances. The default currency if not sent.
+    currency: Option<String>,
+}
+
+impl TransferHttpRequestBody {
+    /// Converts the HTTP request body into a domain request.
+    pub fn try_into_domain(self) -> Result<TransferRequest, ApiError> {
+        let from_client_id = ClientId::try_from(self.from_client_id)?;
+        let to_client_id = ClientId::try_from(self.to_client_id)?;
+        let mut transfer_request = TransferRequest::new(from_client_id, to_client_id, self.amount)?;
+        if let Some(currency) = self.currency {
+            transfer_request = transfer_request.with_currency(Currency::try_from(currency)?);
+        }
+        Ok(transfer_request)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransferBalanceHttpResponseBody {
+    id: String,
+    currency: String,
+    balance: String,
+}
+
+impl From<Balance> for TransferBalanceHttpResponseBody {
+    fn from(client_balance: Balance) -> Self {
+        Self {
+            id: client_balance.client_id().to_string(),
+            currency: client_balance.currency().to_string(),
+            balance: client_balance.balance().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransferHttpResponseBody {
+    from: TransferBalanceHttpResponseBody,
+    to: TransferBalanceHttpResponseBody,
+}
+
+impl From<(Balance, Balance)> for TransferHttpResponseBody {
+    fn from((from, to): (Balance, Balance)) -> Self {
+        Self {
+            from: TransferBalanceHttpResponseBody::from(from),
+            to: TransferBalanceHttpResponseBody::from(to),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{
+    domain::model::{
+        dto::{get_balance::GetClientRequest, update_client::UpdateClientRequest},
+        entity::{client::Client, client_change::ClientChange},
+        error::ClientError,
+        value::{
+            birth_date::BirthDate, client_id::ClientId, client_name::ClientName, country::Country,
+            document::Document, document_type::DocumentType, email::Email,
+            phone_number::PhoneNumber, version::Version,
+        },
+    },
+    infrastructure::inbound::http::error::ApiError,
+};
+
+/// The path of a [Client] whose details are corrected.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct UpdateClientHttpRequestPath {
+    id: String,
+}
+
+impl UpdateClientHttpRequestPath {
+    /// Converts the HTTP request path into a domain request to get the audit trail.
+    pub fn try_into_domain(self) -> Result<GetClientRequest, ApiError> {
+        let client_id = ClientId::try_from(self.id)?;
+        Ok(GetClientRequest::new(client_id))
+    }
+}
+
+/// The body of a [Client] update request. Only the fields that are sent are changed, and `email`
+/// and `phone` are removed if sent as `null`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct UpdateClientHttpRequestBody {
+    name: Option<String>,
+    birth_date: Option<String>,
+    /// `dni`, `cuit`, `cuil` or `passport`. Only sent along with `document`, `dni` if not sent.
+    document_type: Option<String>,
+    document: Option<String>,
+    country: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    email: Option<Option<String>>,
+    /// With its country code, e.g. `+54 9 11 2345-6789`.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    phone: Option<Option<String>>,
+}
+
+/// Tells a field sent as `null`, `Some(None)`, apart from one not sent, left as `None` by
+/// `#[serde(default)]`.
+fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+impl UpdateClientHttpRequestBody {
+    /// Converts the HTTP request path and body into a domain request.
+    pub fn try_into_domain(
+        self,
+        path: UpdateClientHttpRequestPath,
+        if_match_header: Option<Version>,
+    ) -> Result<UpdateClientRequest, ApiError> {
+        let client_id = ClientId::try_from(path.id)?;
+        let mut update_client_request = UpdateClientRequest::new(client_id);
+        if let Some(expected_version) = if_match_header {
+            update_client_request = update_client_request.with_expected_version(expected_version);
+        }
+        if let Some(name) = self.name {
+            update_client_request = update_client_request.with_name(ClientName::new(&name)?);
+        }
+        if let Some(birth_date) = self.birth_date {
+            update_client_request =
+                update_client_request.with_birth_date(BirthDate::new(&birth_date)?);
+        }
+        match (self.document_type, self.document) {
+            (document_type, Some(document)) => {
+                let document_type = document_type
+                    .map(DocumentType::try_from)
+                    .transpose()?
+                    .unwrap_or_default();
+                update_client_request =
+                    update_client_request.with_document(Document::new(document_type, &document)?);
+            }
+            (Some(_), None) => {
+                return Err(ClientError::FieldEmpty {
+                    field_name: "document".to_string(),
+                }
+                .into());
+            }
+            (None, None) => {}
+        }
+        if let Some(country) = self.country {
+            update_client_request = update_client_request.with_country(Country::new(&country)?);
+        }
+        match self.email {
+            Some(Some(email)) => {
+                update_client_request = update_client_request.with_email(Email::try_from(email)?);
+            }
+            Some(None) => update_client_request = update_client_request.without_email(),
+            None => {}
+        }
+        match self.phone {
+            Some(Some(phone)) => {
+                update_client_request =
+                    update_client_request.with_phone(PhoneNumber::try_from(phone)?);
+            }
+            Some(None) => update_client_request = update_client_request.without_phone(),
+            None => {}
+        }
+        Ok(update_client_request)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateClientHttpResponseBody {
+    id: String,
+    name: String,
+    birth_date: String,
+    document_type: String,
+    document: String,
+    /// The ISO 3166-1 alpha-2 code.
+    country: String,
+    country_name: String,
+    /// Masked, e.g. `j***@example.com`.
+    email: Option<String>,
+    /// Masked, e.g. `+*********6789`.
+    phone: Option<String>,
+}
+
+impl From<Client> for UpdateClientHttpResponseBody {
+    fn from(client: Client) -> Self {
+        Self {
+            id: client.id().to_string(),
+            name: client.name().to_string(),
+            birth_date: client.birth_date().to_string(),
+            document_type: client.document().document_type().to_string(),
+            document: client.document().number().to_string(),
+            country: client.country().to_string(),
+            country_name: client.country().name().to_string(),
+            email: client.email().map(Email::masked),
+            phone: client.phone().map(PhoneNumber::masked),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClientChangeHttpResponseBody {
+    field: String,
+    old_value: String,
+    new_value: String,
+    changed_at: String,
+}
+
+impl From<ClientChange> for ClientChangeHttpResponseBody {
+    fn from(change: ClientChange) -> Self {
+        Self {
+            field: change.field().to_string(),
+            old_value: change.old_value().to_string(),
+            new_value: change.new_value().to_string(),
+            changed_at: change.changed_at().to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetClientChangesHttpResponseBody {
+    changes: Vec<ClientChangeHttpResponseBody>,
+}
+
+impl From<Vec<ClientChange>> for GetClientChangesHttpResponseBody {
+    fn from(changes: Vec<ClientChange>) -> Self {
+        Self {
+            changes: changes
+                .into_iter()
+                .map(ClientChangeHttpResponseBody::from)
+                .collect(),
+        }
+    }
+}
@@ -11,6 +11,9 @@ pub struct ApiError {
     status_code: u16,
     error_code: String,
     error_message: String,
+    /// The position of the rejected item when a batch is rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failing_index: Option<usize>,
 }
 
 impl ApiError {
@@ -19,8 +22,14 @@ impl ApiError {
             status_code,
             error_code,
             error_message,
+            failing_index: None,
         }
     }
+
+    pub fn with_failing_index(mut self, failing_index: usize) -> Self {
+        self.failing_index = Some(failing_index);
+        self
+    }
 }
 
 impl std::fmt::Display for ApiError {
@@ -33,11 +42,7 @@ impl actix_web::error::ResponseError for ClientError {
     fn error_response(&self) -> HttpResponse {
         HttpResponse::build(self.status_code())
             .insert_header(ContentType::json())
-            .json(ApiError::new(
-                self.status_code().as_u16(),
-                self.code().to_string(),
-                self.to_string(),
-            ))
+            .json(ApiError::from(self))
     }
 
     fn status_code(&self) -> StatusCode {
@@ -52,6 +57,31 @@ impl actix_web::error::ResponseError for ClientError {
             ClientError::FieldMaxLength { .. } => StatusCode::BAD_REQUEST,
             ClientError::PositiveAmount => StatusCode::BAD_REQUEST,
             ClientError::BalancesEmpty => StatusCode::NOT_FOUND,
+            ClientError::SelfTransfer { .. } => StatusCode::BAD_REQUEST,
+            ClientError::IdempotencyKeyConflict { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::InsufficientFunds { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::TransactionNotFound { .. } => StatusCode::NOT_FOUND,
+            ClientError::TransactionAlreadyReversed { .. } => StatusCode::CONFLICT,
+            ClientError::TransactionNotReversible { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::ReversalExceedsOriginal { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::AuthorizationNotFound { .. } => StatusCode::NOT_FOUND,
+            ClientError::AuthorizationNotPending { .. } => StatusCode::CONFLICT,
+            ClientError::AuthorizationExpired { .. } => StatusCode::CONFLICT,
+            ClientError::CaptureExceedsAuthorization { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::ExchangeRateNotFound { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::BatchEmpty => StatusCode::BAD_REQUEST,
+            ClientError::BatchItemRejected { ref reason, .. } => reason.status_code(),
+            ClientError::AmountOverflow => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::ClientBlocked { .. } => StatusCode::LOCKED,
+            ClientError::ClientClosed { .. } => StatusCode::CONFLICT,
+            ClientError::InvalidStatusTransition { .. } => StatusCode::CONFLICT,
+            ClientError::BalanceNotZero { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::BirthDateInFuture { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::BirthDateImplausible { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::UnderMinimumAge { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ClientError::DuplicateContact { .. } => StatusCode::CONFLICT,
+            ClientError::AccountNotFound { .. } => StatusCode::NOT_FOUND,
+            ClientError::VersionConflict { .. } => StatusCode::PRECONDITION_FAILED,
             ClientError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -67,13 +97,23 @@ impl actix_web::error::ResponseError for ApiError {
     }
 }
 
-impl From<ClientError> for ApiError {
-    fn from(error: ClientError) -> Self {
+impl From<&ClientError> for ApiError {
+    fn from(error: &ClientError) -> Self {
         let status_code = error.status_code();
-        Self::new(
+        let api_error = Self::new(
             status_code.as_u16(),
             error.code().to_string(),
             error.to_string(),
-        )
+        );
+        match error {
+            ClientError::BatchItemRejected { index, .. } => api_error.with_failing_index(*index),
+            _ => api_error,
+        }
+    }
+}
+
+impl From<ClientError> for ApiError {
+    fn from(error: ClientError) -> Self {
+        Self::from(&error)
     }
 }
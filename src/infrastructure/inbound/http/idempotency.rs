@@ -0,0 +1,43 @@
+use actix_web::HttpRequest;
+
+use crate::{
+    domain::model::{error::ClientError, value::idempotency_key::IdempotencyKey},
+    infrastructure::inbound::http::error::ApiError,
+};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Reads the optional `Idempotency-Key` header of a transaction request.
+pub fn idempotency_key_header(request: &HttpRequest) -> Result<Option<IdempotencyKey>, ApiError> {
+    let Some(value) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+    let value = value.to_str().map_err(|_| ClientError::FieldInvalid {
+        field_name: "idempotency_key".to_string(),
+        value: String::from_utf8_lossy(value.as_bytes()).to_string(),
+    })?;
+    Ok(Some(IdempotencyKey::new(value)?))
+}
+
+/// Resolves the idempotency key from the header and the `external_reference` of the body.
+/// If both are sent, they must be the same.
+pub fn resolve_idempotency_key(
+    header: Option<IdempotencyKey>,
+    external_reference: Option<String>,
+) -> Result<Option<IdempotencyKey>, ApiError> {
+    let external_reference = external_reference
+        .as_deref()
+        .map(IdempotencyKey::new)
+        .transpose()?;
+
+    match (header, external_reference) {
+        (Some(header), Some(external_reference)) if header != external_reference => {
+            Err(ClientError::FieldInvalid {
+                field_name: "idempotency_key".to_string(),
+                value: external_reference.to_string(),
+            }
+            .into())
+        }
+        (header, external_reference) => Ok(header.or(external_reference)),
+    }
+}
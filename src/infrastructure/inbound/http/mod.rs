@@ -1,5 +1,7 @@
 pub mod client_balance_handlers;
 pub mod dto;
 pub mod error;
+pub mod idempotency;
 pub mod logger;
+pub mod precondition;
 pub mod server;
@@ -0,0 +1,40 @@
+use actix_web::{
+    HttpRequest,
+    http::header::{self, ETag, EntityTag},
+};
+
+use crate::{
+    domain::model::{error::ClientError, value::version::Version},
+    infrastructure::inbound::http::error::ApiError,
+};
+
+#[allow(unused_imports)]
+use crate::domain::model::entity::client::Client;
+
+/// Reads the optional `If-Match` header of a mutating request, as the [Version] of the [Client]
+/// the caller last read. `*` matches any version, the same as not sending it.
+pub fn if_match_header(request: &HttpRequest) -> Result<Option<Version>, ApiError> {
+    let Some(value) = request.headers().get(header::IF_MATCH) else {
+        return Ok(None);
+    };
+    let invalid = || ClientError::FieldInvalid {
+        field_name: "if_match".to_string(),
+        value: String::from_utf8_lossy(value.as_bytes()).to_string(),
+    };
+    let value = value.to_str().map_err(|_| invalid())?.trim();
+    if value == "*" {
+        return Ok(None);
+    }
+    // `If-Match` uses the strong comparison, so a weak tag could never match any version.
+    let entity_tag = value.parse::<EntityTag>().map_err(|_| invalid())?;
+    if entity_tag.weak {
+        return Err(invalid().into());
+    }
+    let version = Version::new(entity_tag.tag()).map_err(|_| invalid())?;
+    Ok(Some(version))
+}
+
+/// The `ETag` header of a response, holding the [Version] of the [Client] it shows.
+pub fn etag(version: &Version) -> ETag {
+    ETag(EntityTag::new_strong(version.to_string()))
+}
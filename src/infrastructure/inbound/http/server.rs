@@ -7,13 +7,22 @@ use std::sync::Arc;
 use tracing_actix_web::TracingLogger;
 
 use crate::{
-    CREATE_CLIENT_METHOD, GET_CLIENT_BALANCE_METHOD, NEW_CREDIT_TRANSACTION_METHOD,
-    NEW_DEBIT_TRANSACTION_METHOD, STORE_BALANCES_METHOD,
+    AUTHORIZE_METHOD, BATCH_TRANSACTIONS_METHOD, BLOCK_CLIENT_METHOD, CAPTURE_AUTHORIZATION_METHOD,
+    CLOSE_CLIENT_METHOD, CREATE_ACCOUNT_METHOD, CREATE_CLIENT_METHOD, GET_CLIENT_BALANCE_METHOD,
+    GET_CLIENT_BY_DOCUMENT_METHOD, GET_CLIENT_CHANGES_METHOD, GET_CLIENT_TRANSACTIONS_METHOD,
+    LIST_CLIENTS_METHOD, NEW_CREDIT_TRANSACTION_METHOD, NEW_DEBIT_TRANSACTION_METHOD,
+    REVERSE_TRANSACTION_METHOD, STORE_BALANCES_METHOD, TRANSFER_METHOD, UNBLOCK_CLIENT_METHOD,
+    UPDATE_CLIENT_METHOD, VOID_AUTHORIZATION_METHOD,
     domain::port::inbound::client_balance_service::ClientBalanceService,
     infrastructure::inbound::http::{
         client_balance_handlers::{
-            CREATE_CLIENT_ROUTE, GET_CLIENT_BALANCE_ROUTE, NEW_CREDIT_TRANSACTION_ROUTE,
-            NEW_DEBIT_TRANSACTION_ROUTE, STORE_BALANCES_ROUTE,
+            AUTHORIZE_ROUTE, BATCH_TRANSACTIONS_PAYLOAD_LIMIT, BATCH_TRANSACTIONS_ROUTE,
+            BLOCK_CLIENT_ROUTE, CAPTURE_AUTHORIZATION_ROUTE, CLOSE_CLIENT_ROUTE,
+            CREATE_ACCOUNT_ROUTE, CREATE_CLIENT_ROUTE, GET_CLIENT_BALANCE_ROUTE,
+            GET_CLIENT_BY_DOCUMENT_ROUTE, GET_CLIENT_CHANGES_ROUTE, GET_CLIENT_TRANSACTIONS_ROUTE,
+            LIST_CLIENTS_ROUTE, NEW_CREDIT_TRANSACTION_ROUTE, NEW_DEBIT_TRANSACTION_ROUTE,
+            REVERSE_TRANSACTION_ROUTE, STORE_BALANCES_ROUTE, TRANSFER_ROUTE, UNBLOCK_CLIENT_ROUTE,
+            UPDATE_CLIENT_ROUTE, VOID_AUTHORIZATION_ROUTE,
         },
         logger::CustomLogger,
     },
@@ -76,7 +85,19 @@ fn app_builder<T: ClientBalanceService>(
         .app_data(client_service)
         .wrap(TracingLogger::<CustomLogger>::new())
         .route(CREATE_CLIENT_ROUTE, CREATE_CLIENT_METHOD!(T))
+        .route(CREATE_ACCOUNT_ROUTE, CREATE_ACCOUNT_METHOD!(T))
         .route(GET_CLIENT_BALANCE_ROUTE, GET_CLIENT_BALANCE_METHOD!(T))
+        .route(LIST_CLIENTS_ROUTE, LIST_CLIENTS_METHOD!(T))
+        .route(
+            GET_CLIENT_BY_DOCUMENT_ROUTE,
+            GET_CLIENT_BY_DOCUMENT_METHOD!(T),
+        )
+        .route(UPDATE_CLIENT_ROUTE, UPDATE_CLIENT_METHOD!(T))
+        .route(GET_CLIENT_CHANGES_ROUTE, GET_CLIENT_CHANGES_METHOD!(T))
+        .route(
+            GET_CLIENT_TRANSACTIONS_ROUTE,
+            GET_CLIENT_TRANSACTIONS_METHOD!(T),
+        )
         .route(
             NEW_CREDIT_TRANSACTION_ROUTE,
             NEW_CREDIT_TRANSACTION_METHOD!(T),
@@ -85,5 +106,21 @@ fn app_builder<T: ClientBalanceService>(
             NEW_DEBIT_TRANSACTION_ROUTE,
             NEW_DEBIT_TRANSACTION_METHOD!(T),
         )
+        .route(TRANSFER_ROUTE, TRANSFER_METHOD!(T))
+        .service(
+            web::resource(BATCH_TRANSACTIONS_ROUTE)
+                .app_data(web::JsonConfig::default().limit(BATCH_TRANSACTIONS_PAYLOAD_LIMIT))
+                .route(BATCH_TRANSACTIONS_METHOD!(T)),
+        )
+        .route(REVERSE_TRANSACTION_ROUTE, REVERSE_TRANSACTION_METHOD!(T))
+        .route(AUTHORIZE_ROUTE, AUTHORIZE_METHOD!(T))
+        .route(
+            CAPTURE_AUTHORIZATION_ROUTE,
+            CAPTURE_AUTHORIZATION_METHOD!(T),
+        )
+        .route(VOID_AUTHORIZATION_ROUTE, VOID_AUTHORIZATION_METHOD!(T))
+        .route(BLOCK_CLIENT_ROUTE, BLOCK_CLIENT_METHOD!(T))
+        .route(UNBLOCK_CLIENT_ROUTE, UNBLOCK_CLIENT_METHOD!(T))
+        .route(CLOSE_CLIENT_ROUTE, CLOSE_CLIENT_METHOD!(T))
         .route(STORE_BALANCES_ROUTE, STORE_BALANCES_METHOD!(T))
 }
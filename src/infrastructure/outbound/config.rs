@@ -0,0 +1,37 @@
+use chrono::Duration;
+
+const DEFAULT_IDEMPOTENCY_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+const DEFAULT_AUTHORIZATION_TIMEOUT_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// How long an idempotency key is remembered, set in `IDEMPOTENCY_WINDOW_SECONDS`, one day by
+/// default.
+pub fn get_idempotency_window() -> Duration {
+    let seconds = std::env::var("IDEMPOTENCY_WINDOW_SECONDS")
+        .unwrap_or(DEFAULT_IDEMPOTENCY_WINDOW_SECONDS.to_string());
+    Duration::seconds(
+        seconds
+            .parse::<i64>()
+            .expect("IDEMPOTENCY_WINDOW_SECONDS must be a number"),
+    )
+}
+
+/// How long a pending authorization holds its funds, set in `AUTHORIZATION_TIMEOUT_SECONDS`,
+/// seven days by default.
+pub fn get_authorization_timeout() -> Duration {
+    let seconds = std::env::var("AUTHORIZATION_TIMEOUT_SECONDS")
+        .unwrap_or(DEFAULT_AUTHORIZATION_TIMEOUT_SECONDS.to_string());
+    Duration::seconds(
+        seconds
+            .parse::<i64>()
+            .expect("AUTHORIZATION_TIMEOUT_SECONDS must be a number"),
+    )
+}
+
+/// Whether two clients can't share an email or phone number, set in `UNIQUE_CONTACTS`, false by
+/// default.
+pub fn get_unique_contacts() -> bool {
+    std::env::var("UNIQUE_CONTACTS")
+        .unwrap_or(false.to_string())
+        .parse::<bool>()
+        .expect("UNIQUE_CONTACTS must be true or false")
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use rust_decimal::Decimal;
+
+use crate::domain::{
+    model::{entity::exchange_rate::ExchangeRate, error::ClientError, value::currency::Currency},
+    port::outbound::exchange_rate_provider::ExchangeRateProvider,
+};
+
+/// A fixed table of [ExchangeRate]s kept in memory, optionally loaded from a file.
+#[derive(Debug, Default)]
+pub struct ExchangeRateTable {
+    rates: HashMap<(Currency, Currency), ExchangeRate>,
+}
+
+impl ExchangeRateTable {
+    pub fn new(rates: impl IntoIterator<Item = ExchangeRate>) -> Self {
+        Self {
+            rates: rates
+                .into_iter()
+                .map(|rate| ((rate.from().clone(), rate.to().clone()), rate))
+                .collect(),
+        }
+    }
+
+    /// Loads the table from the file set in `EXCHANGE_RATES_FILE`. Without it the table is empty,
+    /// so only operations in the currency of the balance are possible.
+    pub async fn from_env() -> Result<Self, anyhow::Error> {
+        match std::env::var("EXCHANGE_RATES_FILE") {
+            Ok(path) => Self::from_file(&path).await,
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Loads the table from a file with one rate per line, with the format "FROM TO RATE SPREAD",
+    /// for example "USD ARS 1000 0.02". Empty lines and lines starting with `#` are ignored.
+    /// A rate only converts in the given direction: the inverse pair needs its own line.
+    pub async fn from_file(path: &str) -> Result<Self, anyhow::Error> {
+        let content = tokio::fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Error reading exchange rates file: {path}"))?;
+
+        let mut rates = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let rate = parse_rate(line)
+                .with_context(|| format!("Invalid exchange rate at {path}:{}", index + 1))?;
+            rates.push(rate);
+        }
+
+        Ok(Self::new(rates))
+    }
+}
+
+fn parse_rate(line: &str) -> Result<ExchangeRate, anyhow::Error> {
+    let [from, to, rate, spread] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+        anyhow::bail!("expected \"FROM TO RATE SPREAD\", got \"{line}\"");
+    };
+    Ok(ExchangeRate::new(
+        Currency::new(from)?,
+        Currency::new(to)?,
+        rate.parse::<Decimal>()?,
+        spread.parse::<Decimal>()?,
+    )?)
+}
+
+impl ExchangeRateProvider for ExchangeRateTable {
+    async fn get_exchange_rate(
+        &self,
+        from: &Currency,
+        to: &Currency,
+    ) -> Result<ExchangeRate, ClientError> {
+        self.rates.get(&(from.clone(), to.clone())).cloned().ok_or(
+            ClientError::ExchangeRateNotFound {
+                from: from.clone(),
+                to: to.clone(),
+            },
+        )
+    }
+}
@@ -17,6 +17,7 @@ pub struct FileExporter {
 }
 
 impl FileExporter {
+    #[allow(clippy::collapsible_if)]
     pub async fn new() -> Result<Self, anyhow::Error> {
         let directory =
             std::env::var("FILE_EXPORT_DIRECTORY").unwrap_or(DEFAULT_DIRECTORY.to_string());
@@ -54,6 +55,8 @@ fn extract_counter(file_name: &str) -> Option<usize> {
 impl BalanceExporter for FileExporter {
     /// Exports the balances to a file with the format "DDMMYYYY_COUNTER.DAT"
     /// where DDMMYYYY is the current date and COUNTER is a counter that is incremented for each file.
+    /// Each line holds the client id, the account id, the currency and the balance, so a client has one
+    /// line per account and currency.
     ///
     /// # Arguments
     ///
@@ -78,7 +81,17 @@ impl BalanceExporter for FileExporter {
             .with_context(|| format!("Error creating file: {file_path}"))?;
 
         for balance in balances {
-            file.write_all(format!("{} {}\n", balance.client_id(), balance.balance()).as_bytes())
+            let account_id = balance
+                .account_id()
+                .map_or_else(|| "-".to_string(), ToString::to_string);
+            let line = format!(
+                "{} {} {} {}\n",
+                balance.client_id(),
+                account_id,
+                balance.currency(),
+                balance.balance()
+            );
+            file.write_all(line.as_bytes())
                 .await
                 .with_context(|| format!("Error writing to file: {file_path}"))?;
         }
@@ -0,0 +1,352 @@
+use std::{
+    str::FromStr,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use anyhow::{Context, bail};
+use chrono::Utc;
+
+use crate::domain::{
+    model::{error::ClientError, value::client_id::ClientId},
+    port::outbound::id_generator::IdGenerator,
+};
+
+/// 2024-01-01T00:00:00Z, the start of the time of the Snowflake ids.
+const SNOWFLAKE_EPOCH_MILLIS: i64 = 1_704_067_200_000;
+const SNOWFLAKE_WORKER_ID_BITS: u32 = 10;
+const SNOWFLAKE_SEQUENCE_BITS: u32 = 12;
+pub const MAX_SNOWFLAKE_WORKER_ID: u64 = (1 << SNOWFLAKE_WORKER_ID_BITS) - 1;
+const MAX_SNOWFLAKE_SEQUENCE: u64 = (1 << SNOWFLAKE_SEQUENCE_BITS) - 1;
+
+/// The strategies to generate [ClientId]s, chosen with `CLIENT_ID_STRATEGY`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdGeneratorStrategy {
+    /// 0, 1, 2... Easy to read, but easy to enumerate.
+    #[default]
+    Sequential,
+    /// RFC 9562 UUIDv7, ordered by time and random otherwise.
+    UuidV7,
+    /// ULID, ordered by time and monotonic within the same millisecond.
+    Ulid,
+    /// 64-bit ids made of the time, the worker id and a sequence, rendered as a number.
+    Snowflake,
+}
+
+impl FromStr for IdGeneratorStrategy {
+    type Err = anyhow::Error;
+
+    fn from_str(strategy: &str) -> Result<Self, Self::Err> {
+        match strategy.trim().to_lowercase().as_str() {
+            "sequential" => Ok(Self::Sequential),
+            "uuid_v7" | "uuidv7" => Ok(Self::UuidV7),
+            "ulid" => Ok(Self::Ulid),
+            "snowflake" => Ok(Self::Snowflake),
+            _ => bail!(
+                "CLIENT_ID_STRATEGY must be sequential, uuid_v7, ulid or snowflake, got \"{strategy}\""
+            ),
+        }
+    }
+}
+
+/// Builds the [IdGenerator] set in `CLIENT_ID_STRATEGY`, sequential by default. The Snowflake one
+/// takes its worker id from `SNOWFLAKE_WORKER_ID`, 0 by default.
+pub fn from_env() -> Result<Box<dyn IdGenerator>, anyhow::Error> {
+    from_vars(
+        std::env::var("CLIENT_ID_STRATEGY").ok(),
+        std::env::var("SNOWFLAKE_WORKER_ID").ok(),
+    )
+}
+
+/// Like [from_env], with the values of `CLIENT_ID_STRATEGY` and `SNOWFLAKE_WORKER_ID` given.
+fn from_vars(
+    strategy: Option<String>,
+    worker_id: Option<String>,
+) -> Result<Box<dyn IdGenerator>, anyhow::Error> {
+    let strategy = match strategy {
+        Some(strategy) => strategy.parse()?,
+        None => IdGeneratorStrategy::default(),
+    };
+    Ok(match strategy {
+        IdGeneratorStrategy::Sequential => Box::new(SequentialIdGenerator::default()),
+        IdGeneratorStrategy::UuidV7 => Box::new(UuidV7IdGenerator),
+        IdGeneratorStrategy::Ulid => Box::new(UlidIdGenerator::default()),
+        IdGeneratorStrategy::Snowflake => {
+            let worker_id = match worker_id {
+                Some(worker_id) => worker_id
+                    .parse()
+                    .context("SNOWFLAKE_WORKER_ID must be a number")?,
+                None => 0,
+            };
+            Box::new(SnowflakeIdGenerator::new(worker_id)?)
+        }
+    })
+}
+
+/// Generates 0, 1, 2... in the order they are requested.
+#[derive(Debug, Default)]
+pub struct SequentialIdGenerator {
+    counter: AtomicUsize,
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_client_id(&self) -> Result<ClientId, ClientError> {
+        ClientId::new(&self.counter.fetch_add(1, Ordering::Relaxed).to_string())
+    }
+}
+
+/// Generates UUIDv7s, such as `0190f5a2-7b3c-7d4e-8f90-123456789abc`.
+#[derive(Debug, Default)]
+pub struct UuidV7IdGenerator;
+
+impl IdGenerator for UuidV7IdGenerator {
+    fn next_client_id(&self) -> Result<ClientId, ClientError> {
+        ClientId::new(&uuid::Uuid::now_v7().to_string())
+    }
+}
+
+/// Generates ULIDs, such as `01J1ZK7Q8X9Y0Z1A2B3C4D5E6F`.
+#[derive(Default)]
+pub struct UlidIdGenerator {
+    generator: Mutex<ulid::Generator>,
+}
+
+impl IdGenerator for UlidIdGenerator {
+    fn next_client_id(&self) -> Result<ClientId, ClientError> {
+        let mut generator = self
+            .generator
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Poisoned lock on ULID generator: {}", e))?;
+        let ulid = generator
+            .generate()
+            .context("Too many ULIDs generated in the same millisecond")?;
+        ClientId::new(&ulid.to_string())
+    }
+}
+
+/// Generates Snowflake ids: the milliseconds since 2024-01-01, the worker id and a sequence that
+/// restarts every millisecond, so several workers never generate the same id.
+#[derive(Debug)]
+pub struct SnowflakeIdGenerator {
+    worker_id: u64,
+    /// The millisecond of the last id and the sequence used in it.
+    last: Mutex<(u64, u64)>,
+}
+
+impl SnowflakeIdGenerator {
+    pub fn new(worker_id: u64) -> Result<Self, anyhow::Error> {
+        if worker_id > MAX_SNOWFLAKE_WORKER_ID {
+            bail!("SNOWFLAKE_WORKER_ID must be at most {MAX_SNOWFLAKE_WORKER_ID}, got {worker_id}");
+        }
+        Ok(Self {
+            worker_id,
+            last: Mutex::new((0, 0)),
+        })
+    }
+
+    fn now_millis() -> u64 {
+        (Utc::now().timestamp_millis() - SNOWFLAKE_EPOCH_MILLIS).max(0) as u64
+    }
+}
+
+impl IdGenerator for SnowflakeIdGenerator {
+    fn next_client_id(&self) -> Result<ClientId, ClientError> {
+        self.next_client_id_at(Self::now_millis())
+    }
+}
+
+impl SnowflakeIdGenerator {
+    /// The next id, given the milliseconds since the epoch of the Snowflake ids.
+    fn next_client_id_at(&self, now_millis: u64) -> Result<ClientId, ClientError> {
+        let mut last = self
+            .last
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Poisoned lock on Snowflake generator: {}", e))?;
+        let (last_millis, last_sequence) = *last;
+        // The clock never goes back for the ids: if it does, or the sequence of the millisecond
+        // runs out, the ids keep counting from the last millisecond used.
+        let mut millis = now_millis.max(last_millis);
+        let mut sequence = 0;
+        if millis == last_millis {
+            sequence = last_sequence + 1;
+            if sequence > MAX_SNOWFLAKE_SEQUENCE {
+                millis += 1;
+                sequence = 0;
+            }
+        }
+        *last = (millis, sequence);
+        let id = (millis << (SNOWFLAKE_WORKER_ID_BITS + SNOWFLAKE_SEQUENCE_BITS))
+            | (self.worker_id << SNOWFLAKE_SEQUENCE_BITS)
+            | sequence;
+        ClientId::new(&id.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The millisecond, worker id and sequence a Snowflake id is made of.
+    fn unpack(id: &ClientId) -> (u64, u64, u64) {
+        let id = id.to_string().parse::<u64>().unwrap();
+        (
+            id >> (SNOWFLAKE_WORKER_ID_BITS + SNOWFLAKE_SEQUENCE_BITS),
+            (id >> SNOWFLAKE_SEQUENCE_BITS) & MAX_SNOWFLAKE_WORKER_ID,
+            id & MAX_SNOWFLAKE_SEQUENCE,
+        )
+    }
+
+    fn assert_increasing(generator: &dyn IdGenerator) {
+        let ids = (0..1000)
+            .map(|_| generator.next_client_id().unwrap())
+            .collect::<Vec<_>>();
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_01_given_a_sequential_generator_when_generating_ids_then_they_should_count_from_zero() {
+        let generator = SequentialIdGenerator::default();
+        assert_eq!(generator.next_client_id().unwrap().to_string(), "0");
+        assert_eq!(generator.next_client_id().unwrap().to_string(), "1");
+        assert_eq!(generator.next_client_id().unwrap().to_string(), "2");
+    }
+
+    #[test]
+    fn test_02_given_a_snowflake_generator_when_generating_an_id_then_it_should_pack_the_time_the_worker_and_the_sequence()
+     {
+        let generator = SnowflakeIdGenerator::new(5).unwrap();
+        let id = generator.next_client_id_at(1000).unwrap();
+        assert_eq!(id.to_string(), ((1000_u64 << 22) | (5 << 12)).to_string());
+        assert_eq!(unpack(&id), (1000, 5, 0));
+        assert_eq!(
+            unpack(&generator.next_client_id_at(1000).unwrap()),
+            (1000, 5, 1)
+        );
+        assert_eq!(
+            unpack(&generator.next_client_id_at(1001).unwrap()),
+            (1001, 5, 0)
+        );
+    }
+
+    #[test]
+    fn test_03_given_a_worker_id_out_of_bounds_when_creating_a_snowflake_generator_then_it_should_fail()
+     {
+        let generator = SnowflakeIdGenerator::new(MAX_SNOWFLAKE_WORKER_ID).unwrap();
+        assert_eq!(
+            unpack(&generator.next_client_id_at(1).unwrap()),
+            (1, MAX_SNOWFLAKE_WORKER_ID, 0)
+        );
+        let error = SnowflakeIdGenerator::new(MAX_SNOWFLAKE_WORKER_ID + 1)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "SNOWFLAKE_WORKER_ID must be at most 1023, got 1024"
+        );
+    }
+
+    #[test]
+    fn test_04_given_the_sequence_runs_out_within_a_millisecond_when_generating_an_id_then_it_should_move_to_the_next_millisecond()
+     {
+        let generator = SnowflakeIdGenerator::new(1).unwrap();
+        for sequence in 0..=MAX_SNOWFLAKE_SEQUENCE {
+            assert_eq!(
+                unpack(&generator.next_client_id_at(1000).unwrap()),
+                (1000, 1, sequence)
+            );
+        }
+        assert_eq!(
+            unpack(&generator.next_client_id_at(1000).unwrap()),
+            (1001, 1, 0)
+        );
+        // The millisecond borrowed from the future is not reused when the clock reaches it.
+        assert_eq!(
+            unpack(&generator.next_client_id_at(1001).unwrap()),
+            (1001, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_05_given_the_clock_goes_back_when_generating_an_id_then_it_should_keep_counting_from_the_last_millisecond()
+     {
+        let generator = SnowflakeIdGenerator::new(1).unwrap();
+        let first = generator.next_client_id_at(2000).unwrap();
+        let second = generator.next_client_id_at(1500).unwrap();
+        assert_eq!(unpack(&second), (2000, 1, 1));
+        assert!(first < second);
+        assert_eq!(
+            unpack(&generator.next_client_id_at(2001).unwrap()),
+            (2001, 1, 0)
+        );
+    }
+
+    #[test]
+    fn test_06_given_a_ulid_generator_when_generating_ids_then_they_should_be_increasing() {
+        assert_increasing(&UlidIdGenerator::default());
+    }
+
+    #[test]
+    fn test_07_given_a_uuid_v7_generator_when_generating_ids_then_they_should_be_increasing() {
+        let generator = UuidV7IdGenerator;
+        assert_increasing(&generator);
+        let id = generator.next_client_id().unwrap().to_string();
+        assert_eq!(uuid::Uuid::parse_str(&id).unwrap().get_version_num(), 7);
+    }
+
+    #[test]
+    fn test_08_given_a_strategy_name_when_parsing_it_then_it_should_ignore_case_and_spaces() {
+        assert_eq!(
+            "sequential".parse::<IdGeneratorStrategy>().unwrap(),
+            IdGeneratorStrategy::Sequential
+        );
+        assert_eq!(
+            " UUID_V7 ".parse::<IdGeneratorStrategy>().unwrap(),
+            IdGeneratorStrategy::UuidV7
+        );
+        assert_eq!(
+            "uuidv7".parse::<IdGeneratorStrategy>().unwrap(),
+            IdGeneratorStrategy::UuidV7
+        );
+        assert_eq!(
+            "Ulid".parse::<IdGeneratorStrategy>().unwrap(),
+            IdGeneratorStrategy::Ulid
+        );
+        assert_eq!(
+            "snowflake".parse::<IdGeneratorStrategy>().unwrap(),
+            IdGeneratorStrategy::Snowflake
+        );
+        assert_eq!(
+            "random"
+                .parse::<IdGeneratorStrategy>()
+                .err()
+                .unwrap()
+                .to_string(),
+            "CLIENT_ID_STRATEGY must be sequential, uuid_v7, ulid or snowflake, got \"random\""
+        );
+    }
+
+    #[test]
+    fn test_09_given_the_env_values_when_building_the_generator_then_it_should_use_the_strategy_set()
+     {
+        let sequential = from_vars(None, None).unwrap();
+        assert_eq!(sequential.next_client_id().unwrap().to_string(), "0");
+        let ulid = from_vars(Some("ulid".to_string()), None).unwrap();
+        assert_eq!(ulid.next_client_id().unwrap().to_string().len(), 26);
+        let snowflake = from_vars(Some("snowflake".to_string()), Some("7".to_string())).unwrap();
+        assert_eq!(unpack(&snowflake.next_client_id().unwrap()).1, 7);
+        let snowflake = from_vars(Some("snowflake".to_string()), None).unwrap();
+        assert_eq!(unpack(&snowflake.next_client_id().unwrap()).1, 0);
+    }
+
+    #[test]
+    fn test_10_given_invalid_env_values_when_building_the_generator_then_it_should_fail() {
+        assert!(from_vars(Some("random".to_string()), None).is_err());
+        let error = from_vars(Some("snowflake".to_string()), Some("abc".to_string()))
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "SNOWFLAKE_WORKER_ID must be a number");
+        assert!(from_vars(Some("snowflake".to_string()), Some("1024".to_string())).is_err());
+    }
+}
@@ -1,32 +1,672 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap, VecDeque},
+    hash::{BuildHasher, Hash, RandomState},
     sync::{
-        Arc, Mutex, MutexGuard,
+        Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
         atomic::{AtomicUsize, Ordering},
     },
 };
 
+use chrono::{DateTime, Duration, Utc};
 use rust_decimal::Decimal;
 
 use crate::domain::{
     model::{
         dto::{
-            create_client::CreateClientRequest, credit_transaction::CreditTransactionRequest,
+            authorize::AuthorizeRequest, batch_transaction::BatchTransactionRequest,
+            capture_authorization::CaptureAuthorizationRequest,
+            create_account::CreateAccountRequest, create_client::CreateClientRequest,
+            credit_transaction::CreditTransactionRequest,
             debit_transaction::DebitTransactionRequest, get_balance::GetClientRequest,
+            list_clients::ListClientsRequest, transfer::TransferRequest,
+            update_client::UpdateClientRequest,
+        },
+        entity::{
+            account::Account, authorization::Authorization, balance::Balance,
+            balance_reset::BalanceReset, client::Client, client_change::ClientChange,
+            client_overview::ClientOverview, client_page::ClientPage, conversion::Conversion,
+            idempotency_record::IdempotencyRecord, transaction::Transaction,
         },
-        entity::{balance::Balance, client::Client},
         error::ClientError,
-        value::{client_id::ClientId, document::Document},
+        value::{
+            account_id::AccountId,
+            authorization_id::AuthorizationId,
+            authorization_status::AuthorizationStatus,
+            client_cursor::ClientCursor,
+            client_id::ClientId,
+            client_sort::{ClientSort, SortDirection},
+            client_status::ClientStatus,
+            currency::Currency,
+            document::Document,
+            email::Email,
+            idempotency_key::IdempotencyKey,
+            money::Money,
+            phone_number::PhoneNumber,
+            transaction_id::TransactionId,
+            transaction_kind::TransactionKind,
+            version::Version,
+        },
+    },
+    port::outbound::{
+        client_balance_repository::ClientBalanceRepository, id_generator::IdGenerator,
     },
-    port::outbound::client_balance_repository::ClientBalanceRepository,
 };
+use crate::infrastructure::outbound::{
+    config::{get_authorization_timeout, get_idempotency_window, get_unique_contacts},
+    id_generators::SequentialIdGenerator,
+    write_ahead_log::{LogEntry, WriteAheadLog},
+};
+
+/// How many shards the clients and the indexes by id are split in. Many more than the cores of a
+/// server, so two requests rarely wait for each other only because their keys share a shard.
+const SHARD_COUNT: usize = 64;
+
+/// A [Client] with everything it owns, behind a lock of its own.
+type ClientHandle = Arc<Mutex<ClientState>>;
+
+/// The balances of an [Account], one per [Currency].
+type CurrencyBalances = BTreeMap<Currency, Money>;
+
+/// Every [Account] starts with a zero balance in the default currency, so its client is always
+/// listed and exported even before its first transaction.
+fn opening_balances() -> CurrencyBalances {
+    BTreeMap::from([(Currency::default(), Money::zero(Currency::default()))])
+}
+
+fn guard<'a, T>(mutex: &'a Mutex<T>, name: &str) -> Result<MutexGuard<'a, T>, anyhow::Error> {
+    match mutex.lock() {
+        Ok(lock) => Ok(lock),
+        Err(e) => Err(anyhow::anyhow!("Poisoned lock on {}: {}", name, e)),
+    }
+}
+
+fn guard_read<'a, T>(
+    lock: &'a RwLock<T>,
+    name: &str,
+) -> Result<RwLockReadGuard<'a, T>, anyhow::Error> {
+    match lock.read() {
+        Ok(lock) => Ok(lock),
+        Err(e) => Err(anyhow::anyhow!("Poisoned lock on {}: {}", name, e)),
+    }
+}
+
+fn guard_write<'a, T>(
+    lock: &'a RwLock<T>,
+    name: &str,
+) -> Result<RwLockWriteGuard<'a, T>, anyhow::Error> {
+    match lock.write() {
+        Ok(lock) => Ok(lock),
+        Err(e) => Err(anyhow::anyhow!("Poisoned lock on {}: {}", name, e)),
+    }
+}
+
+/// Locks the given clients in ascending id order, the order every operation on more than one
+/// client takes them in, so two of them never wait for each other.
+fn guard_clients(
+    handles: &BTreeMap<ClientId, ClientHandle>,
+) -> Result<BTreeMap<&ClientId, MutexGuard<'_, ClientState>>, anyhow::Error> {
+    handles
+        .iter()
+        .map(|(client_id, handle)| Ok((client_id, guard(handle, "client")?)))
+        .collect()
+}
+
+/// The value a counter of numeric ids continues from: one past the greatest of them.
+fn next_counter(ids: impl Iterator<Item = String>) -> usize {
+    ids.filter_map(|id| id.parse::<usize>().ok())
+        .max()
+        .map_or(0, |id| id + 1)
+}
+
+/// Values split in shards by the hash of their key, each one behind its own lock, so keys of
+/// different shards are never contended.
+struct Sharded<T> {
+    hasher: RandomState,
+    shards: Vec<T>,
+}
+
+impl<T: Default> Default for Sharded<T> {
+    fn default() -> Self {
+        Self {
+            hasher: RandomState::new(),
+            shards: (0..SHARD_COUNT).map(|_| T::default()).collect(),
+        }
+    }
+}
+
+impl<T> Sharded<T> {
+    fn shard(&self, key: &impl Hash) -> &T {
+        let index = self.hasher.hash_one(key) % self.shards.len() as u64;
+        &self.shards[index as usize]
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        self.shards.iter()
+    }
+}
+
+/// A [Client] with its [Account]s, its ledger, its holds and the audit trail of its details.
+/// Account ids grow with every new one, so they are kept in the order they were opened, the main
+/// one first.
+struct ClientState {
+    client: Client,
+    main_account_id: AccountId,
+    accounts: BTreeMap<AccountId, (Account, CurrencyBalances)>,
+    /// Its [Transaction]s, in the order they were applied.
+    transactions: Vec<Transaction>,
+    authorizations: HashMap<AuthorizationId, Authorization>,
+    /// The changes of its details, in the order they were made.
+    changes: Vec<ClientChange>,
+}
+
+impl ClientState {
+    fn new(client: Client, main_account: Account) -> Self {
+        let main_account_id = main_account.id().clone();
+        Self {
+            client,
+            main_account_id: main_account_id.clone(),
+            accounts: BTreeMap::from([(main_account_id, (main_account, opening_balances()))]),
+            transactions: Vec::new(),
+            authorizations: HashMap::new(),
+            changes: Vec::new(),
+        }
+    }
+
+    /// The balances of the main [Account], the one holds reserve funds from.
+    fn main_balances(&self) -> &CurrencyBalances {
+        self.accounts
+            .get(&self.main_account_id)
+            .map(|(_, currency_balances)| currency_balances)
+            .expect("every client has a main account")
+    }
+
+    /// The ledger balance in the [Currency] added up across every [Account].
+    fn total_balance(&self, currency: &Currency) -> Result<Money, ClientError> {
+        self.accounts.values().try_fold(
+            Money::zero(currency.clone()),
+            |total, (_, currency_balances)| {
+                total.checked_add(&Self::balance_of(currency_balances, currency))
+            },
+        )
+    }
+
+    /// The ledger balances added up across every [Account], one per [Currency].
+    fn total_balances(&self) -> Result<CurrencyBalances, ClientError> {
+        let mut totals = CurrencyBalances::new();
+        for (_, currency_balances) in self.accounts.values() {
+            for (currency, balance) in currency_balances {
+                let total = Self::balance_of(&totals, currency).checked_add(balance)?;
+                totals.insert(currency.clone(), total);
+            }
+        }
+        Ok(totals)
+    }
+
+    /// The amount reserved in the [Currency] by the holds that have not expired yet. Holds always
+    /// reserve funds of the main [Account].
+    fn held_amount(&self, currency: &Currency, now: DateTime<Utc>) -> Result<Money, ClientError> {
+        Self::held_by(self.authorizations.values(), currency, now)
+    }
+
+    fn held_by<'a>(
+        authorizations: impl Iterator<Item = &'a Authorization>,
+        currency: &Currency,
+        now: DateTime<Utc>,
+    ) -> Result<Money, ClientError> {
+        authorizations
+            .filter(|authorization| {
+                authorization.currency() == currency && authorization.is_holding(now)
+            })
+            .try_fold(Money::zero(currency.clone()), |held, authorization| {
+                held.checked_add(&Money::new(*authorization.amount(), currency.clone())?)
+            })
+    }
+
+    /// Its [Balance]s added up across every [Account], one per [Currency], with the amounts held.
+    fn balances(&self, now: DateTime<Utc>) -> Result<Vec<Balance>, ClientError> {
+        self.total_balances()?
+            .iter()
+            .map(|(currency, balance)| {
+                let held = self.held_amount(currency, now)?;
+                Ok(Balance::new(self.client.id().clone(), *balance.amount())
+                    .with_held(*held.amount())
+                    .with_currency(currency.clone())
+                    .with_version(*self.client.version()))
+            })
+            .collect()
+    }
+
+    /// Each [Account] with its [Balance]s, one per [Currency]. Only the main one holds funds.
+    fn account_balances(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<(Account, Vec<Balance>)>, ClientError> {
+        self.accounts
+            .values()
+            .map(|(account, currency_balances)| {
+                let balances = currency_balances
+                    .iter()
+                    .map(|(currency, balance)| {
+                        let held = if account.is_main() {
+                            self.held_amount(currency, now)?
+                        } else {
+                            Money::zero(currency.clone())
+                        };
+                        Ok(Balance::new(self.client.id().clone(), *balance.amount())
+                            .with_account_id(account.id().clone())
+                            .with_held(*held.amount())
+                            .with_currency(currency.clone())
+                            .with_version(*self.client.version()))
+                    })
+                    .collect::<Result<_, ClientError>>()?;
+                Ok((account.clone(), balances))
+            })
+            .collect()
+    }
+
+    /// The ledger balance in the [Currency], zero if the [Account] never operated in it.
+    fn balance_of(currency_balances: &CurrencyBalances, currency: &Currency) -> Money {
+        currency_balances
+            .get(currency)
+            .cloned()
+            .unwrap_or_else(|| Money::zero(currency.clone()))
+    }
+
+    fn find_transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> Result<&Transaction, ClientError> {
+        self.transactions
+            .iter()
+            .find(|transaction| transaction.id() == transaction_id)
+            .ok_or(ClientError::TransactionNotFound {
+                transaction_id: transaction_id.clone(),
+            })
+    }
+
+    fn ensure_reversible(
+        &self,
+        transaction_id: &TransactionId,
+        account_id: &AccountId,
+        amount: &Decimal,
+        currency: &Currency,
+    ) -> Result<(), ClientError> {
+        let transaction = self.find_transaction(transaction_id)?;
+        if self
+            .transactions
+            .iter()
+            .any(|reversal| reversal.reversal_of() == Some(transaction_id))
+        {
+            return Err(ClientError::TransactionAlreadyReversed {
+                transaction_id: transaction_id.clone(),
+            });
+        }
+        if transaction.account_id() != Some(account_id) {
+            return Err(ClientError::TransactionNotReversible {
+                transaction_id: transaction_id.clone(),
+            });
+        }
+        transaction.ensure_reversible_by(self.client.id(), amount, currency)
+    }
+
+    /// Applies a change to this [Client], the same way while running and while replaying the
+    /// [WriteAheadLog]. New clients and idempotency records are kept outside of it.
+    fn apply(&mut self, entry: LogEntry) -> Result<(), ClientError> {
+        match entry {
+            LogEntry::ClientSaved(client) => self.client = client,
+            LogEntry::AccountOpened(account) => {
+                self.accounts
+                    .insert(account.id().clone(), (account, opening_balances()));
+            }
+            LogEntry::BalanceSet {
+                account_id,
+                balance,
+                ..
+            } => {
+                let (_, currency_balances) = self
+                    .accounts
+                    .get_mut(&account_id)
+                    .ok_or(ClientError::AccountNotFound { account_id })?;
+                currency_balances.insert(balance.currency().clone(), balance);
+            }
+            LogEntry::TransactionAppended(transaction) => self.transactions.push(transaction),
+            LogEntry::AuthorizationSaved(authorization) => {
+                self.authorizations
+                    .insert(authorization.id().clone(), authorization);
+            }
+            LogEntry::ClientChanged(change) => self.changes.push(change),
+            LogEntry::ClientCreated { .. } | LogEntry::IdempotencyRecorded(_) => {}
+        }
+        Ok(())
+    }
+
+    /// The fewest entries that rebuild this [Client], for a snapshot of the [WriteAheadLog].
+    fn snapshot_entries(&self) -> Vec<LogEntry> {
+        let (main_account, _) = &self.accounts[&self.main_account_id];
+        let mut entries = vec![LogEntry::ClientCreated {
+            client: self.client.clone(),
+            main_account: main_account.clone(),
+        }];
+        for (account, currency_balances) in self.accounts.values() {
+            if !account.is_main() {
+                entries.push(LogEntry::AccountOpened(account.clone()));
+            }
+            entries.extend(
+                currency_balances
+                    .values()
+                    .map(|balance| LogEntry::BalanceSet {
+                        client_id: account.client_id().clone(),
+                        account_id: account.id().clone(),
+                        balance: balance.clone(),
+                    }),
+            );
+        }
+        entries.extend(
+            self.transactions
+                .iter()
+                .cloned()
+                .map(LogEntry::TransactionAppended),
+        );
+        entries.extend(
+            self.authorizations
+                .values()
+                .cloned()
+                .map(LogEntry::AuthorizationSaved),
+        );
+        entries.extend(self.changes.iter().cloned().map(LogEntry::ClientChanged));
+        entries
+    }
+}
+
+/// The changes a mutation makes to a [ClientState], computed without touching it. Every change is
+/// recorded as a [LogEntry] and applied to the draft right away, so the mutation sees its own
+/// changes, while the state only gets them once they are in the [WriteAheadLog].
+struct Draft<'a> {
+    state: &'a ClientState,
+    client: Client,
+    accounts: BTreeMap<AccountId, (Account, CurrencyBalances)>,
+    /// The [Authorization]s the mutation changed, in place of those of the state.
+    authorizations: HashMap<AuthorizationId, Authorization>,
+    entries: Vec<LogEntry>,
+}
+
+impl<'a> Draft<'a> {
+    fn new(state: &'a ClientState) -> Self {
+        Self {
+            state,
+            client: state.client.clone(),
+            accounts: state.accounts.clone(),
+            authorizations: HashMap::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, entry: LogEntry) {
+        match &entry {
+            LogEntry::ClientSaved(client) => self.client = client.clone(),
+            LogEntry::AccountOpened(account) => {
+                self.accounts
+                    .insert(account.id().clone(), (account.clone(), opening_balances()));
+            }
+            LogEntry::BalanceSet {
+                account_id,
+                balance,
+                ..
+            } => {
+                if let Some((_, currency_balances)) = self.accounts.get_mut(account_id) {
+                    currency_balances.insert(balance.currency().clone(), balance.clone());
+                }
+            }
+            LogEntry::AuthorizationSaved(authorization) => {
+                self.authorizations
+                    .insert(authorization.id().clone(), authorization.clone());
+            }
+            _ => {}
+        }
+        self.entries.push(entry);
+    }
+
+    fn into_entries(self) -> Vec<LogEntry> {
+        self.entries
+    }
+
+    /// The id of the given [Account], or of the main one if unset.
+    fn account_id(&self, account_id: Option<&AccountId>) -> Result<AccountId, ClientError> {
+        match account_id {
+            None => Ok(self.state.main_account_id.clone()),
+            Some(account_id) if self.accounts.contains_key(account_id) => Ok(account_id.clone()),
+            Some(account_id) => Err(ClientError::AccountNotFound {
+                account_id: account_id.clone(),
+            }),
+        }
+    }
+
+    fn authorizations(&self) -> impl Iterator<Item = &Authorization> {
+        self.state
+            .authorizations
+            .iter()
+            .filter(|(authorization_id, _)| !self.authorizations.contains_key(authorization_id))
+            .map(|(_, authorization)| authorization)
+            .chain(self.authorizations.values())
+    }
+
+    fn authorization(
+        &self,
+        authorization_id: &AuthorizationId,
+    ) -> Result<Authorization, ClientError> {
+        self.authorizations
+            .get(authorization_id)
+            .or_else(|| self.state.authorizations.get(authorization_id))
+            .cloned()
+            .ok_or(ClientError::AuthorizationNotFound {
+                authorization_id: authorization_id.clone(),
+            })
+    }
+
+    fn held_amount(&self, currency: &Currency, now: DateTime<Utc>) -> Result<Money, ClientError> {
+        ClientState::held_by(self.authorizations(), currency, now)
+    }
+}
+
+/// The drafts of the clients locked by a mutation, one per client.
+fn drafts<'a>(
+    states: &'a BTreeMap<&ClientId, MutexGuard<'_, ClientState>>,
+) -> BTreeMap<&'a ClientId, Draft<'a>> {
+    states
+        .iter()
+        .map(|(client_id, state)| (*client_id, Draft::new(state)))
+        .collect()
+}
+
+/// The locked clients, for [InMemoryRepository::commit].
+fn locked_states<'a>(
+    states: &'a mut BTreeMap<&ClientId, MutexGuard<'_, ClientState>>,
+) -> Vec<&'a mut ClientState> {
+    states.values_mut().map(|state| &mut **state).collect()
+}
+
+fn draft_of<'a, 'b>(
+    drafts: &'a mut BTreeMap<&ClientId, Draft<'b>>,
+    client_id: &ClientId,
+) -> Result<&'a mut Draft<'b>, ClientError> {
+    drafts.get_mut(client_id).ok_or(ClientError::NotFoundById {
+        id_document: client_id.clone(),
+    })
+}
+
+/// The details no two clients can share, so a [Client] is found by them without going through
+/// every one.
+#[derive(Default)]
+struct ClientIndex {
+    documents: HashMap<Document, ClientId>,
+    /// Contacts are only unique when `UNIQUE_CONTACTS` is set, otherwise they point to the last
+    /// client that took them.
+    emails: HashMap<Email, ClientId>,
+    phones: HashMap<PhoneNumber, ClientId>,
+}
+
+impl ClientIndex {
+    fn insert(&mut self, client: &Client) {
+        self.documents
+            .insert(client.document().clone(), client.id().clone());
+        if let Some(email) = client.email() {
+            self.emails.insert(email.clone(), client.id().clone());
+        }
+        if let Some(phone) = client.phone() {
+            self.phones.insert(phone.clone(), client.id().clone());
+        }
+    }
+
+    /// Drops the details of the [Client], except those another one took since.
+    fn remove(&mut self, client: &Client) {
+        fn remove_owned<K: Hash + Eq>(index: &mut HashMap<K, ClientId>, key: &K, owner: &ClientId) {
+            if index.get(key) == Some(owner) {
+                index.remove(key);
+            }
+        }
+        remove_owned(&mut self.documents, client.document(), client.id());
+        if let Some(email) = client.email() {
+            remove_owned(&mut self.emails, email, client.id());
+        }
+        if let Some(phone) = client.phone() {
+            remove_owned(&mut self.phones, phone, client.id());
+        }
+    }
+
+    /// Checks that no [Client] other than the owner already has the [Document].
+    fn ensure_document_available(
+        &self,
+        document: &Document,
+        owner: Option<&ClientId>,
+    ) -> Result<(), ClientError> {
+        if Self::is_taken(&self.documents, document, owner) {
+            return Err(ClientError::Duplicate {
+                document: document.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that no [Client] other than the owner already has the [Email] or the [PhoneNumber].
+    fn ensure_contacts_available(
+        &self,
+        email: Option<&Email>,
+        phone: Option<&PhoneNumber>,
+        owner: Option<&ClientId>,
+    ) -> Result<(), ClientError> {
+        if let Some(email) = email
+            && Self::is_taken(&self.emails, email, owner)
+        {
+            return Err(ClientError::DuplicateContact {
+                field_name: "email".to_string(),
+                value: email.to_string(),
+            });
+        }
+        if let Some(phone) = phone
+            && Self::is_taken(&self.phones, phone, owner)
+        {
+            return Err(ClientError::DuplicateContact {
+                field_name: "phone".to_string(),
+                value: phone.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn is_taken<K: Hash + Eq>(
+        index: &HashMap<K, ClientId>,
+        key: &K,
+        owner: Option<&ClientId>,
+    ) -> bool {
+        index
+            .get(key)
+            .is_some_and(|client_id| Some(client_id) != owner)
+    }
+
+    fn client_id(&self, document: &Document) -> Result<ClientId, ClientError> {
+        self.documents
+            .get(document)
+            .cloned()
+            .ok_or(ClientError::NotFoundByDocument {
+                document: document.clone(),
+            })
+    }
+}
+
+/// The idempotency records of the keys of a shard.
+#[derive(Default)]
+struct IdempotencyRecords {
+    records: HashMap<IdempotencyKey, IdempotencyRecord>,
+    /// Keys in insertion order, so expired records are evicted from the front.
+    keys_by_age: VecDeque<IdempotencyKey>,
+}
+
+impl IdempotencyRecords {
+    fn insert(&mut self, record: IdempotencyRecord) {
+        self.keys_by_age.push_back(record.key().clone());
+        self.records.insert(record.key().clone(), record);
+    }
 
-type GuardMutexClients<'a> = MutexGuard<'a, HashMap<ClientId, (Client, Decimal)>>;
+    fn evict_expired(&mut self, window: Duration) {
+        let now = Utc::now();
+        while let Some(key) = self.keys_by_age.front() {
+            match self.records.get(key) {
+                Some(record) if !record.is_expired(now, window) => break,
+                _ => {
+                    self.records.remove(key);
+                    self.keys_by_age.pop_front();
+                }
+            }
+        }
+    }
+}
 
+/// What a [Transaction] refers to besides its amount.
+#[derive(Clone, Copy, Default)]
+struct TransactionOrigin<'a> {
+    /// The [Account] it is applied to, the main one if unset.
+    account_id: Option<&'a AccountId>,
+    /// The [Transaction] it compensates, if it is a reversal.
+    reversal_of: Option<&'a TransactionId>,
+    /// The conversion of the requested amount, if it was requested in another [Currency].
+    conversion: Option<&'a Conversion>,
+    /// The [Version] the [Client] must still be at, if the caller expects one.
+    expected_version: Option<&'a Version>,
+}
+
+/// Every [Client] lives behind a lock of its own, so operations on different clients run in
+/// parallel, while a balance update and its [Transaction] are always seen together. Locks are
+/// always taken in the same order, so they never wait for each other: the snapshot gate, the
+/// clients in ascending id order, the [ClientIndex], the idempotency records, and the
+/// [WriteAheadLog] last, only to append to it. The shards of the clients and of the indexes by id
+/// are only held to read or insert an entry.
 pub struct InMemoryRepository {
-    /// Recomiendo leer el README para entender el uso de Mutex sincronico de la std.
-    clients: Arc<Mutex<HashMap<ClientId, (Client, Decimal)>>>,
-    id_counter: AtomicUsize,
+    /// A shard is only held to find or insert a [ClientHandle], and is released before the lock of
+    /// the [Client] behind it is taken.
+    clients: Sharded<RwLock<HashMap<ClientId, ClientHandle>>>,
+    /// Only locked to create a client or to change its details.
+    client_index: Mutex<ClientIndex>,
+    /// The owner of every [Transaction], to find it by id.
+    transaction_owners: Sharded<RwLock<HashMap<TransactionId, ClientId>>>,
+    /// The owner of every [Authorization], to find it by id.
+    authorization_owners: Sharded<RwLock<HashMap<AuthorizationId, ClientId>>>,
+    idempotency_records: Sharded<Mutex<IdempotencyRecords>>,
+    /// Mints the ids of new clients, sequential unless another strategy is set.
+    id_generator: Box<dyn IdGenerator>,
+    transaction_id_counter: AtomicUsize,
+    /// How long an idempotency key is remembered after its first use.
+    idempotency_window: Duration,
+    authorization_id_counter: AtomicUsize,
+    account_id_counter: AtomicUsize,
+    /// How long a hold reserves funds before expiring on its own.
+    authorization_timeout: Duration,
+    /// Whether two clients can share the same email or phone.
+    unique_contacts: bool,
+    /// Where every mutation is appended before it is applied, so it survives a crash.
+    write_ahead_log: Option<Mutex<WriteAheadLog>>,
+    /// Held to read by every mutation while there is a [WriteAheadLog], and to write while taking
+    /// a snapshot of it, so the snapshot never sees a mutation appended but not applied yet.
+    snapshot_gate: RwLock<()>,
 }
 
 impl Default for InMemoryRepository {
@@ -38,138 +678,1114 @@ impl Default for InMemoryRepository {
 impl InMemoryRepository {
     pub fn new() -> Self {
         Self {
-            clients: Arc::new(Mutex::new(HashMap::new())),
-            id_counter: AtomicUsize::new(0),
+            clients: Sharded::default(),
+            client_index: Mutex::new(ClientIndex::default()),
+            transaction_owners: Sharded::default(),
+            authorization_owners: Sharded::default(),
+            idempotency_records: Sharded::default(),
+            id_generator: Box::new(SequentialIdGenerator::default()),
+            transaction_id_counter: AtomicUsize::new(0),
+            idempotency_window: get_idempotency_window(),
+            authorization_id_counter: AtomicUsize::new(0),
+            account_id_counter: AtomicUsize::new(0),
+            authorization_timeout: get_authorization_timeout(),
+            unique_contacts: get_unique_contacts(),
+            write_ahead_log: None,
+            snapshot_gate: RwLock::new(()),
+        }
+    }
+
+    /// Replaces the sequential [IdGenerator] the client ids are minted with.
+    pub fn with_id_generator(mut self, id_generator: Box<dyn IdGenerator>) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    /// Rebuilds the state from the snapshot and the entries of the [WriteAheadLog], and appends
+    /// every later mutation to it.
+    pub fn with_write_ahead_log(
+        mut self,
+        mut write_ahead_log: WriteAheadLog,
+    ) -> Result<Self, anyhow::Error> {
+        for entry in write_ahead_log.replay()? {
+            self.replay(entry)?;
+        }
+        let mut transactions = Vec::new();
+        for shard in self.transaction_owners.iter() {
+            transactions.extend(
+                guard_read(shard, "transactions")?
+                    .keys()
+                    .map(ToString::to_string),
+            );
+        }
+        let mut authorizations = Vec::new();
+        for shard in self.authorization_owners.iter() {
+            authorizations.extend(
+                guard_read(shard, "authorizations")?
+                    .keys()
+                    .map(ToString::to_string),
+            );
+        }
+        let mut accounts = Vec::new();
+        for handle in self.all_clients()? {
+            accounts.extend(
+                guard(&handle, "client")?
+                    .accounts
+                    .keys()
+                    .map(ToString::to_string),
+            );
+        }
+        self.transaction_id_counter = AtomicUsize::new(next_counter(transactions.into_iter()));
+        self.authorization_id_counter = AtomicUsize::new(next_counter(authorizations.into_iter()));
+        self.account_id_counter = AtomicUsize::new(next_counter(accounts.into_iter()));
+        self.write_ahead_log = Some(Mutex::new(write_ahead_log));
+        Ok(self)
+    }
+
+    /// The [Client] with the id, to be locked by the caller.
+    fn client(&self, client_id: &ClientId) -> Result<ClientHandle, ClientError> {
+        guard_read(self.clients.shard(client_id), "clients")?
+            .get(client_id)
+            .cloned()
+            .ok_or(ClientError::NotFoundById {
+                id_document: client_id.clone(),
+            })
+    }
+
+    /// The clients with the given ids that exist, to be locked with [guard_clients].
+    fn clients_by_id<'a>(
+        &self,
+        client_ids: impl IntoIterator<Item = &'a ClientId>,
+    ) -> Result<BTreeMap<ClientId, ClientHandle>, anyhow::Error> {
+        let mut handles = BTreeMap::new();
+        for client_id in client_ids {
+            if let Some(handle) =
+                guard_read(self.clients.shard(client_id), "clients")?.get(client_id)
+            {
+                handles.insert(client_id.clone(), handle.clone());
+            }
+        }
+        Ok(handles)
+    }
+
+    /// Every [Client], to be locked one at a time by the caller.
+    fn all_clients(&self) -> Result<Vec<ClientHandle>, anyhow::Error> {
+        let mut handles = Vec::new();
+        for shard in self.clients.iter() {
+            handles.extend(guard_read(shard, "clients")?.values().cloned());
+        }
+        Ok(handles)
+    }
+
+    fn insert_client(
+        &self,
+        client_index: &mut ClientIndex,
+        client: Client,
+        main_account: Account,
+    ) -> Result<(), anyhow::Error> {
+        client_index.insert(&client);
+        guard_write(self.clients.shard(client.id()), "clients")?.insert(
+            client.id().clone(),
+            Arc::new(Mutex::new(ClientState::new(client, main_account))),
+        );
+        Ok(())
+    }
+
+    fn transaction_owner(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> Result<Option<ClientId>, anyhow::Error> {
+        Ok(guard_read(
+            self.transaction_owners.shard(transaction_id),
+            "transactions",
+        )?
+        .get(transaction_id)
+        .cloned())
+    }
+
+    fn authorization_owner(
+        &self,
+        authorization_id: &AuthorizationId,
+    ) -> Result<ClientId, ClientError> {
+        guard_read(
+            self.authorization_owners.shard(authorization_id),
+            "authorizations",
+        )?
+        .get(authorization_id)
+        .cloned()
+        .ok_or(ClientError::AuthorizationNotFound {
+            authorization_id: authorization_id.clone(),
+        })
+    }
+
+    /// Keeps the owner of a new [Transaction] or [Authorization], to find it by id.
+    fn index_owner(&self, entry: &LogEntry) -> Result<(), anyhow::Error> {
+        match entry {
+            LogEntry::TransactionAppended(transaction) => {
+                guard_write(
+                    self.transaction_owners.shard(transaction.id()),
+                    "transactions",
+                )?
+                .insert(transaction.id().clone(), transaction.client_id().clone());
+            }
+            LogEntry::AuthorizationSaved(authorization) => {
+                guard_write(
+                    self.authorization_owners.shard(authorization.id()),
+                    "authorizations",
+                )?
+                .insert(
+                    authorization.id().clone(),
+                    authorization.client_id().clone(),
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Appends the entries of a mutation to the [WriteAheadLog], if there is one, and only then
+    /// applies them to the clients, which the caller keeps locked. The log is only locked to
+    /// append, so mutations of different clients only wait for each other while writing to it.
+    /// New clients and idempotency records are left to the caller, which holds the locks they
+    /// need.
+    fn commit(
+        &self,
+        states: &mut [&mut ClientState],
+        entries: Vec<LogEntry>,
+    ) -> Result<(), ClientError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        if let Some(write_ahead_log) = &self.write_ahead_log {
+            guard(write_ahead_log, "write-ahead log")?.append(&entries)?;
+        }
+        for entry in entries {
+            if matches!(
+                entry,
+                LogEntry::ClientCreated { .. } | LogEntry::IdempotencyRecorded(_)
+            ) {
+                continue;
+            }
+            self.index_owner(&entry)?;
+            states
+                .iter_mut()
+                .find(|state| state.client.id() == entry.client_id())
+                .expect("the mutation locked every client it changed")
+                .apply(entry)?;
+        }
+        Ok(())
+    }
+
+    /// Applies a change read from the [WriteAheadLog] on startup.
+    fn replay(&self, entry: LogEntry) -> Result<(), ClientError> {
+        match entry {
+            LogEntry::ClientCreated {
+                client,
+                main_account,
+            } => {
+                let mut client_index = guard(&self.client_index, "client index")?;
+                self.insert_client(&mut client_index, client, main_account)?;
+            }
+            LogEntry::IdempotencyRecorded(record) => {
+                guard(
+                    self.idempotency_records.shard(record.key()),
+                    "idempotency records",
+                )?
+                .insert(record);
+            }
+            entry => {
+                let handle = self.client(entry.client_id())?;
+                let mut state = guard(&handle, "client")?;
+                if let LogEntry::ClientSaved(client) = &entry {
+                    let mut client_index = guard(&self.client_index, "client index")?;
+                    client_index.remove(&state.client);
+                    client_index.insert(client);
+                }
+                self.index_owner(&entry)?;
+                state.apply(entry)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The fewest entries that rebuild the whole state, for a snapshot of the [WriteAheadLog].
+    fn snapshot_entries(&self) -> Result<Vec<LogEntry>, anyhow::Error> {
+        let mut entries = Vec::new();
+        for handle in self.all_clients()? {
+            entries.extend(guard(&handle, "client")?.snapshot_entries());
+        }
+        for shard in self.idempotency_records.iter() {
+            let idempotency_records = guard(shard, "idempotency records")?;
+            entries.extend(
+                idempotency_records
+                    .keys_by_age
+                    .iter()
+                    .filter_map(|key| idempotency_records.records.get(key))
+                    .cloned()
+                    .map(LogEntry::IdempotencyRecorded),
+            );
+        }
+        Ok(entries)
+    }
+
+    /// Runs a mutation, which appends its entries with [Self::commit], and takes a snapshot of the
+    /// [WriteAheadLog] once one is due. The snapshot only compacts what the log already has, so
+    /// failing to take it is retried after the next mutation rather than failing this one.
+    fn logged<T>(
+        &self,
+        mutation: impl FnOnce() -> Result<T, ClientError>,
+    ) -> Result<T, ClientError> {
+        let Some(write_ahead_log) = &self.write_ahead_log else {
+            return mutation();
+        };
+        let result = {
+            let _snapshot_gate = guard_read(&self.snapshot_gate, "snapshot gate")?;
+            mutation()
+        };
+        if guard(write_ahead_log, "write-ahead log")?.is_snapshot_due()
+            && let Err(e) = self.snapshot(write_ahead_log)
+        {
+            tracing::error!("Error taking a snapshot of the write-ahead log: {e:#}");
+        }
+        result
+    }
+
+    /// Takes a snapshot once every mutation in progress is applied, and before any other starts.
+    fn snapshot(&self, write_ahead_log: &Mutex<WriteAheadLog>) -> Result<(), anyhow::Error> {
+        let _snapshot_gate = guard_write(&self.snapshot_gate, "snapshot gate")?;
+        // Another mutation may have taken it while this one waited for the gate.
+        if !guard(write_ahead_log, "write-ahead log")?.is_snapshot_due() {
+            return Ok(());
+        }
+        let entries = self.snapshot_entries()?;
+        guard(write_ahead_log, "write-ahead log")?.snapshot(&entries)
+    }
+
+    fn update_balance(
+        &self,
+        client_id: &ClientId,
+        amount: &Decimal,
+        currency: &Currency,
+        kind: TransactionKind,
+        idempotency_key: Option<&IdempotencyKey>,
+        origin: TransactionOrigin,
+    ) -> Result<Balance, ClientError> {
+        // Retries are matched against the amount as it was requested, so a change of the exchange
+        // rate between two retries does not turn them into a conflict.
+        let (requested_amount, requested_currency) = match origin.conversion {
+            Some(conversion) => (conversion.original_amount(), conversion.original_currency()),
+            None => (amount, currency),
+        };
+        let handle = self.client(client_id)?;
+        let mut state = guard(&handle, "client")?;
+        let mut draft = Draft::new(&state);
+        let account_id = draft.account_id(origin.account_id)?;
+        // The shard of the key stays locked until its record is stored, so a retry running at the
+        // same time, even for another client, waits for it instead of applying the amount again.
+        let mut idempotency_records = idempotency_key
+            .map(|key| guard(self.idempotency_records.shard(key), "idempotency records"))
+            .transpose()?;
+        if let (Some(key), Some(idempotency_records)) = (idempotency_key, &mut idempotency_records)
+        {
+            idempotency_records.evict_expired(self.idempotency_window);
+            if let Some(record) = idempotency_records.records.get(key) {
+                if !record.matches(client_id, requested_amount, requested_currency, kind)
+                    || record.balance().account_id() != Some(&account_id)
+                {
+                    return Err(ClientError::IdempotencyKeyConflict { key: key.clone() });
+                }
+                return Ok(record.balance().clone());
+            }
+        }
+        // A retry of an applied request was answered above, even if that request moved the
+        // version it expected.
+        draft.client.ensure_version(origin.expected_version)?;
+        if let Some(transaction_id) = origin.reversal_of {
+            // Only the owner of a transaction can reverse it.
+            match self.transaction_owner(transaction_id)? {
+                Some(owner) if &owner != client_id => {
+                    return Err(ClientError::TransactionNotReversible {
+                        transaction_id: transaction_id.clone(),
+                    });
+                }
+                _ => state.ensure_reversible(transaction_id, &account_id, amount, currency)?,
+            }
+        }
+        let origin = TransactionOrigin {
+            account_id: Some(&account_id),
+            ..origin
+        };
+        let balance = self.apply_amount(&mut draft, amount, currency, kind, origin)?;
+        let record = idempotency_key.map(|key| {
+            IdempotencyRecord::new(
+                key.clone(),
+                client_id.clone(),
+                *requested_amount,
+                requested_currency.clone(),
+                kind,
+                balance.clone(),
+                Utc::now(),
+            )
+        });
+        if let Some(record) = &record {
+            draft.record(LogEntry::IdempotencyRecorded(record.clone()));
         }
+        let entries = draft.into_entries();
+        self.commit(&mut [&mut *state], entries)?;
+        if let (Some(record), Some(idempotency_records)) = (record, &mut idempotency_records) {
+            idempotency_records.insert(record);
+        }
+        Ok(balance)
     }
-    fn guard_clients(&self) -> Result<GuardMutexClients, anyhow::Error> {
-        match self.clients.lock() {
-            Ok(lock) => Ok(lock),
-            Err(e) => Err(anyhow::anyhow!("Poisoned lock on clients: {}", e)),
+
+    /// Records in the draft the amount applied to the balance of an [Account] of the [Client] and
+    /// its [Transaction] appended to the ledger. Debits of the main [Account] can only spend the
+    /// balance not held by pending authorizations. The caller must hold the lock of the client.
+    fn apply_amount(
+        &self,
+        draft: &mut Draft,
+        amount: &Decimal,
+        currency: &Currency,
+        kind: TransactionKind,
+        origin: TransactionOrigin,
+    ) -> Result<Balance, ClientError> {
+        let now = Utc::now();
+        let amount = Money::new(*amount, currency.clone())?;
+        draft.client.ensure_operable()?;
+        let account_id = draft.account_id(origin.account_id)?;
+        let held = if account_id == draft.state.main_account_id {
+            draft.held_amount(currency, now)?
+        } else {
+            Money::zero(currency.clone())
+        };
+        let (account, currency_balances) = &draft.accounts[&account_id];
+        let balance = ClientState::balance_of(currency_balances, currency);
+        account.ensure_funds_for(&draft.client, &balance.checked_sub(&held)?, &amount)?;
+        let new_balance = balance.checked_add(&amount)?;
+        let mut client = draft.client.clone();
+        client.increase_version();
+        let version = *client.version();
+        let client_id = client.id();
+        let mut transaction = Transaction::new(
+            self.next_transaction_id()?,
+            client_id.clone(),
+            *amount.amount(),
+            kind,
+            now,
+            *new_balance.amount(),
+        )
+        .with_currency(currency.clone())
+        .with_account_id(account_id.clone());
+        if let Some(transaction_id) = origin.reversal_of {
+            transaction = transaction.with_reversal_of(transaction_id.clone());
+        }
+        let mut balance = Balance::new(client_id.clone(), *new_balance.amount())
+            .with_account_id(account_id.clone())
+            .with_held(*held.amount())
+            .with_currency(currency.clone())
+            .with_version(version);
+        if let Some(conversion) = origin.conversion {
+            transaction = transaction.with_conversion(conversion.clone());
+            balance = balance.with_conversion(conversion.clone());
         }
+        draft.record(LogEntry::BalanceSet {
+            client_id: client_id.clone(),
+            account_id: account_id.clone(),
+            balance: new_balance,
+        });
+        draft.record(LogEntry::TransactionAppended(transaction));
+        draft.record(LogEntry::ClientSaved(client));
+        Ok(balance)
+    }
+
+    /// Returns the [TransactionKind::Adjustment] that records a balance set from `old_balance` to
+    /// `new_balance` without a movement, or nothing if the balance does not change.
+    fn adjustment(
+        &self,
+        client_id: &ClientId,
+        account_id: &AccountId,
+        old_balance: &Money,
+        new_balance: &Money,
+        now: DateTime<Utc>,
+    ) -> Result<Option<LogEntry>, ClientError> {
+        let amount = new_balance.checked_sub(old_balance)?;
+        if amount.amount().is_zero() {
+            return Ok(None);
+        }
+        let transaction = Transaction::new(
+            self.next_transaction_id()?,
+            client_id.clone(),
+            *amount.amount(),
+            TransactionKind::Adjustment,
+            now,
+            *new_balance.amount(),
+        )
+        .with_currency(new_balance.currency().clone())
+        .with_account_id(account_id.clone());
+        Ok(Some(LogEntry::TransactionAppended(transaction)))
+    }
+
+    fn next_transaction_id(&self) -> Result<TransactionId, ClientError> {
+        TransactionId::new(
+            &self
+                .transaction_id_counter
+                .fetch_add(1, Ordering::Relaxed)
+                .to_string(),
+        )
     }
-    fn update_balance(
-        &self,
-        client_id: &ClientId,
-        amount: &Decimal,
-    ) -> Result<Balance, ClientError> {
-        let mut clients = self.guard_clients()?;
-        let client_balance = clients
-            .get_mut(client_id)
-            .ok_or(ClientError::NotFoundById {
-                id_document: client_id.clone(),
-            })?;
-        let new_decimal_balance = client_balance.1 + amount;
-        client_balance.1 = new_decimal_balance;
-        Ok(Balance::new(client_id.clone(), new_decimal_balance))
+
+    fn next_account_id(&self) -> Result<AccountId, ClientError> {
+        AccountId::new(
+            &self
+                .account_id_counter
+                .fetch_add(1, Ordering::Relaxed)
+                .to_string(),
+        )
     }
 
     fn _create_client(&self, req: &CreateClientRequest) -> Result<Client, ClientError> {
-        let id = ClientId::new(&self.id_counter.fetch_add(1, Ordering::Relaxed).to_string())?;
+        // The index stays locked until the client is inserted, so two clients with the same
+        // document or the same id are never created at the same time.
+        let mut client_index = guard(&self.client_index, "client index")?;
+        client_index.ensure_document_available(req.document(), None)?;
+        if self.unique_contacts {
+            client_index.ensure_contacts_available(req.email(), req.phone(), None)?;
+        }
+        // The id is only minted once the client is known to be valid, so a rejected client does
+        // not leave a gap in sequential ids. Ids already taken are skipped, as the sequential
+        // generator starts over after replaying the write-ahead log.
+        let mut id = self.id_generator.next_client_id()?;
+        while self._client_id_exists(&id)? {
+            id = self.id_generator.next_client_id()?;
+        }
         let client = Client::new(
             id.clone(),
             req.name().clone(),
             req.birth_date().clone(),
             req.document().clone(),
             req.country().clone(),
+        )
+        .with_overdraft_limit(req.overdraft_limit().clone())
+        .with_email(req.email().cloned())
+        .with_phone(req.phone().cloned());
+        let main_account = Account::main(self.next_account_id()?, id);
+        self.commit(
+            &mut [],
+            vec![LogEntry::ClientCreated {
+                client: client.clone(),
+                main_account: main_account.clone(),
+            }],
+        )?;
+        self.insert_client(&mut client_index, client.clone(), main_account)?;
+        Ok(client)
+    }
+
+    fn _create_account(&self, req: &CreateAccountRequest) -> Result<Account, ClientError> {
+        let handle = self.client(req.client_id())?;
+        let mut state = guard(&handle, "client")?;
+        state.client.ensure_version(req.expected_version())?;
+        state.client.ensure_operable()?;
+        let account = Account::new(
+            self.next_account_id()?,
+            req.client_id().clone(),
+            req.name().clone(),
         );
-        let mut clients = self.guard_clients()?;
-        if clients
-            .iter()
-            .any(|(_, (client, _))| client.document() == req.document())
-        {
-            return Err(ClientError::Duplicate {
-                document: req.document().to_string(),
+        let mut client = state.client.clone();
+        client.increase_version();
+        self.commit(
+            &mut [&mut *state],
+            vec![
+                LogEntry::AccountOpened(account.clone()),
+                LogEntry::ClientSaved(client),
+            ],
+        )?;
+        Ok(account)
+    }
+
+    /// Every client is read under its own lock, so the page is not a snapshot taken at a single
+    /// moment: a client updated while listing may show either its old or its new balance.
+    fn _list_clients(&self, req: &ListClientsRequest) -> Result<ClientPage, ClientError> {
+        let now = Utc::now();
+        let name_contains = req
+            .name_contains()
+            .map(|name| name.to_string().to_lowercase());
+        let mut rows: Vec<(Client, Money, Money)> = Vec::new();
+        for handle in self.all_clients()? {
+            let state = guard(&handle, "client")?;
+            let client = &state.client;
+            let listed = req
+                .country()
+                .is_none_or(|country| client.country() == country)
+                && req
+                    .document_prefix()
+                    .is_none_or(|prefix| client.document().number().starts_with(prefix))
+                && name_contains.as_ref().is_none_or(|name| {
+                    client
+                        .name()
+                        .to_string()
+                        .to_lowercase()
+                        .contains(name.as_str())
+                });
+            if listed {
+                rows.push((
+                    client.clone(),
+                    state.total_balance(req.currency())?,
+                    state.held_amount(req.currency(), now)?,
+                ));
+            }
+        }
+        rows.retain(|(_, balance, _)| req.balance_range().contains(balance.amount()));
+
+        // The id breaks the ties, so every client has a unique position and the cursor never skips
+        // or repeats one.
+        let sort_key = |(client, balance, _): &(Client, Money, Money)| {
+            let balance = (req.sort() == ClientSort::Balance).then(|| *balance.amount());
+            (balance, client.id().clone())
+        };
+        rows.sort_by_key(sort_key);
+        if req.direction() == SortDirection::Descending {
+            rows.reverse();
+        }
+        if let Some(cursor) = req.cursor() {
+            let cursor_key = (cursor.balance().copied(), cursor.client_id().clone());
+            rows.retain(|row| match req.direction() {
+                SortDirection::Ascending => sort_key(row) > cursor_key,
+                SortDirection::Descending => sort_key(row) < cursor_key,
+            });
+        }
+
+        let next_cursor = (rows.len() > req.limit()).then(|| {
+            let (client, balance, _) = &rows[req.limit() - 1];
+            match req.sort() {
+                ClientSort::Id => ClientCursor::after_id(client.id().clone()),
+                ClientSort::Balance => {
+                    ClientCursor::after_balance(*balance.amount(), client.id().clone())
+                }
+            }
+        });
+        let page = rows
+            .into_iter()
+            .take(req.limit())
+            .map(|(client, balance, held)| {
+                let balance = Balance::new(client.id().clone(), *balance.amount())
+                    .with_held(*held.amount())
+                    .with_currency(req.currency().clone())
+                    .with_version(*client.version());
+                (client, balance)
+            })
+            .collect();
+        Ok(ClientPage::new(page, next_cursor))
+    }
+
+    fn _update_client(&self, req: &UpdateClientRequest) -> Result<Client, ClientError> {
+        let handle = self.client(req.client_id())?;
+        let mut state = guard(&handle, "client")?;
+        state.client.ensure_version(req.expected_version())?;
+        let mut client_index = guard(&self.client_index, "client index")?;
+        if let Some(document) = req.document() {
+            client_index.ensure_document_available(document, Some(req.client_id()))?;
+        }
+        if self.unique_contacts {
+            client_index.ensure_contacts_available(
+                req.email().flatten(),
+                req.phone().flatten(),
+                Some(req.client_id()),
+            )?;
+        }
+        if state.client.status() == ClientStatus::Closed {
+            return Err(ClientError::ClientClosed {
+                client_id: req.client_id().clone(),
             });
         }
-        clients.insert(id, (client.clone(), Decimal::from(0)));
+        let previous_client = state.client.clone();
+        let mut client = previous_client.clone();
+        let changes = client.update(req, Utc::now());
+        if changes.is_empty() {
+            return Ok(client);
+        }
+        client.increase_version();
+        let mut entries = vec![LogEntry::ClientSaved(client.clone())];
+        entries.extend(changes.into_iter().map(LogEntry::ClientChanged));
+        self.commit(&mut [&mut *state], entries)?;
+        client_index.remove(&previous_client);
+        client_index.insert(&client);
         Ok(client)
     }
 
+    fn _get_client_changes(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<ClientChange>, ClientError> {
+        let handle = self.client(req.client_id())?;
+        Ok(guard(&handle, "client")?.changes.clone())
+    }
+
     fn _client_id_exists(&self, client_id: &ClientId) -> Result<bool, ClientError> {
-        let clients = self.guard_clients()?;
-        Ok(clients.contains_key(client_id))
+        Ok(guard_read(self.clients.shard(client_id), "clients")?.contains_key(client_id))
     }
 
     fn _get_client_by_document(&self, document: &Document) -> Result<Client, ClientError> {
-        let clients = self.guard_clients()?;
-        let (_, (client, _)) = clients
-            .iter()
-            .find(|(_, (client, _))| client.document() == document)
-            .ok_or(ClientError::NotFoundByDocument {
+        let client_id = guard(&self.client_index, "client index")?.client_id(document)?;
+        let handle = self.client(&client_id)?;
+        let client = guard(&handle, "client")?.client.clone();
+        // The document may have changed since the index was read.
+        if client.document() != document {
+            return Err(ClientError::NotFoundByDocument {
                 document: document.clone(),
-            })?;
-        Ok(client.clone())
+            });
+        }
+        Ok(client)
     }
 
     fn _credit_balance(&self, req: &CreditTransactionRequest) -> Result<Balance, ClientError> {
-        self.update_balance(req.client_id(), req.amount())
+        self.update_balance(
+            req.client_id(),
+            req.applied_amount(),
+            req.applied_currency(),
+            TransactionKind::Credit,
+            req.idempotency_key(),
+            TransactionOrigin {
+                account_id: req.account_id(),
+                reversal_of: req.reversal_of(),
+                conversion: req.conversion(),
+                expected_version: req.expected_version(),
+            },
+        )
     }
 
     fn _get_client(&self, req: &GetClientRequest) -> Result<Client, ClientError> {
-        let clients = self.guard_clients()?;
-        let (client, _) = clients
-            .get(req.client_id())
-            .ok_or(ClientError::NotFoundById {
-                id_document: req.client_id().clone(),
-            })?;
-        Ok(client.clone())
+        let handle = self.client(req.client_id())?;
+        Ok(guard(&handle, "client")?.client.clone())
     }
 
     fn _debit_balance(&self, req: &DebitTransactionRequest) -> Result<Balance, ClientError> {
-        self.update_balance(req.client_id(), req.amount())
+        self.update_balance(
+            req.client_id(),
+            req.applied_amount(),
+            req.applied_currency(),
+            TransactionKind::Debit,
+            req.idempotency_key(),
+            TransactionOrigin {
+                account_id: req.account_id(),
+                reversal_of: req.reversal_of(),
+                conversion: req.conversion(),
+                expected_version: req.expected_version(),
+            },
+        )
     }
 
-    fn _get_balance_by_client_id(&self, req: &GetClientRequest) -> Result<Balance, ClientError> {
-        let client_balances = self.guard_clients()?;
-        let (client, balance) =
-            client_balances
-                .get(req.client_id())
+    fn _transfer(&self, req: &TransferRequest) -> Result<(Balance, Balance), ClientError> {
+        let handles = self.clients_by_id([req.from_client_id(), req.to_client_id()])?;
+        let mut states = guard_clients(&handles)?;
+        // Both clients are checked before computing any balance, so a missing or frozen one is
+        // reported before the funds of the origin.
+        for client_id in [req.from_client_id(), req.to_client_id()] {
+            states
+                .get(client_id)
                 .ok_or(ClientError::NotFoundById {
-                    id_document: req.client_id().clone(),
+                    id_document: client_id.clone(),
+                })?
+                .client
+                .ensure_operable()?;
+        }
+        // Both legs are computed before applying either, so a failing credit never leaves the
+        // debit applied.
+        let mut drafts = drafts(&states);
+        let from_balance = self.apply_amount(
+            draft_of(&mut drafts, req.from_client_id())?,
+            &-req.amount(),
+            req.currency(),
+            TransactionKind::TransferOut,
+            TransactionOrigin::default(),
+        )?;
+        let to_balance = self.apply_amount(
+            draft_of(&mut drafts, req.to_client_id())?,
+            req.amount(),
+            req.currency(),
+            TransactionKind::TransferIn,
+            TransactionOrigin::default(),
+        )?;
+        let entries = drafts.into_values().flat_map(Draft::into_entries).collect();
+        self.commit(&mut locked_states(&mut states), entries)?;
+        Ok((from_balance, to_balance))
+    }
+
+    fn _apply_batch(&self, req: &BatchTransactionRequest) -> Result<Vec<Balance>, ClientError> {
+        let handles = self.clients_by_id(req.items().iter().map(|item| item.client_id()))?;
+        let mut states = guard_clients(&handles)?;
+        // Every item is computed against the balances left by the previous ones, and nothing is
+        // applied unless the whole batch succeeds.
+        let mut drafts = drafts(&states);
+        let mut balances = Vec::with_capacity(req.items().len());
+        for (index, item) in req.items().iter().enumerate() {
+            let balance = draft_of(&mut drafts, item.client_id())
+                .and_then(|draft| {
+                    self.apply_amount(
+                        draft,
+                        item.amount(),
+                        item.currency(),
+                        item.kind(),
+                        TransactionOrigin::default(),
+                    )
+                })
+                .map_err(|reason| ClientError::BatchItemRejected {
+                    index,
+                    reason: Box::new(reason),
                 })?;
-        Ok(Balance::new(client.id().clone(), *balance))
+            balances.push(balance);
+        }
+        let entries = drafts.into_values().flat_map(Draft::into_entries).collect();
+        self.commit(&mut locked_states(&mut states), entries)?;
+        Ok(balances)
     }
 
-    fn _reset_all_balances_to_zero(&self) -> Result<Vec<Balance>, ClientError> {
-        let mut clients = self.guard_clients()?;
-        let old_balances = clients
-            .values_mut()
-            .map(|(client, balance)| {
-                let old_balance = *balance;
-                *balance = Decimal::from(0);
-                Balance::new(client.id().clone(), old_balance)
-            })
-            .collect();
-        Ok(old_balances)
+    fn _authorize(&self, req: &AuthorizeRequest) -> Result<Authorization, ClientError> {
+        let handle = self.client(req.client_id())?;
+        let mut state = guard(&handle, "client")?;
+        let now = Utc::now();
+        let amount = Money::new(-req.amount(), req.currency().clone())?;
+        let held = state.held_amount(req.currency(), now)?;
+        let client = &state.client;
+        client.ensure_version(req.expected_version())?;
+        client.ensure_operable()?;
+        let balance = ClientState::balance_of(state.main_balances(), req.currency());
+        client.ensure_funds_for(&balance.checked_sub(&held)?, &amount)?;
+        let id = AuthorizationId::new(
+            &self
+                .authorization_id_counter
+                .fetch_add(1, Ordering::Relaxed)
+                .to_string(),
+        )?;
+        let authorization = Authorization::new(
+            id,
+            req.client_id().clone(),
+            *req.amount(),
+            now,
+            now + self.authorization_timeout,
+        )
+        .with_currency(req.currency().clone());
+        let mut client = client.clone();
+        client.increase_version();
+        let version = *client.version();
+        self.commit(
+            &mut [&mut *state],
+            vec![
+                LogEntry::AuthorizationSaved(authorization.clone()),
+                LogEntry::ClientSaved(client),
+            ],
+        )?;
+        Ok(authorization.with_version(version))
+    }
+
+    fn _capture_authorization(
+        &self,
+        req: &CaptureAuthorizationRequest,
+    ) -> Result<Balance, ClientError> {
+        let handle = self.client(&self.authorization_owner(req.authorization_id())?)?;
+        let mut state = guard(&handle, "client")?;
+        state.client.ensure_version(req.expected_version())?;
+        let mut draft = Draft::new(&state);
+        let mut authorization = draft.authorization(req.authorization_id())?;
+        let amount = req.amount().copied().unwrap_or(*authorization.amount());
+        authorization.capture(amount, Utc::now())?;
+        let currency = authorization.currency().clone();
+        // The hold is released before debiting, so the debit can spend the funds it reserved.
+        draft.record(LogEntry::AuthorizationSaved(authorization));
+        let balance = self.apply_amount(
+            &mut draft,
+            &-amount,
+            &currency,
+            TransactionKind::Debit,
+            TransactionOrigin::default(),
+        )?;
+        let entries = draft.into_entries();
+        self.commit(&mut [&mut *state], entries)?;
+        Ok(balance)
+    }
+
+    fn _void_authorization(
+        &self,
+        authorization_id: &AuthorizationId,
+        expected_version: Option<Version>,
+    ) -> Result<Authorization, ClientError> {
+        let handle = self.client(&self.authorization_owner(authorization_id)?)?;
+        let mut state = guard(&handle, "client")?;
+        state.client.ensure_version(expected_version.as_ref())?;
+        let mut authorization = state.authorizations.get(authorization_id).cloned().ok_or(
+            ClientError::AuthorizationNotFound {
+                authorization_id: authorization_id.clone(),
+            },
+        )?;
+        authorization.void(Utc::now())?;
+        let mut client = state.client.clone();
+        client.increase_version();
+        let version = *client.version();
+        self.commit(
+            &mut [&mut *state],
+            vec![
+                LogEntry::AuthorizationSaved(authorization.clone()),
+                LogEntry::ClientSaved(client),
+            ],
+        )?;
+        Ok(authorization.with_version(version))
+    }
+
+    fn _get_balance_by_client_id(&self, req: &GetClientRequest) -> Result<Balance, ClientError> {
+        let handle = self.client(req.client_id())?;
+        let state = guard(&handle, "client")?;
+        let balance = state.total_balance(req.currency())?;
+        let held = state.held_amount(req.currency(), Utc::now())?;
+        Ok(Balance::new(req.client_id().clone(), *balance.amount())
+            .with_held(*held.amount())
+            .with_currency(req.currency().clone())
+            .with_version(*state.client.version()))
+    }
+
+    fn _get_balances_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<Balance>, ClientError> {
+        let handle = self.client(req.client_id())?;
+        guard(&handle, "client")?.balances(Utc::now())
+    }
+
+    fn _get_accounts_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<(Account, Vec<Balance>)>, ClientError> {
+        let handle = self.client(req.client_id())?;
+        guard(&handle, "client")?.account_balances(Utc::now())
+    }
+
+    fn _get_client_overview(&self, req: &GetClientRequest) -> Result<ClientOverview, ClientError> {
+        let handle = self.client(req.client_id())?;
+        let state = guard(&handle, "client")?;
+        let now = Utc::now();
+        Ok(ClientOverview::new(
+            state.client.clone(),
+            state.balances(now)?,
+            state.account_balances(now)?,
+        ))
+    }
+
+    fn _get_transactions_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<Transaction>, ClientError> {
+        let handle = self.client(req.client_id())?;
+        Ok(guard(&handle, "client")?.transactions.clone())
+    }
+
+    fn _get_transaction(&self, transaction_id: &TransactionId) -> Result<Transaction, ClientError> {
+        let client_id =
+            self.transaction_owner(transaction_id)?
+                .ok_or(ClientError::TransactionNotFound {
+                    transaction_id: transaction_id.clone(),
+                })?;
+        let handle = self.client(&client_id)?;
+        Ok(guard(&handle, "client")?
+            .find_transaction(transaction_id)?
+            .clone())
+    }
+
+    fn _change_client_status(
+        &self,
+        client_id: &ClientId,
+        status: ClientStatus,
+        expected_version: Option<Version>,
+    ) -> Result<Client, ClientError> {
+        let handle = self.client(client_id)?;
+        let mut state = guard(&handle, "client")?;
+        let now = Utc::now();
+        let mut client = state.client.clone();
+        client.ensure_version(expected_version.as_ref())?;
+        client.change_status(status)?;
+        if status == ClientStatus::Closed {
+            let has_balance = state.accounts.values().any(|(_, currency_balances)| {
+                currency_balances
+                    .values()
+                    .any(|balance| !balance.amount().is_zero())
+            });
+            let has_holds = state
+                .authorizations
+                .values()
+                .any(|authorization| authorization.is_holding(now));
+            if has_balance || has_holds {
+                return Err(ClientError::BalanceNotZero {
+                    client_id: client_id.clone(),
+                });
+            }
+        }
+        client.increase_version();
+        self.commit(
+            &mut [&mut *state],
+            vec![LogEntry::ClientSaved(client.clone())],
+        )?;
+        Ok(client)
+    }
+
+    /// Every client is reset under its own lock, so an update applied meanwhile is either part of
+    /// the returned balance or applied after the reset, never lost.
+    fn _reset_all_balances_to_zero(&self) -> Result<BalanceReset, ClientError> {
+        let now = Utc::now();
+        let mut old_balances = Vec::new();
+        let mut voided_authorizations = Vec::new();
+        for handle in self.all_clients()? {
+            let mut state = guard(&handle, "client")?;
+            if state.client.status() == ClientStatus::Closed {
+                continue;
+            }
+            let mut client = state.client.clone();
+            client.increase_version();
+            let mut entries = Vec::new();
+            for (account_id, (_, currency_balances)) in &state.accounts {
+                for (currency, balance) in currency_balances {
+                    let zero = Money::zero(currency.clone());
+                    entries.extend(self.adjustment(
+                        client.id(),
+                        account_id,
+                        balance,
+                        &zero,
+                        now,
+                    )?);
+                    entries.push(LogEntry::BalanceSet {
+                        client_id: client.id().clone(),
+                        account_id: account_id.clone(),
+                        balance: zero,
+                    });
+                    old_balances.push(
+                        Balance::new(client.id().clone(), *balance.amount())
+                            .with_account_id(account_id.clone())
+                            .with_currency(currency.clone()),
+                    );
+                }
+            }
+            // The funds its pending authorizations reserve are gone, so they are released too.
+            for authorization in state.authorizations.values() {
+                if authorization.is_holding(now) {
+                    let mut authorization = authorization.clone();
+                    authorization.void(now)?;
+                    voided_authorizations.push(authorization.id().clone());
+                    entries.push(LogEntry::AuthorizationSaved(authorization));
+                }
+            }
+            entries.push(LogEntry::ClientSaved(client));
+            self.commit(&mut [&mut *state], entries)?;
+        }
+        Ok(BalanceReset::new(old_balances, voided_authorizations))
     }
 
     fn _are_balances_empty(&self) -> Result<bool, ClientError> {
-        let clients = self.guard_clients()?;
-        Ok(clients.is_empty())
-    }
-
-    fn _merge_old_balances(&self, old_client_balances: Vec<Balance>) -> Result<(), ClientError> {
-        let mut clients = self.guard_clients()?;
-        old_client_balances.iter().for_each(|old_client_balance| {
-            let old_balance = old_client_balance.balance();
-            if let Some((_, balance)) = clients.get_mut(old_client_balance.client_id()) {
-                let new_balance = *old_balance + *balance;
-                *balance = new_balance;
-            } else {
+        for handle in self.all_clients()? {
+            if guard(&handle, "client")?.client.status() != ClientStatus::Closed {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn _merge_old_balances(&self, reset: BalanceReset) -> Result<(), ClientError> {
+        let voided_authorizations = reset
+            .voided_authorizations()
+            .iter()
+            .map(|authorization_id| {
+                Ok((
+                    self.authorization_owner(authorization_id)?,
+                    authorization_id,
+                ))
+            })
+            .collect::<Result<Vec<_>, ClientError>>()?;
+        let handles = self.clients_by_id(
+            reset
+                .old_balances()
+                .iter()
+                .map(Balance::client_id)
+                .chain(voided_authorizations.iter().map(|(owner, _)| owner)),
+        )?;
+        let mut states = guard_clients(&handles)?;
+        let now = Utc::now();
+        // Every sum is computed before applying any of them, so an overflow leaves the balances
+        // untouched.
+        let mut drafts = drafts(&states);
+        for old_client_balance in reset.old_balances() {
+            let Ok(draft) = draft_of(&mut drafts, old_client_balance.client_id()) else {
                 tracing::warn!(
                     "client not found by id {} and balance of this client will be ignored...",
                     old_client_balance.client_id()
                 );
+                continue;
+            };
+            let Ok(account_id) = draft.account_id(old_client_balance.account_id()) else {
+                tracing::warn!(
+                    "account not found for client {} and balance of this account will be ignored...",
+                    old_client_balance.client_id()
+                );
+                continue;
+            };
+            let old_balance = Money::new(
+                *old_client_balance.balance(),
+                old_client_balance.currency().clone(),
+            )?;
+            let (_, currency_balances) = &draft.accounts[&account_id];
+            let current_balance =
+                ClientState::balance_of(currency_balances, old_client_balance.currency());
+            let balance = current_balance.checked_add(&old_balance)?;
+            let mut client = draft.client.clone();
+            client.increase_version();
+            if let Some(adjustment) = self.adjustment(
+                old_client_balance.client_id(),
+                &account_id,
+                &current_balance,
+                &balance,
+                now,
+            )? {
+                draft.record(adjustment);
             }
-        });
-        Ok(())
+            draft.record(LogEntry::BalanceSet {
+                client_id: old_client_balance.client_id().clone(),
+                account_id,
+                balance,
+            });
+            draft.record(LogEntry::ClientSaved(client));
+        }
+        // The holds are reinstated along with the funds they reserve, so they can still be
+        // captured or voided. Those that expired meanwhile are expired as usual.
+        for (owner, authorization_id) in voided_authorizations {
+            let draft = draft_of(&mut drafts, &owner)?;
+            let authorization = draft.authorization(authorization_id)?;
+            if authorization.status() == &AuthorizationStatus::Voided {
+                draft.record(LogEntry::AuthorizationSaved(
+                    authorization.with_status(AuthorizationStatus::Pending, None),
+                ));
+            }
+        }
+        let entries = drafts.into_values().flat_map(Draft::into_entries).collect();
+        self.commit(&mut locked_states(&mut states), entries)
     }
 }
 
 impl ClientBalanceRepository for InMemoryRepository {
     async fn create_client(&self, req: &CreateClientRequest) -> Result<Client, ClientError> {
-        self._create_client(req)
+        self.logged(|| self._create_client(req))
+    }
+
+    async fn create_account(&self, req: &CreateAccountRequest) -> Result<Account, ClientError> {
+        self.logged(|| self._create_account(req))
+    }
+
+    async fn list_clients(&self, req: &ListClientsRequest) -> Result<ClientPage, ClientError> {
+        self._list_clients(req)
+    }
+
+    async fn update_client(&self, req: &UpdateClientRequest) -> Result<Client, ClientError> {
+        self.logged(|| self._update_client(req))
+    }
+
+    async fn get_client_changes(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<ClientChange>, ClientError> {
+        self._get_client_changes(req)
     }
 
     async fn client_id_exists(&self, client_id: &ClientId) -> Result<bool, ClientError> {
@@ -181,7 +1797,7 @@ impl ClientBalanceRepository for InMemoryRepository {
     }
 
     async fn credit_balance(&self, req: &CreditTransactionRequest) -> Result<Balance, ClientError> {
-        self._credit_balance(req)
+        self.logged(|| self._credit_balance(req))
     }
 
     async fn get_client(&self, req: &GetClientRequest) -> Result<Client, ClientError> {
@@ -189,7 +1805,37 @@ impl ClientBalanceRepository for InMemoryRepository {
     }
 
     async fn debit_balance(&self, req: &DebitTransactionRequest) -> Result<Balance, ClientError> {
-        self._debit_balance(req)
+        self.logged(|| self._debit_balance(req))
+    }
+
+    async fn transfer(&self, req: &TransferRequest) -> Result<(Balance, Balance), ClientError> {
+        self.logged(|| self._transfer(req))
+    }
+
+    async fn apply_batch(
+        &self,
+        req: &BatchTransactionRequest,
+    ) -> Result<Vec<Balance>, ClientError> {
+        self.logged(|| self._apply_batch(req))
+    }
+
+    async fn authorize(&self, req: &AuthorizeRequest) -> Result<Authorization, ClientError> {
+        self.logged(|| self._authorize(req))
+    }
+
+    async fn capture_authorization(
+        &self,
+        req: &CaptureAuthorizationRequest,
+    ) -> Result<Balance, ClientError> {
+        self.logged(|| self._capture_authorization(req))
+    }
+
+    async fn void_authorization(
+        &self,
+        authorization_id: &AuthorizationId,
+        expected_version: Option<Version>,
+    ) -> Result<Authorization, ClientError> {
+        self.logged(|| self._void_authorization(authorization_id, expected_version))
     }
 
     async fn get_balance_by_client_id(
@@ -199,18 +1845,600 @@ impl ClientBalanceRepository for InMemoryRepository {
         self._get_balance_by_client_id(req)
     }
 
-    async fn reset_all_balances_to_zero(&self) -> Result<Vec<Balance>, ClientError> {
-        self._reset_all_balances_to_zero()
+    async fn get_balances_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<Balance>, ClientError> {
+        self._get_balances_by_client_id(req)
+    }
+
+    async fn get_accounts_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<(Account, Vec<Balance>)>, ClientError> {
+        self._get_accounts_by_client_id(req)
+    }
+
+    async fn get_client_overview(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<ClientOverview, ClientError> {
+        self._get_client_overview(req)
+    }
+
+    async fn get_transactions_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<Transaction>, ClientError> {
+        self._get_transactions_by_client_id(req)
+    }
+
+    async fn get_transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> Result<Transaction, ClientError> {
+        self._get_transaction(transaction_id)
+    }
+
+    async fn change_client_status(
+        &self,
+        client_id: &ClientId,
+        status: ClientStatus,
+        expected_version: Option<Version>,
+    ) -> Result<Client, ClientError> {
+        self.logged(|| self._change_client_status(client_id, status, expected_version))
+    }
+
+    async fn reset_all_balances_to_zero(&self) -> Result<BalanceReset, ClientError> {
+        self.logged(|| self._reset_all_balances_to_zero())
     }
 
     async fn are_balances_empty(&self) -> Result<bool, ClientError> {
         self._are_balances_empty()
     }
 
-    async fn merge_old_balances(
-        &self,
-        old_client_balances: Vec<Balance>,
-    ) -> Result<(), ClientError> {
-        self._merge_old_balances(old_client_balances)
+    async fn merge_old_balances(&self, reset: BalanceReset) -> Result<(), ClientError> {
+        self.logged(|| self._merge_old_balances(reset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration as StdDuration;
+
+    use rust_decimal::dec;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::domain::model::dto::batch_transaction::BatchTransactionItem;
+    use crate::domain::model::value::{
+        account_name::AccountName, birth_date::BirthDate, client_field::ClientField,
+        client_name::ClientName, country::Country, document_type::DocumentType,
+    };
+    use crate::infrastructure::outbound::write_ahead_log::FsyncPolicy;
+
+    fn repository_with_log(directory: &TempDir, snapshot_every: u64) -> InMemoryRepository {
+        let write_ahead_log = WriteAheadLog::open(
+            directory.path(),
+            FsyncPolicy::Always,
+            StdDuration::from_secs(1),
+            snapshot_every,
+        )
+        .unwrap();
+        InMemoryRepository::new()
+            .with_write_ahead_log(write_ahead_log)
+            .unwrap()
+    }
+
+    fn create_client_request(document: &str) -> CreateClientRequest {
+        CreateClientRequest::new(
+            ClientName::new("John Doe").unwrap(),
+            BirthDate::new("1990-01-01").unwrap(),
+            Document::new(DocumentType::Dni, document).unwrap(),
+            Country::new("AR").unwrap(),
+        )
+    }
+
+    fn client_id(id: &str) -> ClientId {
+        ClientId::new(id).unwrap()
+    }
+
+    fn credit(client_id: &str, amount: Decimal) -> CreditTransactionRequest {
+        CreditTransactionRequest::new(self::client_id(client_id), amount).unwrap()
+    }
+
+    fn debit(client_id: &str, amount: Decimal) -> DebitTransactionRequest {
+        DebitTransactionRequest::new(self::client_id(client_id), amount).unwrap()
+    }
+
+    async fn balance(repository: &InMemoryRepository, client_id: &str) -> Balance {
+        repository
+            .get_balance_by_client_id(&GetClientRequest::new(self::client_id(client_id)))
+            .await
+            .unwrap()
+    }
+
+    fn log_lines(directory: &TempDir) -> usize {
+        std::fs::read_to_string(directory.path().join("wal.log"))
+            .unwrap()
+            .lines()
+            .count()
+    }
+
+    /// Creates a client with a second account, a credit retried with the same idempotency key and
+    /// a hold.
+    async fn populate(repository: &InMemoryRepository) {
+        repository
+            .create_client(&create_client_request("12345678"))
+            .await
+            .unwrap();
+        repository
+            .create_account(&CreateAccountRequest::new(
+                client_id("0"),
+                AccountName::new("Savings").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let credit =
+            credit("0", dec!(100)).with_idempotency_key(IdempotencyKey::new("key").unwrap());
+        repository.credit_balance(&credit).await.unwrap();
+        repository
+            .authorize(&AuthorizeRequest::new(client_id("0"), dec!(10)).unwrap())
+            .await
+            .unwrap();
+    }
+
+    async fn assert_restored(repository: &InMemoryRepository) {
+        let restored = balance(repository, "0").await;
+        assert_eq!(restored.balance(), &dec!(100));
+        assert_eq!(restored.held(), &dec!(10));
+        let client = repository
+            .get_client_by_document(&Document::new(DocumentType::Dni, "12345678").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(client.id(), &client_id("0"));
+        // The idempotency record is restored, so a retry is not applied again.
+        let retry =
+            credit("0", dec!(100)).with_idempotency_key(IdempotencyKey::new("key").unwrap());
+        assert_eq!(
+            repository.credit_balance(&retry).await.unwrap().balance(),
+            &dec!(100)
+        );
+
+        // The ids continue after the ones replayed.
+        let client = repository
+            .create_client(&create_client_request("87654321"))
+            .await
+            .unwrap();
+        assert_eq!(client.id(), &client_id("1"));
+        let account = repository
+            .create_account(&CreateAccountRequest::new(
+                client_id("1"),
+                AccountName::new("Savings").unwrap(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(account.id().to_string(), "3");
+        repository
+            .credit_balance(&credit("1", dec!(5)))
+            .await
+            .unwrap();
+        let transactions = repository
+            .get_transactions_by_client_id(&GetClientRequest::new(client_id("1")))
+            .await
+            .unwrap();
+        assert_eq!(transactions[0].id().to_string(), "1");
+        let authorization = repository
+            .authorize(&AuthorizeRequest::new(client_id("1"), dec!(1)).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(authorization.id().to_string(), "1");
+    }
+
+    #[tokio::test]
+    async fn test_01_given_a_write_ahead_log_when_reopening_the_repository_then_it_should_restore_the_state_and_the_counters()
+     {
+        let directory = TempDir::new().unwrap();
+        populate(&repository_with_log(&directory, 1000)).await;
+
+        assert_restored(&repository_with_log(&directory, 1000)).await;
+    }
+
+    #[tokio::test]
+    async fn test_02_given_a_snapshot_when_reopening_the_repository_then_it_should_restore_the_state_and_the_counters()
+     {
+        let directory = TempDir::new().unwrap();
+        populate(&repository_with_log(&directory, 1)).await;
+        assert!(directory.path().join("snapshot.json").exists());
+        assert_eq!(log_lines(&directory), 0);
+
+        assert_restored(&repository_with_log(&directory, 1)).await;
+    }
+
+    #[tokio::test]
+    async fn test_03_given_a_rejected_mutation_when_logging_it_then_it_should_append_nothing() {
+        let directory = TempDir::new().unwrap();
+        let repository = repository_with_log(&directory, 1000);
+        repository
+            .create_client(&create_client_request("12345678"))
+            .await
+            .unwrap();
+        assert_eq!(log_lines(&directory), 1);
+
+        let result = repository.debit_balance(&debit("0", dec!(-1))).await;
+
+        assert!(matches!(result, Err(ClientError::InsufficientFunds { .. })));
+        assert_eq!(log_lines(&directory), 1);
+        assert_eq!(balance(&repository, "0").await.balance(), &dec!(0));
+    }
+
+    #[tokio::test]
+    async fn test_04_given_a_credit_leg_that_overflows_when_transferring_then_it_should_leave_both_clients_untouched()
+     {
+        let repository = InMemoryRepository::new();
+        for document in ["12345678", "87654321"] {
+            repository
+                .create_client(&create_client_request(document))
+                .await
+                .unwrap();
+        }
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+        repository
+            .credit_balance(&credit("1", Decimal::MAX))
+            .await
+            .unwrap();
+
+        let result = repository
+            .transfer(&TransferRequest::new(client_id("0"), client_id("1"), dec!(1)).unwrap())
+            .await;
+
+        assert_eq!(result.unwrap_err(), ClientError::AmountOverflow);
+        assert_eq!(balance(&repository, "0").await.balance(), &dec!(10));
+        assert_eq!(balance(&repository, "1").await.balance(), &Decimal::MAX);
+        for client_id in ["0", "1"] {
+            let transactions = repository
+                .get_transactions_by_client_id(&GetClientRequest::new(self::client_id(client_id)))
+                .await
+                .unwrap();
+            assert_eq!(transactions.len(), 1);
+        }
+    }
+
+    async fn create_clients(repository: &InMemoryRepository) {
+        for document in ["12345678", "87654321"] {
+            repository
+                .create_client(&create_client_request(document))
+                .await
+                .unwrap();
+        }
+    }
+
+    fn batch(items: Vec<BatchTransactionItem>) -> BatchTransactionRequest {
+        BatchTransactionRequest::new(items).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_05_given_a_batch_item_that_overflows_when_applying_the_batch_then_it_should_reject_it_by_index_and_apply_nothing()
+     {
+        let repository = InMemoryRepository::new();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("1", dec!(1)))
+            .await
+            .unwrap();
+
+        let result = repository
+            .apply_batch(&batch(vec![
+                credit("0", dec!(10)).into(),
+                credit("1", Decimal::MAX).into(),
+            ]))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ClientError::BatchItemRejected {
+                index: 1,
+                reason: Box::new(ClientError::AmountOverflow),
+            }
+        );
+        assert_eq!(balance(&repository, "0").await.balance(), &dec!(0));
+        assert_eq!(balance(&repository, "1").await.balance(), &dec!(1));
+    }
+
+    #[tokio::test]
+    async fn test_06_given_a_debit_funded_by_a_previous_item_when_applying_the_batch_then_it_should_apply_every_item()
+     {
+        let repository = InMemoryRepository::new();
+        create_clients(&repository).await;
+
+        let balances = repository
+            .apply_batch(&batch(vec![
+                credit("0", dec!(10)).into(),
+                debit("0", dec!(-4)).into(),
+                credit("1", dec!(3)).into(),
+            ]))
+            .await
+            .unwrap();
+
+        let balances = balances
+            .iter()
+            .map(|balance| *balance.balance())
+            .collect::<Vec<_>>();
+        assert_eq!(balances, vec![dec!(10), dec!(6), dec!(3)]);
+        assert_eq!(balance(&repository, "0").await.balance(), &dec!(6));
+        assert_eq!(balance(&repository, "1").await.balance(), &dec!(3));
+    }
+
+    #[tokio::test]
+    async fn test_07_given_a_debit_of_held_funds_when_applying_the_batch_then_it_should_reject_it_by_index_and_apply_nothing()
+     {
+        let repository = InMemoryRepository::new();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+        repository
+            .authorize(&AuthorizeRequest::new(client_id("0"), dec!(8)).unwrap())
+            .await
+            .unwrap();
+
+        let result = repository
+            .apply_batch(&batch(vec![
+                credit("1", dec!(5)).into(),
+                debit("0", dec!(-3)).into(),
+            ]))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ClientError::BatchItemRejected {
+                index: 1,
+                reason: Box::new(ClientError::InsufficientFunds {
+                    client_id: client_id("0"),
+                }),
+            }
+        );
+        assert_eq!(balance(&repository, "0").await.balance(), &dec!(10));
+        assert_eq!(balance(&repository, "1").await.balance(), &dec!(0));
+    }
+
+    #[tokio::test]
+    async fn test_08_given_a_missing_client_when_applying_the_batch_then_it_should_reject_it_by_index()
+     {
+        let repository = InMemoryRepository::new();
+        create_clients(&repository).await;
+
+        let result = repository
+            .apply_batch(&batch(vec![
+                credit("0", dec!(10)).into(),
+                credit("7", dec!(1)).into(),
+            ]))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ClientError::BatchItemRejected {
+                index: 1,
+                reason: Box::new(ClientError::NotFoundById {
+                    id_document: client_id("7"),
+                }),
+            }
+        );
+        assert_eq!(balance(&repository, "0").await.balance(), &dec!(0));
+    }
+
+    #[tokio::test]
+    async fn test_09_given_a_pending_hold_when_resetting_the_balances_then_it_should_void_it() {
+        let repository = InMemoryRepository::new();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+        let authorization = repository
+            .authorize(&AuthorizeRequest::new(client_id("0"), dec!(8)).unwrap())
+            .await
+            .unwrap();
+
+        repository.reset_all_balances_to_zero().await.unwrap();
+
+        let reset = balance(&repository, "0").await;
+        assert_eq!(reset.held(), &dec!(0));
+        assert_eq!(reset.available().unwrap(), dec!(0));
+        assert_eq!(
+            repository
+                .void_authorization(authorization.id(), None)
+                .await
+                .unwrap_err(),
+            ClientError::AuthorizationNotPending {
+                authorization_id: authorization.id().clone(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_10_given_a_reset_and_a_merge_when_listing_the_transactions_then_they_should_record_the_adjustments()
+     {
+        let repository = InMemoryRepository::new();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+
+        let old_balances = repository.reset_all_balances_to_zero().await.unwrap();
+        let reset = repository
+            .get_transactions_by_client_id(&GetClientRequest::new(client_id("0")))
+            .await
+            .unwrap();
+        assert_eq!(reset.len(), 2);
+        assert_eq!(reset[1].kind(), &TransactionKind::Adjustment);
+        assert_eq!(reset[1].amount(), &dec!(-10));
+        assert_eq!(reset[1].resulting_balance(), &dec!(0));
+
+        repository.merge_old_balances(old_balances).await.unwrap();
+        let merged = repository
+            .get_transactions_by_client_id(&GetClientRequest::new(client_id("0")))
+            .await
+            .unwrap();
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[2].kind(), &TransactionKind::Adjustment);
+        assert_eq!(merged[2].amount(), &dec!(10));
+        assert_eq!(merged[2].resulting_balance(), &dec!(10));
+        // A balance that does not change records no adjustment.
+        assert!(
+            repository
+                .get_transactions_by_client_id(&GetClientRequest::new(client_id("1")))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_11_given_a_failed_export_when_merging_the_old_balances_then_it_should_reinstate_the_holds()
+     {
+        let repository = InMemoryRepository::new();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+        let hold = repository
+            .authorize(&AuthorizeRequest::new(client_id("0"), dec!(8)).unwrap())
+            .await
+            .unwrap();
+        let reset = repository.reset_all_balances_to_zero().await.unwrap();
+        assert_eq!(reset.voided_authorizations(), &[hold.id().clone()]);
+
+        // What store_balances does when the export fails.
+        repository.merge_old_balances(reset).await.unwrap();
+
+        let restored = balance(&repository, "0").await;
+        assert_eq!(restored.balance(), &dec!(10));
+        assert_eq!(restored.held(), &dec!(8));
+        let captured = repository
+            .capture_authorization(
+                &CaptureAuthorizationRequest::new(hold.id().clone(), Some(dec!(8))).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(captured.balance(), &dec!(2));
+        assert_eq!(balance(&repository, "0").await.held(), &dec!(0));
+    }
+
+    #[tokio::test]
+    async fn test_12_given_a_client_when_getting_its_overview_then_the_balances_should_match_its_version()
+     {
+        let repository = InMemoryRepository::new();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+
+        let overview = repository
+            .get_client_overview(&GetClientRequest::new(client_id("0")))
+            .await
+            .unwrap();
+
+        let version = overview.client().version();
+        assert_eq!(version, &Version::new("2").unwrap());
+        assert_eq!(overview.balances()[0].balance(), &dec!(10));
+        assert_eq!(overview.balances()[0].version(), version);
+        let (account, balances) = &overview.accounts()[0];
+        assert!(account.is_main());
+        assert_eq!(balances[0].version(), version);
+    }
+
+    #[tokio::test]
+    async fn test_13_given_a_stale_version_when_authorizing_or_voiding_then_it_should_reject_it() {
+        let repository = InMemoryRepository::new();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+        let read_version = Version::new("2").unwrap();
+
+        assert_eq!(
+            repository
+                .authorize(
+                    &AuthorizeRequest::new(client_id("0"), dec!(8))
+                        .unwrap()
+                        .with_expected_version(Version::new("1").unwrap()),
+                )
+                .await
+                .unwrap_err(),
+            ClientError::VersionConflict {
+                client_id: client_id("0"),
+                expected: Version::new("1").unwrap(),
+                actual: read_version,
+            }
+        );
+        let hold = repository
+            .authorize(
+                &AuthorizeRequest::new(client_id("0"), dec!(8))
+                    .unwrap()
+                    .with_expected_version(read_version),
+            )
+            .await
+            .unwrap();
+        assert_eq!(hold.version(), &read_version.next());
+
+        assert_eq!(
+            repository
+                .void_authorization(hold.id(), Some(read_version))
+                .await
+                .unwrap_err(),
+            ClientError::VersionConflict {
+                client_id: client_id("0"),
+                expected: read_version,
+                actual: read_version.next(),
+            }
+        );
+        let voided = repository
+            .void_authorization(hold.id(), Some(*hold.version()))
+            .await
+            .unwrap();
+        assert_eq!(voided.version(), &read_version.next().next());
+    }
+
+    #[tokio::test]
+    async fn test_14_given_a_client_with_an_email_when_removing_it_then_it_should_record_the_change()
+     {
+        let repository = InMemoryRepository::new();
+        create_clients(&repository).await;
+        repository
+            .update_client(
+                &UpdateClientRequest::new(client_id("0"))
+                    .with_email(Email::new("john@example.com").unwrap()),
+            )
+            .await
+            .unwrap();
+
+        let client = repository
+            .update_client(&UpdateClientRequest::new(client_id("0")).without_email())
+            .await
+            .unwrap();
+
+        assert_eq!(client.email(), None);
+        let overview = repository
+            .get_client_overview(&GetClientRequest::new(client_id("0")))
+            .await
+            .unwrap();
+        assert_eq!(overview.client().email(), None);
+        let changes = repository
+            .get_client_changes(&GetClientRequest::new(client_id("0")))
+            .await
+            .unwrap();
+        let removal = changes.last().unwrap();
+        assert_eq!(removal.field(), ClientField::Email);
+        assert_eq!(removal.old_value(), "john@example.com");
+        assert_eq!(removal.new_value(), "");
     }
 }
@@ -1,3 +1,10 @@
+pub mod config;
 pub mod in_memory;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod system_clock;
 
+pub mod exchange_rate_table;
 pub mod file_exporter;
+pub mod id_generators;
+pub mod write_ahead_log;
@@ -0,0 +1,2440 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Mutex, MutexGuard},
+};
+
+use anyhow::{Context, bail};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use rusqlite::{Connection, OptionalExtension, Row, TransactionBehavior, params, params_from_iter};
+use rust_decimal::Decimal;
+
+use crate::domain::{
+    model::{
+        dto::{
+            authorize::AuthorizeRequest, batch_transaction::BatchTransactionRequest,
+            capture_authorization::CaptureAuthorizationRequest,
+            create_account::CreateAccountRequest, create_client::CreateClientRequest,
+            credit_transaction::CreditTransactionRequest,
+            debit_transaction::DebitTransactionRequest, get_balance::GetClientRequest,
+            list_clients::ListClientsRequest, transfer::TransferRequest,
+            update_client::UpdateClientRequest,
+        },
+        entity::{
+            account::Account, authorization::Authorization, balance::Balance,
+            balance_reset::BalanceReset, client::Client, client_change::ClientChange,
+            client_overview::ClientOverview, client_page::ClientPage, conversion::Conversion,
+            transaction::Transaction,
+        },
+        error::ClientError,
+        value::{
+            account_id::AccountId,
+            account_name::AccountName,
+            authorization_id::AuthorizationId,
+            authorization_status::AuthorizationStatus,
+            birth_date::BirthDate,
+            client_cursor::ClientCursor,
+            client_field::ClientField,
+            client_id::ClientId,
+            client_name::ClientName,
+            client_sort::{ClientSort, SortDirection},
+            client_status::ClientStatus,
+            country::Country,
+            currency::Currency,
+            document::Document,
+            document_type::DocumentType,
+            email::Email,
+            idempotency_key::IdempotencyKey,
+            money::Money,
+            overdraft_limit::OverdraftLimit,
+            phone_number::PhoneNumber,
+            transaction_id::TransactionId,
+            transaction_kind::TransactionKind,
+            version::Version,
+        },
+    },
+    port::outbound::{
+        client_balance_repository::ClientBalanceRepository, id_generator::IdGenerator,
+    },
+};
+use crate::infrastructure::outbound::{
+    config::{get_authorization_timeout, get_idempotency_window, get_unique_contacts},
+    id_generators::SequentialIdGenerator,
+};
+
+const DEFAULT_SQLITE_PATH: &str = "prex_core.db";
+
+/// The schema, one migration per step. A database records in `user_version` how many of them it
+/// has applied, so only the new ones run on startup.
+const MIGRATIONS: &[&str] = &[
+    include_str!("../../../migrations/sqlite/0001_create_tables.sql"),
+    include_str!("../../../migrations/sqlite/0002_create_id_sequences.sql"),
+];
+
+const CLIENT_COLUMNS: &str = "id, name, birth_date, document_type, document, country, \
+    overdraft_limit, status, email, phone, version";
+const TRANSACTION_COLUMNS: &str = "id, client_id, account_id, amount, currency, kind, \
+    created_at, resulting_balance, reversal_of, original_amount, original_currency, rate, \
+    spread, applied_rate";
+const AUTHORIZATION_COLUMNS: &str =
+    "id, client_id, amount, currency, status, created_at, expires_at, captured_amount";
+
+type GuardConnection<'a> = MutexGuard<'a, Connection>;
+
+/// The balances of an [Account], one per [Currency].
+type CurrencyBalances = BTreeMap<Currency, Money>;
+
+/// What a [Transaction] refers to besides its amount.
+#[derive(Clone, Copy, Default)]
+struct TransactionOrigin<'a> {
+    /// The [Account] it is applied to, the main one if unset.
+    account_id: Option<&'a AccountId>,
+    /// The [Transaction] it compensates, if it is a reversal.
+    reversal_of: Option<&'a TransactionId>,
+    /// The conversion of the requested amount, if it was requested in another [Currency].
+    conversion: Option<&'a Conversion>,
+    /// The [Version] the [Client] must still be at, if the caller expects one.
+    expected_version: Option<&'a Version>,
+}
+
+/// A row of `clients`, as it is stored.
+struct ClientRow {
+    id: String,
+    name: String,
+    birth_date: String,
+    document_type: String,
+    document: String,
+    country: String,
+    overdraft_limit: String,
+    status: String,
+    email: Option<String>,
+    phone: Option<String>,
+    version: i64,
+}
+
+impl ClientRow {
+    fn read(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            birth_date: row.get(2)?,
+            document_type: row.get(3)?,
+            document: row.get(4)?,
+            country: row.get(5)?,
+            overdraft_limit: row.get(6)?,
+            status: row.get(7)?,
+            email: row.get(8)?,
+            phone: row.get(9)?,
+            version: row.get(10)?,
+        })
+    }
+
+    fn into_client(self) -> Result<Client, ClientError> {
+        let document = Document::new(DocumentType::new(&self.document_type)?, &self.document)?;
+        Ok(Client::new(
+            ClientId::new(&self.id)?,
+            ClientName::new(&self.name)?,
+            BirthDate::new(&self.birth_date)?,
+            document,
+            Country::new(&self.country)?,
+        )
+        .with_overdraft_limit(OverdraftLimit::new(decimal(&self.overdraft_limit)?)?)
+        .with_email(self.email.as_deref().map(Email::new).transpose()?)
+        .with_phone(self.phone.as_deref().map(PhoneNumber::new).transpose()?)
+        .with_status(ClientStatus::new(&self.status)?)
+        .with_version(Version::new(&self.version.to_string())?))
+    }
+}
+
+/// A row of `transactions`, as it is stored. The conversion columns are set together, and its
+/// converted amount and currency are the ones of the transaction.
+struct TransactionRow {
+    id: i64,
+    client_id: String,
+    account_id: Option<i64>,
+    amount: String,
+    currency: String,
+    kind: String,
+    created_at: String,
+    resulting_balance: String,
+    reversal_of: Option<i64>,
+    original_amount: Option<String>,
+    original_currency: Option<String>,
+    rate: Option<String>,
+    spread: Option<String>,
+    applied_rate: Option<String>,
+}
+
+impl TransactionRow {
+    fn read(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            client_id: row.get(1)?,
+            account_id: row.get(2)?,
+            amount: row.get(3)?,
+            currency: row.get(4)?,
+            kind: row.get(5)?,
+            created_at: row.get(6)?,
+            resulting_balance: row.get(7)?,
+            reversal_of: row.get(8)?,
+            original_amount: row.get(9)?,
+            original_currency: row.get(10)?,
+            rate: row.get(11)?,
+            spread: row.get(12)?,
+            applied_rate: row.get(13)?,
+        })
+    }
+
+    /// The conversion the [Transaction] was applied with, if any.
+    fn conversion(&self) -> Result<Option<Conversion>, ClientError> {
+        let (
+            Some(original_amount),
+            Some(original_currency),
+            Some(rate),
+            Some(spread),
+            Some(applied_rate),
+        ) = (
+            &self.original_amount,
+            &self.original_currency,
+            &self.rate,
+            &self.spread,
+            &self.applied_rate,
+        )
+        else {
+            return Ok(None);
+        };
+        Ok(Some(Conversion::new(
+            decimal(original_amount)?,
+            Currency::new(original_currency)?,
+            decimal(rate)?,
+            decimal(spread)?,
+            decimal(applied_rate)?,
+            decimal(&self.amount)?,
+            Currency::new(&self.currency)?,
+        )))
+    }
+
+    fn into_transaction(self) -> Result<Transaction, ClientError> {
+        let conversion = self.conversion()?;
+        let mut transaction = Transaction::new(
+            TransactionId::new(&self.id.to_string())?,
+            ClientId::new(&self.client_id)?,
+            decimal(&self.amount)?,
+            TransactionKind::new(&self.kind)?,
+            timestamp(&self.created_at)?,
+            decimal(&self.resulting_balance)?,
+        )
+        .with_currency(Currency::new(&self.currency)?);
+        if let Some(account_id) = self.account_id {
+            transaction = transaction.with_account_id(AccountId::new(&account_id.to_string())?);
+        }
+        if let Some(reversal_of) = self.reversal_of {
+            transaction =
+                transaction.with_reversal_of(TransactionId::new(&reversal_of.to_string())?);
+        }
+        if let Some(conversion) = conversion {
+            transaction = transaction.with_conversion(conversion);
+        }
+        Ok(transaction)
+    }
+}
+
+/// A row of `authorizations`, as it is stored.
+struct AuthorizationRow {
+    id: i64,
+    client_id: String,
+    amount: String,
+    currency: String,
+    status: String,
+    created_at: String,
+    expires_at: String,
+    captured_amount: Option<String>,
+}
+
+impl AuthorizationRow {
+    fn read(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            client_id: row.get(1)?,
+            amount: row.get(2)?,
+            currency: row.get(3)?,
+            status: row.get(4)?,
+            created_at: row.get(5)?,
+            expires_at: row.get(6)?,
+            captured_amount: row.get(7)?,
+        })
+    }
+
+    fn into_authorization(self) -> Result<Authorization, ClientError> {
+        Ok(Authorization::new(
+            AuthorizationId::new(&self.id.to_string())?,
+            ClientId::new(&self.client_id)?,
+            decimal(&self.amount)?,
+            timestamp(&self.created_at)?,
+            timestamp(&self.expires_at)?,
+        )
+        .with_currency(Currency::new(&self.currency)?)
+        .with_status(
+            AuthorizationStatus::new(&self.status)?,
+            self.captured_amount.as_deref().map(decimal).transpose()?,
+        ))
+    }
+}
+
+/// Every error of SQLite is unexpected for the domain, like a poisoned lock in memory.
+fn unknown(error: rusqlite::Error) -> ClientError {
+    ClientError::Unknown(error.into())
+}
+
+fn decimal(value: &str) -> Result<Decimal, ClientError> {
+    Ok(value
+        .parse::<Decimal>()
+        .with_context(|| format!("Invalid stored amount: {value}"))?)
+}
+
+fn timestamp(value: &str) -> Result<DateTime<Utc>, ClientError> {
+    Ok(DateTime::parse_from_rfc3339(value)
+        .with_context(|| format!("Invalid stored date: {value}"))?
+        .with_timezone(&Utc))
+}
+
+/// Dates are stored in UTC with a fixed number of decimals, so comparing them as text compares
+/// them in time.
+fn stored_timestamp(value: &DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Nanos, true)
+}
+
+/// Applies the migrations the database has not applied yet, each one in its own transaction.
+fn migrate(connection: &mut Connection) -> Result<(), anyhow::Error> {
+    let applied: usize = connection.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    if applied > MIGRATIONS.len() {
+        bail!(
+            "The database is at migration {applied}, but this build only knows {}",
+            MIGRATIONS.len()
+        );
+    }
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(applied) {
+        let transaction = connection.transaction()?;
+        transaction
+            .execute_batch(migration)
+            .with_context(|| format!("Error applying migration {}", index + 1))?;
+        transaction.pragma_update(None, "user_version", index + 1)?;
+        transaction.commit()?;
+    }
+    Ok(())
+}
+
+fn find_client(connection: &Connection, client_id: &ClientId) -> Result<Client, ClientError> {
+    connection
+        .query_row(
+            &format!("SELECT {CLIENT_COLUMNS} FROM clients WHERE id = ?1"),
+            params![client_id.to_string()],
+            ClientRow::read,
+        )
+        .optional()
+        .map_err(unknown)?
+        .ok_or(ClientError::NotFoundById {
+            id_document: client_id.clone(),
+        })?
+        .into_client()
+}
+
+fn find_client_by_document(
+    connection: &Connection,
+    document: &Document,
+) -> Result<Option<Client>, ClientError> {
+    connection
+        .query_row(
+            &format!(
+                "SELECT {CLIENT_COLUMNS} FROM clients WHERE document_type = ?1 AND document = ?2"
+            ),
+            params![document.document_type().to_string(), document.number()],
+            ClientRow::read,
+        )
+        .optional()
+        .map_err(unknown)?
+        .map(ClientRow::into_client)
+        .transpose()
+}
+
+fn client_exists(connection: &Connection, client_id: &ClientId) -> Result<bool, ClientError> {
+    connection
+        .query_row(
+            "SELECT EXISTS (SELECT 1 FROM clients WHERE id = ?1)",
+            params![client_id.to_string()],
+            |row| row.get(0),
+        )
+        .map_err(unknown)
+}
+
+fn insert_client(connection: &Connection, client: &Client) -> Result<(), ClientError> {
+    connection
+        .execute(
+            &format!(
+                "INSERT INTO clients ({CLIENT_COLUMNS}) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)"
+            ),
+            params![
+                client.id().to_string(),
+                client.name().to_string(),
+                client.birth_date().to_string(),
+                client.document().document_type().to_string(),
+                client.document().number(),
+                client.country().to_string(),
+                client.overdraft_limit().to_string(),
+                client.status().to_string(),
+                client.email().map(ToString::to_string),
+                client.phone().map(ToString::to_string),
+                client.version().to_string(),
+            ],
+        )
+        .map_err(unknown)?;
+    Ok(())
+}
+
+/// Writes every detail of the [Client] but its id.
+fn save_client(connection: &Connection, client: &Client) -> Result<(), ClientError> {
+    connection
+        .execute(
+            "UPDATE clients SET name = ?2, birth_date = ?3, document_type = ?4, document = ?5, \
+             country = ?6, overdraft_limit = ?7, status = ?8, email = ?9, phone = ?10, \
+             version = ?11 WHERE id = ?1",
+            params![
+                client.id().to_string(),
+                client.name().to_string(),
+                client.birth_date().to_string(),
+                client.document().document_type().to_string(),
+                client.document().number(),
+                client.country().to_string(),
+                client.overdraft_limit().to_string(),
+                client.status().to_string(),
+                client.email().map(ToString::to_string),
+                client.phone().map(ToString::to_string),
+                client.version().to_string(),
+            ],
+        )
+        .map_err(unknown)?;
+    Ok(())
+}
+
+/// Increases the [Version] of the [Client] and returns the new one.
+fn increase_version(connection: &Connection, client_id: &ClientId) -> Result<Version, ClientError> {
+    let version: i64 = connection
+        .query_row(
+            "UPDATE clients SET version = version + 1 WHERE id = ?1 RETURNING version",
+            params![client_id.to_string()],
+            |row| row.get(0),
+        )
+        .map_err(unknown)?;
+    Version::new(&version.to_string())
+}
+
+/// Checks that no [Client] other than the owner already has the [Email] or the [PhoneNumber].
+fn ensure_contacts_available(
+    connection: &Connection,
+    email: Option<&Email>,
+    phone: Option<&PhoneNumber>,
+    owner: Option<&ClientId>,
+) -> Result<(), ClientError> {
+    let owner = owner.map(ToString::to_string);
+    for (field_name, value) in [
+        ("email", email.map(ToString::to_string)),
+        ("phone", phone.map(ToString::to_string)),
+    ] {
+        let Some(value) = value else {
+            continue;
+        };
+        let taken: bool = connection
+            .query_row(
+                &format!(
+                    "SELECT EXISTS (SELECT 1 FROM clients WHERE {field_name} = ?1 \
+                     AND id IS NOT ?2)"
+                ),
+                params![value, owner],
+                |row| row.get(0),
+            )
+            .map_err(unknown)?;
+        if taken {
+            return Err(ClientError::DuplicateContact {
+                field_name: field_name.to_string(),
+                value,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// The [Account]s of the [Client], in the order they were opened, the main one first.
+fn accounts_of(connection: &Connection, client_id: &ClientId) -> Result<Vec<Account>, ClientError> {
+    let mut statement = connection
+        .prepare("SELECT id, name, main FROM accounts WHERE client_id = ?1 ORDER BY id")
+        .map_err(unknown)?;
+    let rows = statement
+        .query_map(params![client_id.to_string()], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, bool>(2)?,
+            ))
+        })
+        .map_err(unknown)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(unknown)?;
+    rows.into_iter()
+        .map(|(id, name, main)| {
+            let id = AccountId::new(&id.to_string())?;
+            Ok(match main {
+                true => Account::main(id, client_id.clone()),
+                false => Account::new(id, client_id.clone(), AccountName::new(&name)?),
+            })
+        })
+        .collect()
+}
+
+/// The given [Account] of the [Client], or its main one if unset.
+fn resolve_account(
+    connection: &Connection,
+    client_id: &ClientId,
+    account_id: Option<&AccountId>,
+) -> Result<Account, ClientError> {
+    let accounts = accounts_of(connection, client_id)?;
+    match account_id {
+        None => accounts
+            .into_iter()
+            .find(Account::is_main)
+            .context("every client has a main account")
+            .map_err(ClientError::from),
+        Some(account_id) => accounts
+            .into_iter()
+            .find(|account| account.id() == account_id)
+            .ok_or(ClientError::AccountNotFound {
+                account_id: account_id.clone(),
+            }),
+    }
+}
+
+fn insert_account(connection: &Connection, account: &Account) -> Result<(), ClientError> {
+    connection
+        .execute(
+            "INSERT INTO accounts (id, client_id, name, main) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                account.id().to_string(),
+                account.client_id().to_string(),
+                account.name().to_string(),
+                account.is_main(),
+            ],
+        )
+        .map_err(unknown)?;
+    // Like in memory, every account starts with a zero balance in the default currency.
+    set_balance(connection, account.id(), &Money::zero(Currency::default()))
+}
+
+/// The balances of the [Account], one per [Currency] it operated in.
+fn balances_of(
+    connection: &Connection,
+    account_id: &AccountId,
+) -> Result<CurrencyBalances, ClientError> {
+    let mut statement = connection
+        .prepare("SELECT currency, balance FROM balances WHERE account_id = ?1")
+        .map_err(unknown)?;
+    let rows = statement
+        .query_map(params![account_id.to_string()], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(unknown)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(unknown)?;
+    rows.into_iter()
+        .map(|(currency, balance)| {
+            let currency = Currency::new(&currency)?;
+            Ok((currency.clone(), Money::new(decimal(&balance)?, currency)?))
+        })
+        .collect()
+}
+
+/// The ledger balance in the [Currency], zero if the [Account] never operated in it.
+fn balance_of(
+    connection: &Connection,
+    account_id: &AccountId,
+    currency: &Currency,
+) -> Result<Money, ClientError> {
+    let balance: Option<String> = connection
+        .query_row(
+            "SELECT balance FROM balances WHERE account_id = ?1 AND currency = ?2",
+            params![account_id.to_string(), currency.to_string()],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(unknown)?;
+    match balance {
+        Some(balance) => Money::new(decimal(&balance)?, currency.clone()),
+        None => Ok(Money::zero(currency.clone())),
+    }
+}
+
+fn set_balance(
+    connection: &Connection,
+    account_id: &AccountId,
+    balance: &Money,
+) -> Result<(), ClientError> {
+    connection
+        .execute(
+            "INSERT INTO balances (account_id, currency, balance) VALUES (?1, ?2, ?3) \
+             ON CONFLICT (account_id, currency) DO UPDATE SET balance = excluded.balance",
+            params![
+                account_id.to_string(),
+                balance.currency().to_string(),
+                balance.amount().to_string(),
+            ],
+        )
+        .map_err(unknown)?;
+    Ok(())
+}
+
+/// The ledger balances added up across every [Account] of the [Client], one per [Currency].
+fn total_balances(
+    connection: &Connection,
+    client_id: &ClientId,
+) -> Result<CurrencyBalances, ClientError> {
+    let mut totals = CurrencyBalances::new();
+    for account in accounts_of(connection, client_id)? {
+        for (currency, balance) in balances_of(connection, account.id())? {
+            let total = match totals.get(&currency) {
+                Some(total) => total.checked_add(&balance)?,
+                None => balance,
+            };
+            totals.insert(currency, total);
+        }
+    }
+    Ok(totals)
+}
+
+/// The ledger balance in the [Currency] added up across every [Account] of the [Client].
+fn total_balance(
+    connection: &Connection,
+    client_id: &ClientId,
+    currency: &Currency,
+) -> Result<Money, ClientError> {
+    Ok(total_balances(connection, client_id)?
+        .remove(currency)
+        .unwrap_or_else(|| Money::zero(currency.clone())))
+}
+
+/// The amount reserved in the [Currency] by the holds of the [Client] that have not expired yet.
+/// Holds always reserve funds of its main [Account].
+fn held_amount(
+    connection: &Connection,
+    client_id: &ClientId,
+    currency: &Currency,
+    now: DateTime<Utc>,
+) -> Result<Money, ClientError> {
+    let mut statement = connection
+        .prepare(
+            "SELECT amount FROM authorizations WHERE client_id = ?1 AND currency = ?2 \
+             AND status = ?3 AND expires_at > ?4",
+        )
+        .map_err(unknown)?;
+    let amounts = statement
+        .query_map(
+            params![
+                client_id.to_string(),
+                currency.to_string(),
+                AuthorizationStatus::Pending.to_string(),
+                stored_timestamp(&now),
+            ],
+            |row| row.get::<_, String>(0),
+        )
+        .map_err(unknown)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(unknown)?;
+    amounts
+        .iter()
+        .try_fold(Money::zero(currency.clone()), |held, amount| {
+            held.checked_add(&Money::new(decimal(amount)?, currency.clone())?)
+        })
+}
+
+/// The [Balance]s of the [Client] added up across every [Account], one per [Currency], with the
+/// amounts held.
+fn client_balances(
+    connection: &Connection,
+    client: &Client,
+    now: DateTime<Utc>,
+) -> Result<Vec<Balance>, ClientError> {
+    total_balances(connection, client.id())?
+        .iter()
+        .map(|(currency, balance)| {
+            let held = held_amount(connection, client.id(), currency, now)?;
+            Ok(Balance::new(client.id().clone(), *balance.amount())
+                .with_held(*held.amount())
+                .with_currency(currency.clone())
+                .with_version(*client.version()))
+        })
+        .collect()
+}
+
+/// Each [Account] of the [Client] with its [Balance]s, one per [Currency]. Only the main one holds
+/// funds.
+fn account_balances(
+    connection: &Connection,
+    client: &Client,
+    now: DateTime<Utc>,
+) -> Result<Vec<(Account, Vec<Balance>)>, ClientError> {
+    accounts_of(connection, client.id())?
+        .into_iter()
+        .map(|account| {
+            let balances = balances_of(connection, account.id())?
+                .iter()
+                .map(|(currency, balance)| {
+                    let held = if account.is_main() {
+                        held_amount(connection, client.id(), currency, now)?
+                    } else {
+                        Money::zero(currency.clone())
+                    };
+                    Ok(Balance::new(client.id().clone(), *balance.amount())
+                        .with_account_id(account.id().clone())
+                        .with_held(*held.amount())
+                        .with_currency(currency.clone())
+                        .with_version(*client.version()))
+                })
+                .collect::<Result<_, ClientError>>()?;
+            Ok((account, balances))
+        })
+        .collect()
+}
+
+/// Returns if no hold of the [Client] reserves funds anymore, in any [Currency].
+fn held_amounts_are_zero(
+    connection: &Connection,
+    client_id: &ClientId,
+    now: DateTime<Utc>,
+) -> Result<bool, ClientError> {
+    let holding: bool = connection
+        .query_row(
+            "SELECT EXISTS (SELECT 1 FROM authorizations WHERE client_id = ?1 AND status = ?2 \
+             AND expires_at > ?3)",
+            params![
+                client_id.to_string(),
+                AuthorizationStatus::Pending.to_string(),
+                stored_timestamp(&now),
+            ],
+            |row| row.get(0),
+        )
+        .map_err(unknown)?;
+    Ok(!holding)
+}
+
+/// Takes the next id of the table from `id_sequences`, starting from 0 like the counters in
+/// memory. Sequences only grow, so an id is never reused. Unlike client ids, these are always
+/// sequential whatever the [IdGenerator], as they only need to be unique within the database.
+fn next_id(connection: &Connection, table: &str) -> Result<String, ClientError> {
+    let id: i64 = connection
+        .query_row(
+            "UPDATE id_sequences SET next_id = next_id + 1 WHERE name = ?1 RETURNING next_id - 1",
+            params![table],
+            |row| row.get(0),
+        )
+        .map_err(unknown)?;
+    Ok(id.to_string())
+}
+
+fn find_transaction(
+    connection: &Connection,
+    transaction_id: &TransactionId,
+) -> Result<Transaction, ClientError> {
+    connection
+        .query_row(
+            &format!("SELECT {TRANSACTION_COLUMNS} FROM transactions WHERE id = ?1"),
+            params![transaction_id.to_string()],
+            TransactionRow::read,
+        )
+        .optional()
+        .map_err(unknown)?
+        .ok_or(ClientError::TransactionNotFound {
+            transaction_id: transaction_id.clone(),
+        })?
+        .into_transaction()
+}
+
+fn insert_transaction(
+    connection: &Connection,
+    transaction: &Transaction,
+) -> Result<(), ClientError> {
+    let conversion = transaction.conversion();
+    connection
+        .execute(
+            &format!(
+                "INSERT INTO transactions ({TRANSACTION_COLUMNS}) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)"
+            ),
+            params![
+                transaction.id().to_string(),
+                transaction.client_id().to_string(),
+                transaction.account_id().map(ToString::to_string),
+                transaction.amount().to_string(),
+                transaction.currency().to_string(),
+                transaction.kind().to_string(),
+                stored_timestamp(transaction.created_at()),
+                transaction.resulting_balance().to_string(),
+                transaction.reversal_of().map(ToString::to_string),
+                conversion.map(|conversion| conversion.original_amount().to_string()),
+                conversion.map(|conversion| conversion.original_currency().to_string()),
+                conversion.map(|conversion| conversion.rate().to_string()),
+                conversion.map(|conversion| conversion.spread().to_string()),
+                conversion.map(|conversion| conversion.applied_rate().to_string()),
+            ],
+        )
+        .map_err(unknown)?;
+    Ok(())
+}
+
+fn ensure_reversible(
+    connection: &Connection,
+    transaction_id: &TransactionId,
+    client_id: &ClientId,
+    account_id: &AccountId,
+    amount: &Decimal,
+    currency: &Currency,
+) -> Result<(), ClientError> {
+    let transaction = find_transaction(connection, transaction_id)?;
+    let reversed: bool = connection
+        .query_row(
+            "SELECT EXISTS (SELECT 1 FROM transactions WHERE reversal_of = ?1)",
+            params![transaction_id.to_string()],
+            |row| row.get(0),
+        )
+        .map_err(unknown)?;
+    if reversed {
+        return Err(ClientError::TransactionAlreadyReversed {
+            transaction_id: transaction_id.clone(),
+        });
+    }
+    if transaction.account_id() != Some(account_id) {
+        return Err(ClientError::TransactionNotReversible {
+            transaction_id: transaction_id.clone(),
+        });
+    }
+    transaction.ensure_reversible_by(client_id, amount, currency)
+}
+
+fn find_authorization(
+    connection: &Connection,
+    authorization_id: &AuthorizationId,
+) -> Result<Authorization, ClientError> {
+    connection
+        .query_row(
+            &format!("SELECT {AUTHORIZATION_COLUMNS} FROM authorizations WHERE id = ?1"),
+            params![authorization_id.to_string()],
+            AuthorizationRow::read,
+        )
+        .optional()
+        .map_err(unknown)?
+        .ok_or(ClientError::AuthorizationNotFound {
+            authorization_id: authorization_id.clone(),
+        })?
+        .into_authorization()
+}
+
+/// Writes what can change of the [Authorization]: its status and the amount it was captured for.
+fn save_authorization(
+    connection: &Connection,
+    authorization: &Authorization,
+) -> Result<(), ClientError> {
+    connection
+        .execute(
+            "UPDATE authorizations SET status = ?2, captured_amount = ?3 WHERE id = ?1",
+            params![
+                authorization.id().to_string(),
+                authorization.status().to_string(),
+                authorization.captured_amount().map(ToString::to_string),
+            ],
+        )
+        .map_err(unknown)?;
+    Ok(())
+}
+
+/// Records the [TransactionKind::Adjustment] of a balance set from `old_balance` to `new_balance`
+/// without a movement, unless the balance does not change.
+fn insert_adjustment(
+    connection: &Connection,
+    client_id: &ClientId,
+    account_id: &AccountId,
+    old_balance: &Money,
+    new_balance: &Money,
+    now: DateTime<Utc>,
+) -> Result<(), ClientError> {
+    let amount = new_balance.checked_sub(old_balance)?;
+    if amount.amount().is_zero() {
+        return Ok(());
+    }
+    let transaction = Transaction::new(
+        TransactionId::new(&next_id(connection, "transactions")?)?,
+        client_id.clone(),
+        *amount.amount(),
+        TransactionKind::Adjustment,
+        now,
+        *new_balance.amount(),
+    )
+    .with_currency(new_balance.currency().clone())
+    .with_account_id(account_id.clone());
+    insert_transaction(connection, &transaction)
+}
+
+/// Applies the amount to the balance of an [Account] of the [Client] and appends its [Transaction]
+/// to the ledger. Debits of the main [Account] can only spend the balance not held by pending
+/// authorizations. Returns the resulting [Balance] and the id of the [Transaction]. The caller
+/// must run it inside a transaction.
+fn apply_amount(
+    connection: &Connection,
+    client_id: &ClientId,
+    amount: &Decimal,
+    currency: &Currency,
+    kind: TransactionKind,
+    origin: TransactionOrigin,
+) -> Result<(Balance, TransactionId), ClientError> {
+    let now = Utc::now();
+    let amount = Money::new(*amount, currency.clone())?;
+    let client = find_client(connection, client_id)?;
+    client.ensure_operable()?;
+    let account = resolve_account(connection, client_id, origin.account_id)?;
+    let held = if account.is_main() {
+        held_amount(connection, client_id, currency, now)?
+    } else {
+        Money::zero(currency.clone())
+    };
+    let balance = balance_of(connection, account.id(), currency)?;
+    account.ensure_funds_for(&client, &balance.checked_sub(&held)?, &amount)?;
+    let new_balance = balance.checked_add(&amount)?;
+    set_balance(connection, account.id(), &new_balance)?;
+    let version = increase_version(connection, client_id)?;
+    let transaction_id = TransactionId::new(&next_id(connection, "transactions")?)?;
+    let mut transaction = Transaction::new(
+        transaction_id.clone(),
+        client_id.clone(),
+        *amount.amount(),
+        kind,
+        now,
+        *new_balance.amount(),
+    )
+    .with_currency(currency.clone())
+    .with_account_id(account.id().clone());
+    if let Some(transaction_id) = origin.reversal_of {
+        transaction = transaction.with_reversal_of(transaction_id.clone());
+    }
+    let mut balance = Balance::new(client_id.clone(), *new_balance.amount())
+        .with_account_id(account.id().clone())
+        .with_held(*held.amount())
+        .with_currency(currency.clone())
+        .with_version(version);
+    if let Some(conversion) = origin.conversion {
+        transaction = transaction.with_conversion(conversion.clone());
+        balance = balance.with_conversion(conversion.clone());
+    }
+    insert_transaction(connection, &transaction)?;
+    Ok((balance, transaction_id))
+}
+
+/// A repository that keeps clients, balances and ledger in a SQLite database, so they survive a
+/// restart. Every operation runs in a single database transaction, so a failure never leaves it
+/// half applied.
+pub struct SqliteRepository {
+    /// A single connection, so operations are serialized like behind the lock of the in-memory one.
+    connection: Mutex<Connection>,
+    /// Mints the ids of new clients, sequential unless another strategy is set.
+    id_generator: Box<dyn IdGenerator>,
+    /// How long an idempotency key is remembered after its first use.
+    idempotency_window: Duration,
+    /// How long a hold reserves funds before expiring on its own.
+    authorization_timeout: Duration,
+    /// Whether two clients can share the same email or phone.
+    unique_contacts: bool,
+}
+
+impl SqliteRepository {
+    /// Opens the database at the path, creating it if missing, and applies the pending migrations.
+    pub fn open(path: &str) -> Result<Self, anyhow::Error> {
+        let connection = Connection::open(path)
+            .with_context(|| format!("Error opening SQLite database: {path}"))?;
+        Self::from_connection(connection)
+    }
+
+    /// Takes over an open database and applies the pending migrations.
+    fn from_connection(mut connection: Connection) -> Result<Self, anyhow::Error> {
+        connection.pragma_update(None, "foreign_keys", true)?;
+        migrate(&mut connection)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+            id_generator: Box::new(SequentialIdGenerator::default()),
+            idempotency_window: get_idempotency_window(),
+            authorization_timeout: get_authorization_timeout(),
+            unique_contacts: get_unique_contacts(),
+        })
+    }
+
+    /// Opens the database set in `SQLITE_PATH`, `prex_core.db` by default.
+    pub fn from_env() -> Result<Self, anyhow::Error> {
+        let path = std::env::var("SQLITE_PATH").unwrap_or(DEFAULT_SQLITE_PATH.to_string());
+        Self::open(&path)
+    }
+
+    /// Replaces the sequential [IdGenerator] the client ids are minted with.
+    pub fn with_id_generator(mut self, id_generator: Box<dyn IdGenerator>) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    fn guard_connection(&self) -> Result<GuardConnection<'_>, anyhow::Error> {
+        match self.connection.lock() {
+            Ok(lock) => Ok(lock),
+            Err(e) => Err(anyhow::anyhow!("Poisoned lock on SQLite connection: {}", e)),
+        }
+    }
+
+    /// Runs the operation in a database transaction, committed only if it succeeds.
+    fn in_transaction<T>(
+        &self,
+        operation: impl FnOnce(&Connection) -> Result<T, ClientError>,
+    ) -> Result<T, ClientError> {
+        let mut connection = self.guard_connection()?;
+        let transaction = connection
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(unknown)?;
+        let result = operation(&transaction)?;
+        transaction.commit().map_err(unknown)?;
+        Ok(result)
+    }
+
+    fn update_balance(
+        &self,
+        client_id: &ClientId,
+        amount: &Decimal,
+        currency: &Currency,
+        kind: TransactionKind,
+        idempotency_key: Option<&IdempotencyKey>,
+        origin: TransactionOrigin,
+    ) -> Result<Balance, ClientError> {
+        // Retries are matched against the amount as it was requested, so a change of the exchange
+        // rate between two retries does not turn them into a conflict.
+        let (requested_amount, requested_currency) = match origin.conversion {
+            Some(conversion) => (conversion.original_amount(), conversion.original_currency()),
+            None => (amount, currency),
+        };
+        self.in_transaction(|connection| {
+            let account = resolve_account(connection, client_id, origin.account_id)?;
+            if let Some(key) = idempotency_key {
+                let cutoff = Utc::now() - self.idempotency_window;
+                connection
+                    .execute(
+                        "DELETE FROM idempotency_records WHERE created_at <= ?1",
+                        params![stored_timestamp(&cutoff)],
+                    )
+                    .map_err(unknown)?;
+                if let Some(balance) = self.replay(
+                    connection,
+                    key,
+                    client_id,
+                    requested_amount,
+                    requested_currency,
+                    kind,
+                    account.id(),
+                )? {
+                    return Ok(balance);
+                }
+            }
+            // A retry of an applied request was answered above, even if that request moved the
+            // version it expected.
+            find_client(connection, client_id)?.ensure_version(origin.expected_version)?;
+            if let Some(transaction_id) = origin.reversal_of {
+                ensure_reversible(
+                    connection,
+                    transaction_id,
+                    client_id,
+                    account.id(),
+                    amount,
+                    currency,
+                )?;
+            }
+            let origin = TransactionOrigin {
+                account_id: Some(account.id()),
+                ..origin
+            };
+            let (balance, transaction_id) =
+                apply_amount(connection, client_id, amount, currency, kind, origin)?;
+            if let Some(key) = idempotency_key {
+                connection
+                    .execute(
+                        "INSERT INTO idempotency_records (key, client_id, amount, currency, \
+                         kind, transaction_id, held, version, created_at) \
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                        params![
+                            key.to_string(),
+                            client_id.to_string(),
+                            requested_amount.to_string(),
+                            requested_currency.to_string(),
+                            kind.to_string(),
+                            transaction_id.to_string(),
+                            balance.held().to_string(),
+                            balance.version().to_string(),
+                            stored_timestamp(&Utc::now()),
+                        ],
+                    )
+                    .map_err(unknown)?;
+            }
+            Ok(balance)
+        })
+    }
+
+    /// The [Balance] returned the first time the idempotency key was used, rebuilt from the
+    /// [Transaction] it produced, if the key is still remembered.
+    #[allow(clippy::too_many_arguments)]
+    fn replay(
+        &self,
+        connection: &Connection,
+        key: &IdempotencyKey,
+        client_id: &ClientId,
+        requested_amount: &Decimal,
+        requested_currency: &Currency,
+        kind: TransactionKind,
+        account_id: &AccountId,
+    ) -> Result<Option<Balance>, ClientError> {
+        let Some((record_client_id, amount, currency, record_kind, transaction_id, held, version)) =
+            connection
+                .query_row(
+                    "SELECT client_id, amount, currency, kind, transaction_id, held, version \
+                     FROM idempotency_records WHERE key = ?1",
+                    params![key.to_string()],
+                    |row| {
+                        Ok((
+                            row.get::<_, String>(0)?,
+                            row.get::<_, String>(1)?,
+                            row.get::<_, String>(2)?,
+                            row.get::<_, String>(3)?,
+                            row.get::<_, i64>(4)?,
+                            row.get::<_, String>(5)?,
+                            row.get::<_, i64>(6)?,
+                        ))
+                    },
+                )
+                .optional()
+                .map_err(unknown)?
+        else {
+            return Ok(None);
+        };
+        let transaction = find_transaction(
+            connection,
+            &TransactionId::new(&transaction_id.to_string())?,
+        )?;
+        let matches = ClientId::new(&record_client_id)? == *client_id
+            && decimal(&amount)? == *requested_amount
+            && Currency::new(&currency)? == *requested_currency
+            && TransactionKind::new(&record_kind)? == kind
+            && transaction.account_id() == Some(account_id);
+        if !matches {
+            return Err(ClientError::IdempotencyKeyConflict { key: key.clone() });
+        }
+        let mut balance = Balance::new(client_id.clone(), *transaction.resulting_balance())
+            .with_account_id(account_id.clone())
+            .with_held(decimal(&held)?)
+            .with_currency(transaction.currency().clone())
+            .with_version(Version::new(&version.to_string())?);
+        if let Some(conversion) = transaction.conversion() {
+            balance = balance.with_conversion(conversion.clone());
+        }
+        Ok(Some(balance))
+    }
+
+    /// Mints the id of a new [Client], skipping the ones already stored, so the sequential
+    /// generator picks up where the previous run left.
+    fn next_client_id(&self, connection: &Connection) -> Result<ClientId, ClientError> {
+        loop {
+            let id = self.id_generator.next_client_id()?;
+            if !client_exists(connection, &id)? {
+                return Ok(id);
+            }
+        }
+    }
+
+    fn _create_client(&self, req: &CreateClientRequest) -> Result<Client, ClientError> {
+        self.in_transaction(|connection| {
+            if find_client_by_document(connection, req.document())?.is_some() {
+                return Err(ClientError::Duplicate {
+                    document: req.document().to_string(),
+                });
+            }
+            if self.unique_contacts {
+                ensure_contacts_available(connection, req.email(), req.phone(), None)?;
+            }
+            // The id is only minted once the client is known to be valid, so a rejected client
+            // does not leave a gap in sequential ids.
+            let id = self.next_client_id(connection)?;
+            let client = Client::new(
+                id.clone(),
+                req.name().clone(),
+                req.birth_date().clone(),
+                req.document().clone(),
+                req.country().clone(),
+            )
+            .with_overdraft_limit(req.overdraft_limit().clone())
+            .with_email(req.email().cloned())
+            .with_phone(req.phone().cloned());
+            insert_client(connection, &client)?;
+            let account_id = AccountId::new(&next_id(connection, "accounts")?)?;
+            insert_account(connection, &Account::main(account_id, id))?;
+            Ok(client)
+        })
+    }
+
+    fn _create_account(&self, req: &CreateAccountRequest) -> Result<Account, ClientError> {
+        self.in_transaction(|connection| {
+            let client = find_client(connection, req.client_id())?;
+            client.ensure_version(req.expected_version())?;
+            client.ensure_operable()?;
+            let account = Account::new(
+                AccountId::new(&next_id(connection, "accounts")?)?,
+                req.client_id().clone(),
+                req.name().clone(),
+            );
+            insert_account(connection, &account)?;
+            increase_version(connection, req.client_id())?;
+            Ok(account)
+        })
+    }
+
+    fn _list_clients(&self, req: &ListClientsRequest) -> Result<ClientPage, ClientError> {
+        let connection = self.guard_connection()?;
+        let mut conditions = vec!["TRUE".to_string()];
+        let mut values = Vec::new();
+        if let Some(country) = req.country() {
+            values.push(country.to_string());
+            conditions.push(format!("country = ?{}", values.len()));
+        }
+        if let Some(prefix) = req.document_prefix() {
+            values.push(prefix.to_string());
+            conditions.push(format!(
+                "substr(document, 1, length(?{0})) = ?{0}",
+                values.len()
+            ));
+        }
+        let mut statement = connection
+            .prepare(&format!(
+                "SELECT {CLIENT_COLUMNS} FROM clients WHERE {}",
+                conditions.join(" AND ")
+            ))
+            .map_err(unknown)?;
+        let client_rows = statement
+            .query_map(params_from_iter(values), ClientRow::read)
+            .map_err(unknown)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(unknown)?;
+        // Names are compared in Rust, as `lower` in SQLite only folds ASCII letters.
+        let name_contains = req
+            .name_contains()
+            .map(|name| name.to_string().to_lowercase());
+        let mut rows: Vec<(Client, Money)> = client_rows
+            .into_iter()
+            .map(ClientRow::into_client)
+            .collect::<Result<Vec<_>, ClientError>>()?
+            .into_iter()
+            .filter(|client| {
+                name_contains.as_ref().is_none_or(|name| {
+                    client
+                        .name()
+                        .to_string()
+                        .to_lowercase()
+                        .contains(name.as_str())
+                })
+            })
+            .map(|client| {
+                let balance = total_balance(&connection, client.id(), req.currency())?;
+                Ok((client, balance))
+            })
+            .collect::<Result<_, ClientError>>()?;
+        rows.retain(|(_, balance)| req.balance_range().contains(balance.amount()));
+
+        // The id breaks the ties, so every client has a unique position and the cursor never skips
+        // or repeats one.
+        let sort_key = |(client, balance): &(Client, Money)| {
+            let balance = (req.sort() == ClientSort::Balance).then(|| *balance.amount());
+            (balance, client.id().clone())
+        };
+        rows.sort_by_key(sort_key);
+        if req.direction() == SortDirection::Descending {
+            rows.reverse();
+        }
+        if let Some(cursor) = req.cursor() {
+            let cursor_key = (cursor.balance().copied(), cursor.client_id().clone());
+            rows.retain(|row| match req.direction() {
+                SortDirection::Ascending => sort_key(row) > cursor_key,
+                SortDirection::Descending => sort_key(row) < cursor_key,
+            });
+        }
+
+        let next_cursor = (rows.len() > req.limit()).then(|| {
+            let (client, balance) = &rows[req.limit() - 1];
+            match req.sort() {
+                ClientSort::Id => ClientCursor::after_id(client.id().clone()),
+                ClientSort::Balance => {
+                    ClientCursor::after_balance(*balance.amount(), client.id().clone())
+                }
+            }
+        });
+        let now = Utc::now();
+        let page = rows
+            .into_iter()
+            .take(req.limit())
+            .map(|(client, balance)| {
+                let held = held_amount(&connection, client.id(), req.currency(), now)?;
+                let balance = Balance::new(client.id().clone(), *balance.amount())
+                    .with_held(*held.amount())
+                    .with_currency(req.currency().clone())
+                    .with_version(*client.version());
+                Ok((client, balance))
+            })
+            .collect::<Result<Vec<_>, ClientError>>()?;
+        Ok(ClientPage::new(page, next_cursor))
+    }
+
+    fn _update_client(&self, req: &UpdateClientRequest) -> Result<Client, ClientError> {
+        self.in_transaction(|connection| {
+            let mut client = find_client(connection, req.client_id())?;
+            client.ensure_version(req.expected_version())?;
+            if let Some(document) = req.document()
+                && find_client_by_document(connection, document)?
+                    .is_some_and(|other| other.id() != req.client_id())
+            {
+                return Err(ClientError::Duplicate {
+                    document: document.to_string(),
+                });
+            }
+            if self.unique_contacts {
+                ensure_contacts_available(
+                    connection,
+                    req.email().flatten(),
+                    req.phone().flatten(),
+                    Some(req.client_id()),
+                )?;
+            }
+            if client.status() == ClientStatus::Closed {
+                return Err(ClientError::ClientClosed {
+                    client_id: req.client_id().clone(),
+                });
+            }
+            let changes = client.update(req, Utc::now());
+            if changes.is_empty() {
+                return Ok(client);
+            }
+            client.increase_version();
+            save_client(connection, &client)?;
+            for change in &changes {
+                connection
+                    .execute(
+                        "INSERT INTO client_changes (client_id, field, old_value, new_value, \
+                         changed_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+                        params![
+                            change.client_id().to_string(),
+                            change.field().to_string(),
+                            change.old_value(),
+                            change.new_value(),
+                            stored_timestamp(change.changed_at()),
+                        ],
+                    )
+                    .map_err(unknown)?;
+            }
+            Ok(client)
+        })
+    }
+
+    fn _get_client_changes(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<ClientChange>, ClientError> {
+        let connection = self.guard_connection()?;
+        if !client_exists(&connection, req.client_id())? {
+            return Err(ClientError::NotFoundById {
+                id_document: req.client_id().clone(),
+            });
+        }
+        let mut statement = connection
+            .prepare(
+                "SELECT field, old_value, new_value, changed_at FROM client_changes \
+                 WHERE client_id = ?1 ORDER BY id",
+            )
+            .map_err(unknown)?;
+        let rows = statement
+            .query_map(params![req.client_id().to_string()], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                ))
+            })
+            .map_err(unknown)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(unknown)?;
+        rows.into_iter()
+            .map(|(field, old_value, new_value, changed_at)| {
+                Ok(ClientChange::new(
+                    req.client_id().clone(),
+                    ClientField::new(&field)?,
+                    old_value,
+                    new_value,
+                    timestamp(&changed_at)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn _client_id_exists(&self, client_id: &ClientId) -> Result<bool, ClientError> {
+        let connection = self.guard_connection()?;
+        client_exists(&connection, client_id)
+    }
+
+    fn _get_client_by_document(&self, document: &Document) -> Result<Client, ClientError> {
+        let connection = self.guard_connection()?;
+        find_client_by_document(&connection, document)?.ok_or(ClientError::NotFoundByDocument {
+            document: document.clone(),
+        })
+    }
+
+    fn _credit_balance(&self, req: &CreditTransactionRequest) -> Result<Balance, ClientError> {
+        self.update_balance(
+            req.client_id(),
+            req.applied_amount(),
+            req.applied_currency(),
+            TransactionKind::Credit,
+            req.idempotency_key(),
+            TransactionOrigin {
+                account_id: req.account_id(),
+                reversal_of: req.reversal_of(),
+                conversion: req.conversion(),
+                expected_version: req.expected_version(),
+            },
+        )
+    }
+
+    fn _get_client(&self, req: &GetClientRequest) -> Result<Client, ClientError> {
+        let connection = self.guard_connection()?;
+        find_client(&connection, req.client_id())
+    }
+
+    fn _debit_balance(&self, req: &DebitTransactionRequest) -> Result<Balance, ClientError> {
+        self.update_balance(
+            req.client_id(),
+            req.applied_amount(),
+            req.applied_currency(),
+            TransactionKind::Debit,
+            req.idempotency_key(),
+            TransactionOrigin {
+                account_id: req.account_id(),
+                reversal_of: req.reversal_of(),
+                conversion: req.conversion(),
+                expected_version: req.expected_version(),
+            },
+        )
+    }
+
+    fn _transfer(&self, req: &TransferRequest) -> Result<(Balance, Balance), ClientError> {
+        self.in_transaction(|connection| {
+            // Both clients are checked before touching any balance, so the error is the same one
+            // the in-memory repository reports. The origin funds are checked by the debit itself.
+            for client_id in [req.from_client_id(), req.to_client_id()] {
+                find_client(connection, client_id)?.ensure_operable()?;
+            }
+            let (from_balance, _) = apply_amount(
+                connection,
+                req.from_client_id(),
+                &-req.amount(),
+                req.currency(),
+                TransactionKind::TransferOut,
+                TransactionOrigin::default(),
+            )?;
+            let (to_balance, _) = apply_amount(
+                connection,
+                req.to_client_id(),
+                req.amount(),
+                req.currency(),
+                TransactionKind::TransferIn,
+                TransactionOrigin::default(),
+            )?;
+            Ok((from_balance, to_balance))
+        })
+    }
+
+    fn _apply_batch(&self, req: &BatchTransactionRequest) -> Result<Vec<Balance>, ClientError> {
+        // Every item is applied against the balances left by the previous ones, and the whole
+        // transaction is rolled back if any of them is rejected.
+        self.in_transaction(|connection| {
+            req.items()
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    apply_amount(
+                        connection,
+                        item.client_id(),
+                        item.amount(),
+                        item.currency(),
+                        item.kind(),
+                        TransactionOrigin::default(),
+                    )
+                    .map(|(balance, _)| balance)
+                    .map_err(|reason| ClientError::BatchItemRejected {
+                        index,
+                        reason: Box::new(reason),
+                    })
+                })
+                .collect()
+        })
+    }
+
+    fn _authorize(&self, req: &AuthorizeRequest) -> Result<Authorization, ClientError> {
+        self.in_transaction(|connection| {
+            let now = Utc::now();
+            let amount = Money::new(-req.amount(), req.currency().clone())?;
+            let held = held_amount(connection, req.client_id(), req.currency(), now)?;
+            let client = find_client(connection, req.client_id())?;
+            client.ensure_version(req.expected_version())?;
+            client.ensure_operable()?;
+            let main_account = resolve_account(connection, req.client_id(), None)?;
+            let balance = balance_of(connection, main_account.id(), req.currency())?;
+            client.ensure_funds_for(&balance.checked_sub(&held)?, &amount)?;
+            let authorization = Authorization::new(
+                AuthorizationId::new(&next_id(connection, "authorizations")?)?,
+                req.client_id().clone(),
+                *req.amount(),
+                now,
+                now + self.authorization_timeout,
+            )
+            .with_currency(req.currency().clone());
+            connection
+                .execute(
+                    &format!(
+                        "INSERT INTO authorizations ({AUTHORIZATION_COLUMNS}) \
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)"
+                    ),
+                    params![
+                        authorization.id().to_string(),
+                        authorization.client_id().to_string(),
+                        authorization.amount().to_string(),
+                        authorization.currency().to_string(),
+                        authorization.status().to_string(),
+                        stored_timestamp(authorization.created_at()),
+                        stored_timestamp(authorization.expires_at()),
+                        authorization.captured_amount().map(ToString::to_string),
+                    ],
+                )
+                .map_err(unknown)?;
+            let version = increase_version(connection, req.client_id())?;
+            Ok(authorization.with_version(version))
+        })
+    }
+
+    fn _capture_authorization(
+        &self,
+        req: &CaptureAuthorizationRequest,
+    ) -> Result<Balance, ClientError> {
+        self.in_transaction(|connection| {
+            let mut authorization = find_authorization(connection, req.authorization_id())?;
+            find_client(connection, authorization.client_id())?
+                .ensure_version(req.expected_version())?;
+            let amount = req.amount().copied().unwrap_or(*authorization.amount());
+            authorization.capture(amount, Utc::now())?;
+            // The hold is released before debiting, so the debit can spend the funds it reserved.
+            // If the debit fails, the transaction is rolled back and the hold is pending again.
+            save_authorization(connection, &authorization)?;
+            let (balance, _) = apply_amount(
+                connection,
+                authorization.client_id(),
+                &-amount,
+                authorization.currency(),
+                TransactionKind::Debit,
+                TransactionOrigin::default(),
+            )?;
+            Ok(balance)
+        })
+    }
+
+    fn _void_authorization(
+        &self,
+        authorization_id: &AuthorizationId,
+        expected_version: Option<Version>,
+    ) -> Result<Authorization, ClientError> {
+        self.in_transaction(|connection| {
+            let mut authorization = find_authorization(connection, authorization_id)?;
+            find_client(connection, authorization.client_id())?
+                .ensure_version(expected_version.as_ref())?;
+            authorization.void(Utc::now())?;
+            save_authorization(connection, &authorization)?;
+            let version = increase_version(connection, authorization.client_id())?;
+            Ok(authorization.with_version(version))
+        })
+    }
+
+    fn _get_balance_by_client_id(&self, req: &GetClientRequest) -> Result<Balance, ClientError> {
+        let connection = self.guard_connection()?;
+        let client = find_client(&connection, req.client_id())?;
+        let balance = total_balance(&connection, req.client_id(), req.currency())?;
+        let held = held_amount(&connection, req.client_id(), req.currency(), Utc::now())?;
+        Ok(Balance::new(req.client_id().clone(), *balance.amount())
+            .with_held(*held.amount())
+            .with_currency(req.currency().clone())
+            .with_version(*client.version()))
+    }
+
+    fn _get_balances_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<Balance>, ClientError> {
+        let connection = self.guard_connection()?;
+        let client = find_client(&connection, req.client_id())?;
+        client_balances(&connection, &client, Utc::now())
+    }
+
+    fn _get_accounts_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<(Account, Vec<Balance>)>, ClientError> {
+        let connection = self.guard_connection()?;
+        let client = find_client(&connection, req.client_id())?;
+        account_balances(&connection, &client, Utc::now())
+    }
+
+    fn _get_client_overview(&self, req: &GetClientRequest) -> Result<ClientOverview, ClientError> {
+        // Every write goes through the same connection, so nothing changes while it is held.
+        let connection = self.guard_connection()?;
+        let client = find_client(&connection, req.client_id())?;
+        let now = Utc::now();
+        let balances = client_balances(&connection, &client, now)?;
+        let accounts = account_balances(&connection, &client, now)?;
+        Ok(ClientOverview::new(client, balances, accounts))
+    }
+
+    fn _get_transactions_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<Transaction>, ClientError> {
+        let connection = self.guard_connection()?;
+        if !client_exists(&connection, req.client_id())? {
+            return Err(ClientError::NotFoundById {
+                id_document: req.client_id().clone(),
+            });
+        }
+        let mut statement = connection
+            .prepare(&format!(
+                "SELECT {TRANSACTION_COLUMNS} FROM transactions WHERE client_id = ?1 ORDER BY id"
+            ))
+            .map_err(unknown)?;
+        let rows = statement
+            .query_map(params![req.client_id().to_string()], TransactionRow::read)
+            .map_err(unknown)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(unknown)?;
+        rows.into_iter()
+            .map(TransactionRow::into_transaction)
+            .collect()
+    }
+
+    fn _get_transaction(&self, transaction_id: &TransactionId) -> Result<Transaction, ClientError> {
+        let connection = self.guard_connection()?;
+        find_transaction(&connection, transaction_id)
+    }
+
+    fn _change_client_status(
+        &self,
+        client_id: &ClientId,
+        status: ClientStatus,
+        expected_version: Option<Version>,
+    ) -> Result<Client, ClientError> {
+        self.in_transaction(|connection| {
+            let now = Utc::now();
+            let mut client = find_client(connection, client_id)?;
+            client.ensure_version(expected_version.as_ref())?;
+            client.change_status(status)?;
+            if status == ClientStatus::Closed {
+                let has_balance = total_balances(connection, client_id)?
+                    .values()
+                    .any(|balance| !balance.amount().is_zero());
+                let has_holds = !held_amounts_are_zero(connection, client_id, now)?;
+                if has_balance || has_holds {
+                    return Err(ClientError::BalanceNotZero {
+                        client_id: client_id.clone(),
+                    });
+                }
+            }
+            client.increase_version();
+            save_client(connection, &client)?;
+            Ok(client)
+        })
+    }
+
+    fn _reset_all_balances_to_zero(&self) -> Result<BalanceReset, ClientError> {
+        self.in_transaction(|connection| {
+            let mut statement = connection
+                .prepare(
+                    "SELECT accounts.client_id, balances.account_id, balances.currency, \
+                     balances.balance FROM balances \
+                     JOIN accounts ON accounts.id = balances.account_id \
+                     JOIN clients ON clients.id = accounts.client_id \
+                     WHERE clients.status != ?1 \
+                     ORDER BY accounts.client_id, balances.account_id, balances.currency",
+                )
+                .map_err(unknown)?;
+            let rows = statement
+                .query_map(params![ClientStatus::Closed.to_string()], |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, i64>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, String>(3)?,
+                    ))
+                })
+                .map_err(unknown)?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(unknown)?;
+            let now = Utc::now();
+            let old_balances = rows
+                .into_iter()
+                .map(|(client_id, account_id, currency, balance)| {
+                    let client_id = ClientId::new(&client_id)?;
+                    let account_id = AccountId::new(&account_id.to_string())?;
+                    let balance = Money::new(decimal(&balance)?, Currency::new(&currency)?)?;
+                    let zero = Money::zero(balance.currency().clone());
+                    insert_adjustment(connection, &client_id, &account_id, &balance, &zero, now)?;
+                    Ok(Balance::new(client_id, *balance.amount())
+                        .with_account_id(account_id)
+                        .with_currency(balance.currency().clone()))
+                })
+                .collect::<Result<Vec<_>, ClientError>>()?;
+            connection
+                .execute(
+                    "UPDATE balances SET balance = ?1 WHERE account_id IN \
+                     (SELECT accounts.id FROM accounts \
+                     JOIN clients ON clients.id = accounts.client_id WHERE clients.status != ?2)",
+                    params![Decimal::ZERO.to_string(), ClientStatus::Closed.to_string()],
+                )
+                .map_err(unknown)?;
+            // The funds pending authorizations reserve are gone, so they are released too.
+            let mut statement = connection
+                .prepare(
+                    "UPDATE authorizations SET status = ?1 WHERE status = ?2 AND expires_at > ?3 \
+                     AND client_id IN (SELECT id FROM clients WHERE status != ?4) RETURNING id",
+                )
+                .map_err(unknown)?;
+            let voided_authorizations = statement
+                .query_map(
+                    params![
+                        AuthorizationStatus::Voided.to_string(),
+                        AuthorizationStatus::Pending.to_string(),
+                        stored_timestamp(&now),
+                        ClientStatus::Closed.to_string(),
+                    ],
+                    |row| row.get::<_, i64>(0),
+                )
+                .map_err(unknown)?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(unknown)?
+                .into_iter()
+                .map(|id| AuthorizationId::new(&id.to_string()))
+                .collect::<Result<Vec<_>, _>>()?;
+            connection
+                .execute(
+                    "UPDATE clients SET version = version + 1 WHERE status != ?1",
+                    params![ClientStatus::Closed.to_string()],
+                )
+                .map_err(unknown)?;
+            Ok(BalanceReset::new(old_balances, voided_authorizations))
+        })
+    }
+
+    fn _are_balances_empty(&self) -> Result<bool, ClientError> {
+        let connection = self.guard_connection()?;
+        let any_open: bool = connection
+            .query_row(
+                "SELECT EXISTS (SELECT 1 FROM clients WHERE status != ?1)",
+                params![ClientStatus::Closed.to_string()],
+                |row| row.get(0),
+            )
+            .map_err(unknown)?;
+        Ok(!any_open)
+    }
+
+    fn _merge_old_balances(&self, reset: BalanceReset) -> Result<(), ClientError> {
+        // Every sum is written in the same transaction, so an overflow leaves the balances untouched.
+        self.in_transaction(|connection| {
+            let now = Utc::now();
+            for old_client_balance in reset.old_balances() {
+                if !client_exists(connection, old_client_balance.client_id())? {
+                    tracing::warn!(
+                        "client not found by id {} and balance of this client will be ignored...",
+                        old_client_balance.client_id()
+                    );
+                    continue;
+                }
+                let Ok(account) = resolve_account(
+                    connection,
+                    old_client_balance.client_id(),
+                    old_client_balance.account_id(),
+                ) else {
+                    tracing::warn!(
+                        "account not found for client {} and balance of this account will be ignored...",
+                        old_client_balance.client_id()
+                    );
+                    continue;
+                };
+                let old_balance = Money::new(
+                    *old_client_balance.balance(),
+                    old_client_balance.currency().clone(),
+                )?;
+                let balance = balance_of(connection, account.id(), old_client_balance.currency())?;
+                let merged_balance = balance.checked_add(&old_balance)?;
+                insert_adjustment(
+                    connection,
+                    old_client_balance.client_id(),
+                    account.id(),
+                    &balance,
+                    &merged_balance,
+                    now,
+                )?;
+                set_balance(connection, account.id(), &merged_balance)?;
+                increase_version(connection, old_client_balance.client_id())?;
+            }
+            // The holds are reinstated along with the funds they reserve, so they can still be
+            // captured or voided. Those that expired meanwhile are expired as usual.
+            for authorization_id in reset.voided_authorizations() {
+                connection
+                    .execute(
+                        "UPDATE authorizations SET status = ?1 WHERE id = ?2 AND status = ?3",
+                        params![
+                            AuthorizationStatus::Pending.to_string(),
+                            authorization_id.to_string(),
+                            AuthorizationStatus::Voided.to_string(),
+                        ],
+                    )
+                    .map_err(unknown)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl ClientBalanceRepository for SqliteRepository {
+    async fn create_client(&self, req: &CreateClientRequest) -> Result<Client, ClientError> {
+        self._create_client(req)
+    }
+
+    async fn create_account(&self, req: &CreateAccountRequest) -> Result<Account, ClientError> {
+        self._create_account(req)
+    }
+
+    async fn list_clients(&self, req: &ListClientsRequest) -> Result<ClientPage, ClientError> {
+        self._list_clients(req)
+    }
+
+    async fn update_client(&self, req: &UpdateClientRequest) -> Result<Client, ClientError> {
+        self._update_client(req)
+    }
+
+    async fn get_client_changes(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<ClientChange>, ClientError> {
+        self._get_client_changes(req)
+    }
+
+    async fn client_id_exists(&self, client_id: &ClientId) -> Result<bool, ClientError> {
+        self._client_id_exists(client_id)
+    }
+
+    async fn get_client_by_document(&self, document: &Document) -> Result<Client, ClientError> {
+        self._get_client_by_document(document)
+    }
+
+    async fn credit_balance(&self, req: &CreditTransactionRequest) -> Result<Balance, ClientError> {
+        self._credit_balance(req)
+    }
+
+    async fn get_client(&self, req: &GetClientRequest) -> Result<Client, ClientError> {
+        self._get_client(req)
+    }
+
+    async fn debit_balance(&self, req: &DebitTransactionRequest) -> Result<Balance, ClientError> {
+        self._debit_balance(req)
+    }
+
+    async fn transfer(&self, req: &TransferRequest) -> Result<(Balance, Balance), ClientError> {
+        self._transfer(req)
+    }
+
+    async fn apply_batch(
+        &self,
+        req: &BatchTransactionRequest,
+    ) -> Result<Vec<Balance>, ClientError> {
+        self._apply_batch(req)
+    }
+
+    async fn authorize(&self, req: &AuthorizeRequest) -> Result<Authorization, ClientError> {
+        self._authorize(req)
+    }
+
+    async fn capture_authorization(
+        &self,
+        req: &CaptureAuthorizationRequest,
+    ) -> Result<Balance, ClientError> {
+        self._capture_authorization(req)
+    }
+
+    async fn void_authorization(
+        &self,
+        authorization_id: &AuthorizationId,
+        expected_version: Option<Version>,
+    ) -> Result<Authorization, ClientError> {
+        self._void_authorization(authorization_id, expected_version)
+    }
+
+    async fn get_balance_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Balance, ClientError> {
+        self._get_balance_by_client_id(req)
+    }
+
+    async fn get_balances_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<Balance>, ClientError> {
+        self._get_balances_by_client_id(req)
+    }
+
+    async fn get_accounts_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<(Account, Vec<Balance>)>, ClientError> {
+        self._get_accounts_by_client_id(req)
+    }
+
+    async fn get_client_overview(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<ClientOverview, ClientError> {
+        self._get_client_overview(req)
+    }
+
+    async fn get_transactions_by_client_id(
+        &self,
+        req: &GetClientRequest,
+    ) -> Result<Vec<Transaction>, ClientError> {
+        self._get_transactions_by_client_id(req)
+    }
+
+    async fn get_transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> Result<Transaction, ClientError> {
+        self._get_transaction(transaction_id)
+    }
+
+    async fn change_client_status(
+        &self,
+        client_id: &ClientId,
+        status: ClientStatus,
+        expected_version: Option<Version>,
+    ) -> Result<Client, ClientError> {
+        self._change_client_status(client_id, status, expected_version)
+    }
+
+    async fn reset_all_balances_to_zero(&self) -> Result<BalanceReset, ClientError> {
+        self._reset_all_balances_to_zero()
+    }
+
+    async fn are_balances_empty(&self) -> Result<bool, ClientError> {
+        self._are_balances_empty()
+    }
+
+    async fn merge_old_balances(&self, reset: BalanceReset) -> Result<(), ClientError> {
+        self._merge_old_balances(reset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+    use crate::domain::model::dto::batch_transaction::BatchTransactionItem;
+
+    fn repository() -> SqliteRepository {
+        SqliteRepository::from_connection(Connection::open_in_memory().unwrap()).unwrap()
+    }
+
+    /// The same database opened again, with a new [SequentialIdGenerator] as after a restart.
+    fn reopen(repository: SqliteRepository) -> SqliteRepository {
+        SqliteRepository::from_connection(repository.connection.into_inner().unwrap()).unwrap()
+    }
+
+    fn create_client_request(document: &str) -> CreateClientRequest {
+        CreateClientRequest::new(
+            ClientName::new("John Doe").unwrap(),
+            BirthDate::new("1990-01-01").unwrap(),
+            Document::new(DocumentType::Dni, document).unwrap(),
+            Country::new("AR").unwrap(),
+        )
+    }
+
+    async fn create_clients(repository: &SqliteRepository) {
+        for document in ["12345678", "87654321"] {
+            repository
+                .create_client(&create_client_request(document))
+                .await
+                .unwrap();
+        }
+    }
+
+    fn client_id(id: &str) -> ClientId {
+        ClientId::new(id).unwrap()
+    }
+
+    fn credit(client_id: &str, amount: Decimal) -> CreditTransactionRequest {
+        CreditTransactionRequest::new(self::client_id(client_id), amount).unwrap()
+    }
+
+    fn debit(client_id: &str, amount: Decimal) -> DebitTransactionRequest {
+        DebitTransactionRequest::new(self::client_id(client_id), amount).unwrap()
+    }
+
+    async fn balance(repository: &SqliteRepository, client_id: &str) -> Balance {
+        repository
+            .get_balance_by_client_id(&GetClientRequest::new(self::client_id(client_id)))
+            .await
+            .unwrap()
+    }
+
+    async fn transactions(repository: &SqliteRepository, client_id: &str) -> Vec<Transaction> {
+        repository
+            .get_transactions_by_client_id(&GetClientRequest::new(self::client_id(client_id)))
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_01_given_a_new_database_when_opening_it_then_it_should_apply_every_migration_once() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        migrate(&mut connection).unwrap();
+        migrate(&mut connection).unwrap();
+
+        let applied: usize = connection
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .unwrap();
+        assert_eq!(applied, MIGRATIONS.len());
+        let sequences: i64 = connection
+            .query_row("SELECT COUNT(*) FROM id_sequences", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(sequences, 3);
+    }
+
+    #[test]
+    fn test_02_given_rows_stored_before_the_sequences_when_migrating_then_they_should_continue_from_them()
+     {
+        let mut connection = Connection::open_in_memory().unwrap();
+        connection.execute_batch(MIGRATIONS[0]).unwrap();
+        connection.pragma_update(None, "user_version", 1).unwrap();
+        connection
+            .execute_batch(
+                "INSERT INTO clients VALUES ('0', 'John Doe', '1990-01-01', 'DNI', '12345678', \
+                 'AR', '0', 'ACTIVE', NULL, NULL, 0); \
+                 INSERT INTO accounts VALUES (4, '0', 'Main', 1);",
+            )
+            .unwrap();
+
+        migrate(&mut connection).unwrap();
+
+        assert_eq!(next_id(&connection, "accounts").unwrap(), "5");
+        assert_eq!(next_id(&connection, "transactions").unwrap(), "0");
+        assert_eq!(next_id(&connection, "transactions").unwrap(), "1");
+    }
+
+    #[test]
+    fn test_03_given_a_database_from_a_newer_build_when_opening_it_then_it_should_fail() {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .pragma_update(None, "user_version", MIGRATIONS.len() + 1)
+            .unwrap();
+
+        let error = SqliteRepository::from_connection(connection).err().unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "The database is at migration {}, but this build only knows {}",
+                MIGRATIONS.len() + 1,
+                MIGRATIONS.len()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_04_given_a_retry_with_the_same_idempotency_key_when_crediting_then_it_should_return_the_first_balance()
+     {
+        let repository = repository();
+        create_clients(&repository).await;
+        let key = IdempotencyKey::new("key").unwrap();
+
+        let first = repository
+            .credit_balance(&credit("0", dec!(10)).with_idempotency_key(key.clone()))
+            .await
+            .unwrap();
+        let retry = repository
+            .credit_balance(&credit("0", dec!(10)).with_idempotency_key(key.clone()))
+            .await
+            .unwrap();
+        let conflict = repository
+            .credit_balance(&credit("0", dec!(20)).with_idempotency_key(key.clone()))
+            .await;
+
+        assert_eq!(retry, first);
+        assert_eq!(
+            conflict.unwrap_err(),
+            ClientError::IdempotencyKeyConflict { key }
+        );
+        assert_eq!(balance(&repository, "0").await.balance(), &dec!(10));
+        assert_eq!(transactions(&repository, "0").await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_05_given_a_credit_leg_that_overflows_when_transferring_then_it_should_roll_back_the_debit()
+     {
+        let repository = repository();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+        repository
+            .credit_balance(&credit("1", Decimal::MAX))
+            .await
+            .unwrap();
+
+        let result = repository
+            .transfer(&TransferRequest::new(client_id("0"), client_id("1"), dec!(1)).unwrap())
+            .await;
+
+        assert_eq!(result.unwrap_err(), ClientError::AmountOverflow);
+        assert_eq!(balance(&repository, "0").await.balance(), &dec!(10));
+        assert_eq!(balance(&repository, "1").await.balance(), &Decimal::MAX);
+        assert_eq!(transactions(&repository, "0").await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_06_given_a_rejected_batch_item_when_applying_the_batch_then_it_should_roll_back_the_previous_ones()
+     {
+        let repository = repository();
+        create_clients(&repository).await;
+
+        let result = repository
+            .apply_batch(
+                &BatchTransactionRequest::new(vec![
+                    BatchTransactionItem::from(credit("0", dec!(10))),
+                    BatchTransactionItem::from(debit("0", dec!(-4))),
+                    BatchTransactionItem::from(debit("1", dec!(-1))),
+                ])
+                .unwrap(),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ClientError::BatchItemRejected {
+                index: 2,
+                reason: Box::new(ClientError::InsufficientFunds {
+                    client_id: client_id("1"),
+                }),
+            }
+        );
+        assert_eq!(balance(&repository, "0").await.balance(), &dec!(0));
+        assert!(transactions(&repository, "0").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_07_given_a_hold_when_debiting_capturing_and_voiding_then_it_should_reserve_the_funds_until_released()
+     {
+        let repository = repository();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+        let hold = repository
+            .authorize(&AuthorizeRequest::new(client_id("0"), dec!(8)).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(balance(&repository, "0").await.held(), &dec!(8));
+        assert_eq!(
+            repository
+                .debit_balance(&debit("0", dec!(-3)))
+                .await
+                .unwrap_err(),
+            ClientError::InsufficientFunds {
+                client_id: client_id("0"),
+            }
+        );
+
+        let captured = repository
+            .capture_authorization(
+                &CaptureAuthorizationRequest::new(hold.id().clone(), Some(dec!(5))).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(captured.balance(), &dec!(5));
+        assert_eq!(balance(&repository, "0").await.held(), &dec!(0));
+
+        let hold = repository
+            .authorize(&AuthorizeRequest::new(client_id("0"), dec!(5)).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(balance(&repository, "0").await.held(), &dec!(5));
+        repository
+            .void_authorization(hold.id(), None)
+            .await
+            .unwrap();
+        assert_eq!(balance(&repository, "0").await.held(), &dec!(0));
+        assert_eq!(
+            repository
+                .debit_balance(&debit("0", dec!(-5)))
+                .await
+                .unwrap()
+                .balance(),
+            &dec!(0)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_08_given_a_reopened_database_when_creating_a_client_then_it_should_skip_the_stored_ids()
+     {
+        let repository = repository();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+
+        let repository = reopen(repository);
+        let client = repository
+            .create_client(&create_client_request("11111111"))
+            .await
+            .unwrap();
+        repository
+            .credit_balance(&credit("2", dec!(1)))
+            .await
+            .unwrap();
+
+        assert_eq!(client.id(), &client_id("2"));
+        let accounts = repository
+            .get_accounts_by_client_id(&GetClientRequest::new(client_id("2")))
+            .await
+            .unwrap();
+        assert_eq!(accounts[0].0.id().to_string(), "2");
+        assert_eq!(
+            transactions(&repository, "2").await[0].id().to_string(),
+            "1"
+        );
+        assert_eq!(balance(&repository, "0").await.balance(), &dec!(10));
+    }
+
+    #[tokio::test]
+    async fn test_09_given_a_pending_hold_when_resetting_the_balances_then_it_should_void_it() {
+        let repository = repository();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+        let hold = repository
+            .authorize(&AuthorizeRequest::new(client_id("0"), dec!(8)).unwrap())
+            .await
+            .unwrap();
+
+        repository.reset_all_balances_to_zero().await.unwrap();
+
+        let reset = balance(&repository, "0").await;
+        assert_eq!(reset.held(), &dec!(0));
+        assert_eq!(reset.available().unwrap(), dec!(0));
+        assert_eq!(
+            repository
+                .void_authorization(hold.id(), None)
+                .await
+                .unwrap_err(),
+            ClientError::AuthorizationNotPending {
+                authorization_id: hold.id().clone(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_10_given_a_reset_and_a_merge_when_listing_the_transactions_then_they_should_record_the_adjustments()
+     {
+        let repository = repository();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+
+        let old_balances = repository.reset_all_balances_to_zero().await.unwrap();
+        let reset = transactions(&repository, "0").await;
+        assert_eq!(reset.len(), 2);
+        assert_eq!(reset[1].kind(), &TransactionKind::Adjustment);
+        assert_eq!(reset[1].amount(), &dec!(-10));
+        assert_eq!(reset[1].resulting_balance(), &dec!(0));
+
+        repository.merge_old_balances(old_balances).await.unwrap();
+        let merged = transactions(&repository, "0").await;
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[2].kind(), &TransactionKind::Adjustment);
+        assert_eq!(merged[2].amount(), &dec!(10));
+        assert_eq!(merged[2].resulting_balance(), &dec!(10));
+        // A balance that does not change records no adjustment.
+        assert!(transactions(&repository, "1").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_11_given_a_failed_export_when_merging_the_old_balances_then_it_should_reinstate_the_holds()
+     {
+        let repository = repository();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+        let hold = repository
+            .authorize(&AuthorizeRequest::new(client_id("0"), dec!(8)).unwrap())
+            .await
+            .unwrap();
+        let reset = repository.reset_all_balances_to_zero().await.unwrap();
+        assert_eq!(reset.voided_authorizations(), &[hold.id().clone()]);
+
+        // What store_balances does when the export fails.
+        repository.merge_old_balances(reset).await.unwrap();
+
+        let restored = balance(&repository, "0").await;
+        assert_eq!(restored.balance(), &dec!(10));
+        assert_eq!(restored.held(), &dec!(8));
+        let captured = repository
+            .capture_authorization(
+                &CaptureAuthorizationRequest::new(hold.id().clone(), Some(dec!(8))).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(captured.balance(), &dec!(2));
+        assert_eq!(balance(&repository, "0").await.held(), &dec!(0));
+    }
+
+    #[tokio::test]
+    async fn test_12_given_a_client_when_getting_its_overview_then_the_balances_should_match_its_version()
+     {
+        let repository = repository();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+
+        let overview = repository
+            .get_client_overview(&GetClientRequest::new(client_id("0")))
+            .await
+            .unwrap();
+
+        let version = overview.client().version();
+        assert_eq!(version, &Version::new("2").unwrap());
+        assert_eq!(overview.balances()[0].balance(), &dec!(10));
+        assert_eq!(overview.balances()[0].version(), version);
+        let (account, balances) = &overview.accounts()[0];
+        assert!(account.is_main());
+        assert_eq!(balances[0].version(), version);
+    }
+
+    #[tokio::test]
+    async fn test_13_given_a_stale_version_when_authorizing_or_voiding_then_it_should_reject_it() {
+        let repository = repository();
+        create_clients(&repository).await;
+        repository
+            .credit_balance(&credit("0", dec!(10)))
+            .await
+            .unwrap();
+        let read_version = Version::new("2").unwrap();
+
+        assert_eq!(
+            repository
+                .authorize(
+                    &AuthorizeRequest::new(client_id("0"), dec!(8))
+                        .unwrap()
+                        .with_expected_version(Version::new("1").unwrap()),
+                )
+                .await
+                .unwrap_err(),
+            ClientError::VersionConflict {
+                client_id: client_id("0"),
+                expected: Version::new("1").unwrap(),
+                actual: read_version,
+            }
+        );
+        let hold = repository
+            .authorize(
+                &AuthorizeRequest::new(client_id("0"), dec!(8))
+                    .unwrap()
+                    .with_expected_version(read_version),
+            )
+            .await
+            .unwrap();
+        assert_eq!(hold.version(), &read_version.next());
+
+        assert_eq!(
+            repository
+                .void_authorization(hold.id(), Some(read_version))
+                .await
+                .unwrap_err(),
+            ClientError::VersionConflict {
+                client_id: client_id("0"),
+                expected: read_version,
+                actual: read_version.next(),
+            }
+        );
+        let voided = repository
+            .void_authorization(hold.id(), Some(*hold.version()))
+            .await
+            .unwrap();
+        assert_eq!(voided.version(), &read_version.next().next());
+    }
+
+    #[tokio::test]
+    async fn test_14_given_a_client_with_an_email_when_removing_it_then_it_should_record_the_change()
+     {
+        let repository = repository();
+        create_clients(&repository).await;
+        repository
+            .update_client(
+                &UpdateClientRequest::new(client_id("0"))
+                    .with_email(Email::new("john@example.com").unwrap()),
+            )
+            .await
+            .unwrap();
+
+        let client = repository
+            .update_client(&UpdateClientRequest::new(client_id("0")).without_email())
+            .await
+            .unwrap();
+
+        assert_eq!(client.email(), None);
+        let overview = repository
+            .get_client_overview(&GetClientRequest::new(client_id("0")))
+            .await
+            .unwrap();
+        assert_eq!(overview.client().email(), None);
+        let changes = repository
+            .get_client_changes(&GetClientRequest::new(client_id("0")))
+            .await
+            .unwrap();
+        let removal = changes.last().unwrap();
+        assert_eq!(removal.field(), ClientField::Email);
+        assert_eq!(removal.old_value(), "john@example.com");
+        assert_eq!(removal.new_value(), "");
+    }
+}
@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::port::outbound::clock::Clock;
+
+/// A [Clock] that reads the time of the system.
+#[derive(Debug, Clone, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -0,0 +1,1202 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex, Weak},
+    time::Duration,
+};
+
+use anyhow::{Context, bail};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::model::{
+    entity::{
+        account::Account, authorization::Authorization, balance::Balance, client::Client,
+        client_change::ClientChange, conversion::Conversion, idempotency_record::IdempotencyRecord,
+        transaction::Transaction,
+    },
+    error::ClientError,
+    value::{
+        account_id::AccountId, account_name::AccountName, authorization_id::AuthorizationId,
+        authorization_status::AuthorizationStatus, birth_date::BirthDate,
+        client_field::ClientField, client_id::ClientId, client_name::ClientName,
+        client_status::ClientStatus, country::Country, currency::Currency, document::Document,
+        document_type::DocumentType, email::Email, idempotency_key::IdempotencyKey, money::Money,
+        overdraft_limit::OverdraftLimit, phone_number::PhoneNumber, transaction_id::TransactionId,
+        transaction_kind::TransactionKind, version::Version,
+    },
+};
+
+#[allow(unused_imports)]
+use crate::infrastructure::outbound::in_memory::InMemoryRepository;
+
+const LOG_FILE_NAME: &str = "wal.log";
+const SNAPSHOT_FILE_NAME: &str = "snapshot.json";
+const DEFAULT_FSYNC_INTERVAL_MILLIS: u64 = 1000;
+const DEFAULT_SNAPSHOT_EVERY: u64 = 1000;
+
+/// When the appended entries are flushed to the disk, chosen with `WAL_FSYNC`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FsyncPolicy {
+    /// Before acknowledging every mutation. Nothing acknowledged is ever lost.
+    #[default]
+    Always,
+    /// In the background, every `WAL_FSYNC_INTERVAL_MS`. A crash of the machine, not only of the
+    /// process, can lose the mutations of the last interval.
+    Batched,
+    /// Left to the operating system. A crash of the process loses nothing, one of the machine can.
+    Never,
+}
+
+impl FromStr for FsyncPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(policy: &str) -> Result<Self, Self::Err> {
+        match policy.trim().to_lowercase().as_str() {
+            "always" => Ok(Self::Always),
+            "batched" => Ok(Self::Batched),
+            "never" => Ok(Self::Never),
+            _ => bail!("WAL_FSYNC must be always, batched or never, got \"{policy}\""),
+        }
+    }
+}
+
+/// A change to the state of the [InMemoryRepository]. Entries describe what changed rather than
+/// the request that changed it, so replaying them needs no clock, exchange rate or id generator.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LogEntry {
+    /// A new [Client] along with its main [Account].
+    ClientCreated {
+        client: Client,
+        main_account: Account,
+    },
+    /// Any later change to a [Client], its version included.
+    ClientSaved(Client),
+    AccountOpened(Account),
+    BalanceSet {
+        client_id: ClientId,
+        account_id: AccountId,
+        balance: Money,
+    },
+    TransactionAppended(Transaction),
+    IdempotencyRecorded(IdempotencyRecord),
+    /// A new [Authorization] or a change of its status.
+    AuthorizationSaved(Authorization),
+    ClientChanged(ClientChange),
+}
+
+impl LogEntry {
+    /// The [Client] the entry changes, or the one the idempotency record belongs to.
+    pub fn client_id(&self) -> &ClientId {
+        match self {
+            Self::ClientCreated { client, .. } | Self::ClientSaved(client) => client.id(),
+            Self::AccountOpened(account) => account.client_id(),
+            Self::BalanceSet { client_id, .. } => client_id,
+            Self::TransactionAppended(transaction) => transaction.client_id(),
+            Self::IdempotencyRecorded(record) => record.client_id(),
+            Self::AuthorizationSaved(authorization) => authorization.client_id(),
+            Self::ClientChanged(change) => change.client_id(),
+        }
+    }
+}
+
+/// The entries of one mutation, appended as a single line so it is replayed whole or not at all.
+/// A snapshot is a single batch with every entry needed to rebuild the state.
+#[derive(Serialize, Deserialize)]
+struct StoredBatch {
+    /// Grows with every batch. A snapshot keeps the one of the last batch it includes.
+    sequence: u64,
+    entries: Vec<StoredEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum StoredEntry {
+    ClientCreated {
+        client: StoredClient,
+        main_account_id: String,
+    },
+    ClientSaved {
+        client: StoredClient,
+    },
+    AccountOpened {
+        account: StoredAccount,
+    },
+    BalanceSet {
+        client_id: String,
+        account_id: String,
+        currency: String,
+        balance: Decimal,
+    },
+    TransactionAppended {
+        transaction: StoredTransaction,
+    },
+    IdempotencyRecorded {
+        record: StoredIdempotencyRecord,
+    },
+    AuthorizationSaved {
+        authorization: StoredAuthorization,
+    },
+    ClientChanged {
+        change: StoredClientChange,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredClient {
+    id: String,
+    name: String,
+    birth_date: String,
+    document_type: String,
+    document: String,
+    country: String,
+    overdraft_limit: String,
+    status: String,
+    email: Option<String>,
+    phone: Option<String>,
+    version: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredAccount {
+    id: String,
+    client_id: String,
+    name: String,
+    main: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredConversion {
+    original_amount: Decimal,
+    original_currency: String,
+    rate: Decimal,
+    spread: Decimal,
+    applied_rate: Decimal,
+    converted_amount: Decimal,
+    converted_currency: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredTransaction {
+    id: String,
+    client_id: String,
+    account_id: Option<String>,
+    amount: Decimal,
+    currency: String,
+    kind: String,
+    created_at: DateTime<Utc>,
+    resulting_balance: Decimal,
+    reversal_of: Option<String>,
+    conversion: Option<StoredConversion>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredBalance {
+    client_id: String,
+    account_id: Option<String>,
+    balance: Decimal,
+    held: Decimal,
+    currency: String,
+    conversion: Option<StoredConversion>,
+    version: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredIdempotencyRecord {
+    key: String,
+    client_id: String,
+    amount: Decimal,
+    currency: String,
+    kind: String,
+    balance: StoredBalance,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredAuthorization {
+    id: String,
+    client_id: String,
+    amount: Decimal,
+    currency: String,
+    status: String,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    captured_amount: Option<Decimal>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredClientChange {
+    client_id: String,
+    field: String,
+    old_value: String,
+    new_value: String,
+    changed_at: DateTime<Utc>,
+}
+
+impl From<&Client> for StoredClient {
+    fn from(client: &Client) -> Self {
+        Self {
+            id: client.id().to_string(),
+            name: client.name().to_string(),
+            birth_date: client.birth_date().to_string(),
+            document_type: client.document().document_type().to_string(),
+            document: client.document().number().to_string(),
+            country: client.country().to_string(),
+            overdraft_limit: client.overdraft_limit().to_string(),
+            status: client.status().to_string(),
+            email: client.email().map(ToString::to_string),
+            phone: client.phone().map(ToString::to_string),
+            version: client.version().to_string(),
+        }
+    }
+}
+
+impl TryFrom<StoredClient> for Client {
+    type Error = ClientError;
+    fn try_from(stored: StoredClient) -> Result<Self, Self::Error> {
+        let document = Document::new(DocumentType::new(&stored.document_type)?, &stored.document)?;
+        let overdraft_limit = stored.overdraft_limit.parse::<Decimal>().with_context(|| {
+            format!("Invalid stored overdraft limit: {}", stored.overdraft_limit)
+        })?;
+        Ok(Client::new(
+            ClientId::new(&stored.id)?,
+            ClientName::restore(&stored.name),
+            BirthDate::new(&stored.birth_date)?,
+            document,
+            Country::restore(&stored.country),
+        )
+        .with_overdraft_limit(OverdraftLimit::new(overdraft_limit)?)
+        .with_email(stored.email.as_deref().map(Email::restore))
+        .with_phone(stored.phone.as_deref().map(PhoneNumber::restore))
+        .with_status(ClientStatus::new(&stored.status)?)
+        .with_version(Version::new(&stored.version)?))
+    }
+}
+
+impl From<&Account> for StoredAccount {
+    fn from(account: &Account) -> Self {
+        Self {
+            id: account.id().to_string(),
+            client_id: account.client_id().to_string(),
+            name: account.name().to_string(),
+            main: account.is_main(),
+        }
+    }
+}
+
+impl TryFrom<StoredAccount> for Account {
+    type Error = ClientError;
+    fn try_from(stored: StoredAccount) -> Result<Self, Self::Error> {
+        let id = AccountId::new(&stored.id)?;
+        let client_id = ClientId::new(&stored.client_id)?;
+        Ok(match stored.main {
+            true => Account::main(id, client_id),
+            false => Account::new(id, client_id, AccountName::new(&stored.name)?),
+        })
+    }
+}
+
+impl From<&Conversion> for StoredConversion {
+    fn from(conversion: &Conversion) -> Self {
+        Self {
+            original_amount: *conversion.original_amount(),
+            original_currency: conversion.original_currency().to_string(),
+            rate: *conversion.rate(),
+            spread: *conversion.spread(),
+            applied_rate: *conversion.applied_rate(),
+            converted_amount: *conversion.converted_amount(),
+            converted_currency: conversion.converted_currency().to_string(),
+        }
+    }
+}
+
+impl TryFrom<StoredConversion> for Conversion {
+    type Error = ClientError;
+    fn try_from(stored: StoredConversion) -> Result<Self, Self::Error> {
+        Ok(Conversion::new(
+            stored.original_amount,
+            Currency::new(&stored.original_currency)?,
+            stored.rate,
+            stored.spread,
+            stored.applied_rate,
+            stored.converted_amount,
+            Currency::new(&stored.converted_currency)?,
+        ))
+    }
+}
+
+impl From<&Transaction> for StoredTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        Self {
+            id: transaction.id().to_string(),
+            client_id: transaction.client_id().to_string(),
+            account_id: transaction.account_id().map(ToString::to_string),
+            amount: *transaction.amount(),
+            currency: transaction.currency().to_string(),
+            kind: transaction.kind().to_string(),
+            created_at: *transaction.created_at(),
+            resulting_balance: *transaction.resulting_balance(),
+            reversal_of: transaction.reversal_of().map(ToString::to_string),
+            conversion: transaction.conversion().map(StoredConversion::from),
+        }
+    }
+}
+
+impl TryFrom<StoredTransaction> for Transaction {
+    type Error = ClientError;
+    fn try_from(stored: StoredTransaction) -> Result<Self, Self::Error> {
+        let mut transaction = Transaction::new(
+            TransactionId::new(&stored.id)?,
+            ClientId::new(&stored.client_id)?,
+            stored.amount,
+            TransactionKind::new(&stored.kind)?,
+            stored.created_at,
+            stored.resulting_balance,
+        )
+        .with_currency(Currency::new(&stored.currency)?);
+        if let Some(account_id) = stored.account_id {
+            transaction = transaction.with_account_id(AccountId::new(&account_id)?);
+        }
+        if let Some(reversal_of) = stored.reversal_of {
+            transaction = transaction.with_reversal_of(TransactionId::new(&reversal_of)?);
+        }
+        if let Some(conversion) = stored.conversion {
+            transaction = transaction.with_conversion(conversion.try_into()?);
+        }
+        Ok(transaction)
+    }
+}
+
+impl From<&Balance> for StoredBalance {
+    fn from(balance: &Balance) -> Self {
+        Self {
+            client_id: balance.client_id().to_string(),
+            account_id: balance.account_id().map(ToString::to_string),
+            balance: *balance.balance(),
+            held: *balance.held(),
+            currency: balance.currency().to_string(),
+            conversion: balance.conversion().map(StoredConversion::from),
+            version: balance.version().to_string(),
+        }
+    }
+}
+
+impl TryFrom<StoredBalance> for Balance {
+    type Error = ClientError;
+    fn try_from(stored: StoredBalance) -> Result<Self, Self::Error> {
+        let mut balance = Balance::new(ClientId::new(&stored.client_id)?, stored.balance)
+            .with_held(stored.held)
+            .with_currency(Currency::new(&stored.currency)?)
+            .with_version(Version::new(&stored.version)?);
+        if let Some(account_id) = stored.account_id {
+            balance = balance.with_account_id(AccountId::new(&account_id)?);
+        }
+        if let Some(conversion) = stored.conversion {
+            balance = balance.with_conversion(conversion.try_into()?);
+        }
+        Ok(balance)
+    }
+}
+
+impl From<&IdempotencyRecord> for StoredIdempotencyRecord {
+    fn from(record: &IdempotencyRecord) -> Self {
+        Self {
+            key: record.key().to_string(),
+            client_id: record.client_id().to_string(),
+            amount: *record.amount(),
+            currency: record.currency().to_string(),
+            kind: record.kind().to_string(),
+            balance: record.balance().into(),
+            created_at: *record.created_at(),
+        }
+    }
+}
+
+impl TryFrom<StoredIdempotencyRecord> for IdempotencyRecord {
+    type Error = ClientError;
+    fn try_from(stored: StoredIdempotencyRecord) -> Result<Self, Self::Error> {
+        Ok(IdempotencyRecord::new(
+            IdempotencyKey::new(&stored.key)?,
+            ClientId::new(&stored.client_id)?,
+            stored.amount,
+            Currency::new(&stored.currency)?,
+            TransactionKind::new(&stored.kind)?,
+            stored.balance.try_into()?,
+            stored.created_at,
+        ))
+    }
+}
+
+impl From<&Authorization> for StoredAuthorization {
+    fn from(authorization: &Authorization) -> Self {
+        Self {
+            id: authorization.id().to_string(),
+            client_id: authorization.client_id().to_string(),
+            amount: *authorization.amount(),
+            currency: authorization.currency().to_string(),
+            status: authorization.status().to_string(),
+            created_at: *authorization.created_at(),
+            expires_at: *authorization.expires_at(),
+            captured_amount: authorization.captured_amount().copied(),
+        }
+    }
+}
+
+impl TryFrom<StoredAuthorization> for Authorization {
+    type Error = ClientError;
+    fn try_from(stored: StoredAuthorization) -> Result<Self, Self::Error> {
+        Ok(Authorization::new(
+            AuthorizationId::new(&stored.id)?,
+            ClientId::new(&stored.client_id)?,
+            stored.amount,
+            stored.created_at,
+            stored.expires_at,
+        )
+        .with_currency(Currency::new(&stored.currency)?)
+        .with_status(
+            AuthorizationStatus::new(&stored.status)?,
+            stored.captured_amount,
+        ))
+    }
+}
+
+impl From<&ClientChange> for StoredClientChange {
+    fn from(change: &ClientChange) -> Self {
+        Self {
+            client_id: change.client_id().to_string(),
+            field: change.field().to_string(),
+            old_value: change.old_value().to_string(),
+            new_value: change.new_value().to_string(),
+            changed_at: *change.changed_at(),
+        }
+    }
+}
+
+impl TryFrom<StoredClientChange> for ClientChange {
+    type Error = ClientError;
+    fn try_from(stored: StoredClientChange) -> Result<Self, Self::Error> {
+        Ok(ClientChange::new(
+            ClientId::new(&stored.client_id)?,
+            ClientField::new(&stored.field)?,
+            stored.old_value,
+            stored.new_value,
+            stored.changed_at,
+        ))
+    }
+}
+
+impl From<&LogEntry> for StoredEntry {
+    fn from(entry: &LogEntry) -> Self {
+        match entry {
+            LogEntry::ClientCreated {
+                client,
+                main_account,
+            } => StoredEntry::ClientCreated {
+                client: client.into(),
+                main_account_id: main_account.id().to_string(),
+            },
+            LogEntry::ClientSaved(client) => StoredEntry::ClientSaved {
+                client: client.into(),
+            },
+            LogEntry::AccountOpened(account) => StoredEntry::AccountOpened {
+                account: account.into(),
+            },
+            LogEntry::BalanceSet {
+                client_id,
+                account_id,
+                balance,
+            } => StoredEntry::BalanceSet {
+                client_id: client_id.to_string(),
+                account_id: account_id.to_string(),
+                currency: balance.currency().to_string(),
+                balance: *balance.amount(),
+            },
+            LogEntry::TransactionAppended(transaction) => StoredEntry::TransactionAppended {
+                transaction: transaction.into(),
+            },
+            LogEntry::IdempotencyRecorded(record) => StoredEntry::IdempotencyRecorded {
+                record: record.into(),
+            },
+            LogEntry::AuthorizationSaved(authorization) => StoredEntry::AuthorizationSaved {
+                authorization: authorization.into(),
+            },
+            LogEntry::ClientChanged(change) => StoredEntry::ClientChanged {
+                change: change.into(),
+            },
+        }
+    }
+}
+
+impl TryFrom<StoredEntry> for LogEntry {
+    type Error = ClientError;
+    fn try_from(stored: StoredEntry) -> Result<Self, Self::Error> {
+        Ok(match stored {
+            StoredEntry::ClientCreated {
+                client,
+                main_account_id,
+            } => {
+                let client = Client::try_from(client)?;
+                let main_account =
+                    Account::main(AccountId::new(&main_account_id)?, client.id().clone());
+                LogEntry::ClientCreated {
+                    client,
+                    main_account,
+                }
+            }
+            StoredEntry::ClientSaved { client } => LogEntry::ClientSaved(client.try_into()?),
+            StoredEntry::AccountOpened { account } => LogEntry::AccountOpened(account.try_into()?),
+            StoredEntry::BalanceSet {
+                client_id,
+                account_id,
+                currency,
+                balance,
+            } => LogEntry::BalanceSet {
+                client_id: ClientId::new(&client_id)?,
+                account_id: AccountId::new(&account_id)?,
+                balance: Money::restore(balance, Currency::new(&currency)?),
+            },
+            StoredEntry::TransactionAppended { transaction } => {
+                LogEntry::TransactionAppended(transaction.try_into()?)
+            }
+            StoredEntry::IdempotencyRecorded { record } => {
+                LogEntry::IdempotencyRecorded(record.try_into()?)
+            }
+            StoredEntry::AuthorizationSaved { authorization } => {
+                LogEntry::AuthorizationSaved(authorization.try_into()?)
+            }
+            StoredEntry::ClientChanged { change } => LogEntry::ClientChanged(change.try_into()?),
+        })
+    }
+}
+
+impl StoredBatch {
+    fn new(sequence: u64, entries: &[LogEntry]) -> Self {
+        Self {
+            sequence,
+            entries: entries.iter().map(StoredEntry::from).collect(),
+        }
+    }
+
+    /// The entries rebuilt as they were stored, even if the current rules would reject them now.
+    fn into_entries(self) -> Result<Vec<LogEntry>, anyhow::Error> {
+        let sequence = self.sequence;
+        self.entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                LogEntry::try_from(entry).with_context(|| {
+                    format!("Invalid entry {index} of write-ahead log batch {sequence}")
+                })
+            })
+            .collect()
+    }
+}
+
+/// The log file, shared with the thread that flushes it when the [FsyncPolicy] is batched.
+struct LogFile {
+    file: File,
+    /// Whether something was written since the last flush.
+    unsynced: bool,
+}
+
+/// A write-ahead log kept in a directory: `wal.log` with a line per mutation since the last
+/// snapshot, and `snapshot.json` with the compacted state up to it.
+pub struct WriteAheadLog {
+    directory: PathBuf,
+    log_file: Arc<Mutex<LogFile>>,
+    fsync_policy: FsyncPolicy,
+    /// How many batches are appended before compacting them into a new snapshot.
+    snapshot_every: u64,
+    /// The sequence of the last appended batch.
+    sequence: u64,
+    appended_since_snapshot: u64,
+    /// Set when a write fails. The state in memory is then ahead of the disk, so no further
+    /// mutation is accepted until a restart replays the log.
+    failed: bool,
+}
+
+impl WriteAheadLog {
+    /// Opens the log kept in the directory, creating both if missing.
+    pub fn open(
+        directory: &Path,
+        fsync_policy: FsyncPolicy,
+        fsync_interval: Duration,
+        snapshot_every: u64,
+    ) -> Result<Self, anyhow::Error> {
+        fs::create_dir_all(directory).with_context(|| {
+            format!(
+                "Error creating write-ahead log directory: {}",
+                directory.display()
+            )
+        })?;
+        let path = directory.join(LOG_FILE_NAME);
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Error opening write-ahead log: {}", path.display()))?;
+        let log_file = Arc::new(Mutex::new(LogFile {
+            file,
+            unsynced: false,
+        }));
+        if fsync_policy == FsyncPolicy::Batched {
+            Self::spawn_batched_sync(Arc::downgrade(&log_file), fsync_interval);
+        }
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            log_file,
+            fsync_policy,
+            snapshot_every: snapshot_every.max(1),
+            sequence: 0,
+            appended_since_snapshot: 0,
+            failed: false,
+        })
+    }
+
+    /// Opens the log in the directory set in `WAL_DIR`, or none if unset. The policy is set in
+    /// `WAL_FSYNC`, `always` by default, and the snapshots are taken every `WAL_SNAPSHOT_EVERY`
+    /// mutations, 1000 by default.
+    pub fn from_env() -> Result<Option<Self>, anyhow::Error> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Like [Self::from_env], with the variables read through the given function.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Option<Self>, anyhow::Error> {
+        let Some(directory) = var("WAL_DIR") else {
+            return Ok(None);
+        };
+        let fsync_policy = match var("WAL_FSYNC") {
+            Some(policy) => policy.parse()?,
+            None => FsyncPolicy::default(),
+        };
+        let fsync_interval = match var("WAL_FSYNC_INTERVAL_MS") {
+            Some(millis) => millis
+                .trim()
+                .parse::<u64>()
+                .context("WAL_FSYNC_INTERVAL_MS must be a number")?,
+            None => DEFAULT_FSYNC_INTERVAL_MILLIS,
+        };
+        let snapshot_every = match var("WAL_SNAPSHOT_EVERY") {
+            Some(every) => every
+                .trim()
+                .parse::<u64>()
+                .context("WAL_SNAPSHOT_EVERY must be a number")?,
+            None => DEFAULT_SNAPSHOT_EVERY,
+        };
+        Self::open(
+            Path::new(&directory),
+            fsync_policy,
+            Duration::from_millis(fsync_interval),
+            snapshot_every,
+        )
+        .map(Some)
+    }
+
+    fn spawn_batched_sync(log_file: Weak<Mutex<LogFile>>, interval: Duration) {
+        std::thread::spawn(move || {
+            loop {
+                std::thread::sleep(interval);
+                // The log was dropped along with its repository.
+                let Some(log_file) = log_file.upgrade() else {
+                    break;
+                };
+                let Ok(mut log_file) = log_file.lock() else {
+                    break;
+                };
+                if log_file.unsynced {
+                    match log_file.file.sync_data() {
+                        Ok(()) => log_file.unsynced = false,
+                        Err(e) => tracing::error!("Error flushing write-ahead log: {e}"),
+                    }
+                }
+            }
+        });
+    }
+
+    fn guard_log_file(&self) -> Result<std::sync::MutexGuard<'_, LogFile>, anyhow::Error> {
+        match self.log_file.lock() {
+            Ok(lock) => Ok(lock),
+            Err(e) => Err(anyhow::anyhow!("Poisoned lock on write-ahead log: {}", e)),
+        }
+    }
+
+    /// Reads the entries of the snapshot followed by the ones logged after it, in the order they
+    /// have to be applied. A last line cut by a crash while it was written was never acknowledged,
+    /// so it is dropped.
+    pub fn replay(&mut self) -> Result<Vec<LogEntry>, anyhow::Error> {
+        let mut entries = Vec::new();
+        let snapshot_path = self.directory.join(SNAPSHOT_FILE_NAME);
+        if snapshot_path.exists() {
+            let snapshot = fs::read_to_string(&snapshot_path)
+                .with_context(|| format!("Error reading snapshot: {}", snapshot_path.display()))?;
+            let batch: StoredBatch = serde_json::from_str(&snapshot)
+                .with_context(|| format!("Invalid snapshot: {}", snapshot_path.display()))?;
+            self.sequence = batch.sequence;
+            entries.extend(batch.into_entries()?);
+        }
+
+        let log_path = self.directory.join(LOG_FILE_NAME);
+        let mut lines = BufReader::new(File::open(&log_path)?).lines().peekable();
+        let mut valid_length = 0;
+        while let Some(line) = lines.next() {
+            let line = line?;
+            let batch = match serde_json::from_str::<StoredBatch>(&line) {
+                Ok(batch) => batch,
+                Err(_) if lines.peek().is_none() => {
+                    tracing::warn!("Dropping the incomplete last line of the write-ahead log");
+                    self.guard_log_file()?.file.set_len(valid_length)?;
+                    break;
+                }
+                Err(e) => {
+                    return Err(e).context(format!(
+                        "Invalid write-ahead log line after sequence {}",
+                        self.sequence
+                    ));
+                }
+            };
+            valid_length += line.len() as u64 + 1;
+            // A crash between writing a snapshot and truncating the log leaves batches it
+            // already includes.
+            if batch.sequence <= self.sequence {
+                continue;
+            }
+            self.sequence = batch.sequence;
+            self.appended_since_snapshot += 1;
+            entries.extend(batch.into_entries()?);
+        }
+        Ok(entries)
+    }
+
+    /// Appends the entries of a mutation as a single batch, flushed to the disk if the
+    /// [FsyncPolicy] is `always`.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::Unknown] if the log cannot be written, or a previous write failed.
+    pub fn append(&mut self, entries: &[LogEntry]) -> Result<(), ClientError> {
+        self.ensure_writable()?;
+        let mut line = serde_json::to_string(&StoredBatch::new(self.sequence + 1, entries))
+            .context("Error serializing write-ahead log entries")?;
+        line.push('\n');
+        let written = self.guard_log_file().and_then(|mut log_file| {
+            log_file.file.write_all(line.as_bytes())?;
+            match self.fsync_policy {
+                FsyncPolicy::Always => log_file.file.sync_data()?,
+                FsyncPolicy::Batched => log_file.unsynced = true,
+                FsyncPolicy::Never => {}
+            }
+            Ok(())
+        });
+        if let Err(e) = written {
+            self.failed = true;
+            return Err(e.context("Error appending to write-ahead log").into());
+        }
+        self.sequence += 1;
+        self.appended_since_snapshot += 1;
+        Ok(())
+    }
+
+    /// Checks that no previous write failed, so a mutation can be applied and logged.
+    pub fn ensure_writable(&self) -> Result<(), ClientError> {
+        if self.failed {
+            return Err(anyhow::anyhow!(
+                "The write-ahead log failed, restart to recover the state from it"
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Returns if enough batches were appended since the last snapshot to take a new one.
+    pub fn is_snapshot_due(&self) -> bool {
+        self.appended_since_snapshot >= self.snapshot_every
+    }
+
+    /// Replaces the snapshot with the given entries, the whole state up to the last appended
+    /// batch, and empties the log. The snapshot is always flushed before the log is emptied,
+    /// whatever the [FsyncPolicy], as it is the only copy left of what the log had.
+    pub fn snapshot(&mut self, entries: &[LogEntry]) -> Result<(), anyhow::Error> {
+        let snapshot = serde_json::to_string(&StoredBatch::new(self.sequence, entries))?;
+        let path = self.directory.join(SNAPSHOT_FILE_NAME);
+        let temporary_path = path.with_extension("json.tmp");
+        let mut file = File::create(&temporary_path)?;
+        file.write_all(snapshot.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temporary_path, &path)?;
+        File::open(&self.directory)?.sync_all()?;
+
+        {
+            let mut log_file = self.guard_log_file()?;
+            log_file.file.set_len(0)?;
+            log_file.file.sync_all()?;
+            log_file.unsynced = false;
+        }
+        self.appended_since_snapshot = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rust_decimal::dec;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::domain::model::value::MAX_LENGTH_NAME;
+
+    fn open(directory: &TempDir, snapshot_every: u64) -> WriteAheadLog {
+        WriteAheadLog::open(
+            directory.path(),
+            FsyncPolicy::Always,
+            Duration::from_millis(DEFAULT_FSYNC_INTERVAL_MILLIS),
+            snapshot_every,
+        )
+        .unwrap()
+    }
+
+    /// An entry told apart from the others by its balance.
+    fn entry(balance: Decimal) -> LogEntry {
+        LogEntry::BalanceSet {
+            client_id: ClientId::new("0").unwrap(),
+            account_id: AccountId::new("0").unwrap(),
+            balance: Money::new(balance, Currency::default()).unwrap(),
+        }
+    }
+
+    fn log_contents(directory: &TempDir) -> String {
+        fs::read_to_string(directory.path().join(LOG_FILE_NAME)).unwrap()
+    }
+
+    #[test]
+    fn test_01_given_appended_batches_when_replaying_then_it_should_return_their_entries_in_order()
+    {
+        let directory = TempDir::new().unwrap();
+        let mut write_ahead_log = open(&directory, 10);
+        assert_eq!(write_ahead_log.replay().unwrap(), vec![]);
+        write_ahead_log
+            .append(&[entry(dec!(1)), entry(dec!(2))])
+            .unwrap();
+        write_ahead_log.append(&[entry(dec!(3))]).unwrap();
+        drop(write_ahead_log);
+
+        let mut write_ahead_log = open(&directory, 10);
+        assert_eq!(
+            write_ahead_log.replay().unwrap(),
+            vec![entry(dec!(1)), entry(dec!(2)), entry(dec!(3))]
+        );
+        // The sequence continues from the last batch replayed.
+        write_ahead_log.append(&[entry(dec!(4))]).unwrap();
+        assert!(
+            log_contents(&directory)
+                .lines()
+                .last()
+                .unwrap()
+                .contains("\"sequence\":3")
+        );
+    }
+
+    #[test]
+    fn test_02_given_a_snapshot_and_later_batches_when_replaying_then_it_should_return_the_snapshot_followed_by_them()
+     {
+        let directory = TempDir::new().unwrap();
+        let mut write_ahead_log = open(&directory, 10);
+        write_ahead_log.append(&[entry(dec!(1))]).unwrap();
+        write_ahead_log.append(&[entry(dec!(2))]).unwrap();
+        write_ahead_log.snapshot(&[entry(dec!(3))]).unwrap();
+        write_ahead_log.append(&[entry(dec!(4))]).unwrap();
+        drop(write_ahead_log);
+
+        let mut write_ahead_log = open(&directory, 10);
+        assert_eq!(
+            write_ahead_log.replay().unwrap(),
+            vec![entry(dec!(3)), entry(dec!(4))]
+        );
+    }
+
+    #[test]
+    fn test_03_given_a_snapshot_when_taking_it_then_it_should_replace_the_previous_one_and_empty_the_log()
+     {
+        let directory = TempDir::new().unwrap();
+        let mut write_ahead_log = open(&directory, 10);
+        write_ahead_log.snapshot(&[entry(dec!(1))]).unwrap();
+        write_ahead_log.append(&[entry(dec!(2))]).unwrap();
+        write_ahead_log.append(&[entry(dec!(3))]).unwrap();
+        assert_eq!(log_contents(&directory).lines().count(), 2);
+
+        write_ahead_log.snapshot(&[entry(dec!(5))]).unwrap();
+
+        assert_eq!(log_contents(&directory), "");
+        let snapshot_path = directory.path().join(SNAPSHOT_FILE_NAME);
+        let snapshot: StoredBatch =
+            serde_json::from_str(&fs::read_to_string(&snapshot_path).unwrap()).unwrap();
+        assert_eq!(snapshot.sequence, 2);
+        assert_eq!(snapshot.into_entries().unwrap(), vec![entry(dec!(5))]);
+        assert!(!snapshot_path.with_extension("json.tmp").exists());
+    }
+
+    #[test]
+    fn test_04_given_a_torn_last_line_when_replaying_then_it_should_drop_it() {
+        let directory = TempDir::new().unwrap();
+        let mut write_ahead_log = open(&directory, 10);
+        write_ahead_log.append(&[entry(dec!(1))]).unwrap();
+        drop(write_ahead_log);
+        let valid_contents = log_contents(&directory);
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(directory.path().join(LOG_FILE_NAME))
+            .unwrap();
+        file.write_all(b"{\"sequence\":2,\"entries\":[{\"ty")
+            .unwrap();
+
+        let mut write_ahead_log = open(&directory, 10);
+        assert_eq!(write_ahead_log.replay().unwrap(), vec![entry(dec!(1))]);
+        assert_eq!(log_contents(&directory), valid_contents);
+        write_ahead_log.append(&[entry(dec!(2))]).unwrap();
+        drop(write_ahead_log);
+
+        let mut write_ahead_log = open(&directory, 10);
+        assert_eq!(
+            write_ahead_log.replay().unwrap(),
+            vec![entry(dec!(1)), entry(dec!(2))]
+        );
+    }
+
+    #[test]
+    fn test_05_given_an_invalid_line_before_the_last_one_when_replaying_then_it_should_fail() {
+        let directory = TempDir::new().unwrap();
+        let mut write_ahead_log = open(&directory, 10);
+        write_ahead_log.append(&[entry(dec!(1))]).unwrap();
+        drop(write_ahead_log);
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(directory.path().join(LOG_FILE_NAME))
+            .unwrap();
+        file.write_all(b"not json\n{\"sequence\":3,\"entries\":[]}\n")
+            .unwrap();
+
+        let error = open(&directory, 10).replay().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid write-ahead log line after sequence 1"
+        );
+    }
+
+    #[test]
+    fn test_06_given_batches_already_in_the_snapshot_when_replaying_then_it_should_skip_them() {
+        let directory = TempDir::new().unwrap();
+        let mut write_ahead_log = open(&directory, 10);
+        write_ahead_log.append(&[entry(dec!(1))]).unwrap();
+        write_ahead_log.append(&[entry(dec!(2))]).unwrap();
+        let contents = log_contents(&directory);
+        write_ahead_log
+            .snapshot(&[entry(dec!(1)), entry(dec!(2))])
+            .unwrap();
+        drop(write_ahead_log);
+        // A crash between writing the snapshot and emptying the log leaves the batches it has.
+        fs::write(directory.path().join(LOG_FILE_NAME), &contents).unwrap();
+
+        let mut write_ahead_log = open(&directory, 10);
+        assert_eq!(
+            write_ahead_log.replay().unwrap(),
+            vec![entry(dec!(1)), entry(dec!(2))]
+        );
+        assert!(!write_ahead_log.is_snapshot_due());
+        write_ahead_log.append(&[entry(dec!(3))]).unwrap();
+        drop(write_ahead_log);
+
+        let mut write_ahead_log = open(&directory, 10);
+        assert_eq!(
+            write_ahead_log.replay().unwrap(),
+            vec![entry(dec!(1)), entry(dec!(2)), entry(dec!(3))]
+        );
+    }
+
+    #[test]
+    fn test_07_given_enough_batches_appended_when_checking_the_snapshot_then_it_should_be_due_until_taken()
+     {
+        let directory = TempDir::new().unwrap();
+        let mut write_ahead_log = open(&directory, 2);
+        write_ahead_log.append(&[entry(dec!(1))]).unwrap();
+        assert!(!write_ahead_log.is_snapshot_due());
+        write_ahead_log.append(&[entry(dec!(2))]).unwrap();
+        assert!(write_ahead_log.is_snapshot_due());
+        write_ahead_log.snapshot(&[entry(dec!(2))]).unwrap();
+        assert!(!write_ahead_log.is_snapshot_due());
+    }
+
+    #[test]
+    fn test_08_given_a_failed_write_when_appending_then_it_should_refuse_every_later_batch() {
+        let directory = TempDir::new().unwrap();
+        let mut write_ahead_log = open(&directory, 10);
+        write_ahead_log.append(&[entry(dec!(1))]).unwrap();
+        write_ahead_log.failed = true;
+
+        assert!(write_ahead_log.ensure_writable().is_err());
+        assert!(write_ahead_log.append(&[entry(dec!(2))]).is_err());
+        assert_eq!(log_contents(&directory).lines().count(), 1);
+    }
+
+    #[test]
+    fn test_09_given_a_policy_name_when_parsing_it_then_it_should_ignore_case_and_spaces() {
+        assert_eq!(
+            "always".parse::<FsyncPolicy>().unwrap(),
+            FsyncPolicy::Always
+        );
+        assert_eq!(
+            " Batched ".parse::<FsyncPolicy>().unwrap(),
+            FsyncPolicy::Batched
+        );
+        assert_eq!("NEVER".parse::<FsyncPolicy>().unwrap(), FsyncPolicy::Never);
+        assert_eq!(
+            "sometimes".parse::<FsyncPolicy>().unwrap_err().to_string(),
+            "WAL_FSYNC must be always, batched or never, got \"sometimes\""
+        );
+    }
+
+    #[test]
+    fn test_10_given_the_env_values_when_opening_the_log_then_it_should_use_them() {
+        let directory = TempDir::new().unwrap();
+        let directory_path = directory.path().to_str().unwrap().to_string();
+        assert!(WriteAheadLog::from_vars(|_| None).unwrap().is_none());
+
+        let vars = HashMap::from([
+            ("WAL_DIR", directory_path.clone()),
+            ("WAL_FSYNC", "never".to_string()),
+            ("WAL_FSYNC_INTERVAL_MS", "50".to_string()),
+            ("WAL_SNAPSHOT_EVERY", " 5 ".to_string()),
+        ]);
+        let write_ahead_log = WriteAheadLog::from_vars(|name| vars.get(name).cloned())
+            .unwrap()
+            .unwrap();
+        assert_eq!(write_ahead_log.fsync_policy, FsyncPolicy::Never);
+        assert_eq!(write_ahead_log.snapshot_every, 5);
+
+        let vars = HashMap::from([("WAL_DIR", directory_path)]);
+        let write_ahead_log = WriteAheadLog::from_vars(|name| vars.get(name).cloned())
+            .unwrap()
+            .unwrap();
+        assert_eq!(write_ahead_log.fsync_policy, FsyncPolicy::Always);
+        assert_eq!(write_ahead_log.snapshot_every, DEFAULT_SNAPSHOT_EVERY);
+    }
+
+    #[test]
+    fn test_11_given_invalid_env_values_when_opening_the_log_then_it_should_fail() {
+        let directory = TempDir::new().unwrap();
+        let directory_path = directory.path().to_str().unwrap().to_string();
+        for (name, value, message) in [
+            (
+                "WAL_FSYNC",
+                "sometimes",
+                "WAL_FSYNC must be always, batched or never, got \"sometimes\"",
+            ),
+            (
+                "WAL_FSYNC_INTERVAL_MS",
+                "soon",
+                "WAL_FSYNC_INTERVAL_MS must be a number",
+            ),
+            (
+                "WAL_SNAPSHOT_EVERY",
+                "-1",
+                "WAL_SNAPSHOT_EVERY must be a number",
+            ),
+        ] {
+            let vars = HashMap::from([
+                ("WAL_DIR", directory_path.clone()),
+                (name, value.to_string()),
+            ]);
+            let error = WriteAheadLog::from_vars(|name| vars.get(name).cloned())
+                .err()
+                .unwrap();
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    /// Appends the stored entries as a batch, as an older version could have written them.
+    fn append_stored(directory: &TempDir, sequence: u64, entries: Vec<StoredEntry>) {
+        let line = serde_json::to_string(&StoredBatch { sequence, entries }).unwrap();
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(directory.path().join(LOG_FILE_NAME))
+            .unwrap();
+        writeln!(file, "{line}").unwrap();
+    }
+
+    fn stored_client() -> StoredClient {
+        let client = Client::new(
+            ClientId::new("0").unwrap(),
+            ClientName::new("John Doe").unwrap(),
+            BirthDate::new("1990-01-01").unwrap(),
+            Document::new(DocumentType::new("dni").unwrap(), "12345678").unwrap(),
+            Country::new("AR").unwrap(),
+        );
+        StoredClient::from(&client)
+    }
+
+    #[test]
+    fn test_12_given_entries_the_current_rules_reject_when_replaying_then_it_should_restore_them_as_stored()
+     {
+        let directory = TempDir::new().unwrap();
+        drop(open(&directory, 10));
+        let name = "J".repeat(MAX_LENGTH_NAME + 1);
+        let mut client = stored_client();
+        client.name = name.clone();
+        client.country = "XX".to_string();
+        client.email = Some("not an email".to_string());
+        client.phone = Some("123".to_string());
+        append_stored(
+            &directory,
+            1,
+            vec![
+                StoredEntry::ClientSaved { client },
+                StoredEntry::BalanceSet {
+                    client_id: "0".to_string(),
+                    account_id: "0".to_string(),
+                    currency: Currency::default().to_string(),
+                    balance: dec!(1.005),
+                },
+            ],
+        );
+
+        let entries = open(&directory, 10).replay().unwrap();
+
+        let LogEntry::ClientSaved(client) = &entries[0] else {
+            panic!("expected the client, got {:?}", entries[0]);
+        };
+        assert_eq!(client.name().to_string(), name);
+        assert_eq!(client.country().to_string(), "XX");
+        assert_eq!(client.email().unwrap().to_string(), "not an email");
+        assert_eq!(client.phone().unwrap().to_string(), "123");
+        let LogEntry::BalanceSet { balance, .. } = &entries[1] else {
+            panic!("expected the balance, got {:?}", entries[1]);
+        };
+        assert_eq!(balance.amount(), &dec!(1.005));
+    }
+
+    #[test]
+    fn test_13_given_a_corrupt_entry_when_replaying_then_it_should_name_its_batch_and_position() {
+        let directory = TempDir::new().unwrap();
+        drop(open(&directory, 10));
+        let mut client = stored_client();
+        client.birth_date = "yesterday".to_string();
+        append_stored(
+            &directory,
+            1,
+            vec![
+                StoredEntry::from(&entry(dec!(1))),
+                StoredEntry::ClientSaved { client },
+            ],
+        );
+
+        let error = open(&directory, 10).replay().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid entry 1 of write-ahead log batch 1"
+        );
+    }
+}
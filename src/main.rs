@@ -1,24 +1,58 @@
+use prex_core_challenge::domain::port::outbound::client_balance_repository::ClientBalanceRepository;
 use prex_core_challenge::infrastructure::inbound::http::logger::CustomLogger;
+#[cfg(feature = "sqlite")]
+use prex_core_challenge::infrastructure::outbound::sqlite::SqliteRepository;
 use prex_core_challenge::infrastructure::outbound::{
-    file_exporter::FileExporter, in_memory::InMemoryRepository,
+    exchange_rate_table::ExchangeRateTable, file_exporter::FileExporter, id_generators,
+    in_memory::InMemoryRepository, system_clock::SystemClock, write_ahead_log::WriteAheadLog,
 };
 use prex_core_challenge::{
-    application::client_balance_service::Service, infrastructure::inbound::http::server::HttpServer,
+    application::client_balance_service::Service,
+    domain::model::value::birth_date_rules::BirthDateRules,
+    infrastructure::inbound::http::server::HttpServer,
 };
 
 #[tokio::main]
 async fn main() -> Result<(), anyhow::Error> {
     CustomLogger::init_logger();
 
+    let id_generator = id_generators::from_env()?;
+
+    // The repository is chosen with `REPOSITORY`, in memory by default.
+    let repository = std::env::var("REPOSITORY").unwrap_or("in_memory".to_string());
+    match repository.trim().to_lowercase().as_str() {
+        "in_memory" => {
+            let mut in_memory_repository =
+                InMemoryRepository::new().with_id_generator(id_generator);
+            if let Some(write_ahead_log) = WriteAheadLog::from_env()? {
+                in_memory_repository =
+                    in_memory_repository.with_write_ahead_log(write_ahead_log)?;
+            }
+            serve(in_memory_repository).await?
+        }
+        #[cfg(feature = "sqlite")]
+        "sqlite" => serve(SqliteRepository::from_env()?.with_id_generator(id_generator)).await?,
+        #[cfg(not(feature = "sqlite"))]
+        "sqlite" => anyhow::bail!("REPOSITORY=sqlite needs a build with the `sqlite` feature"),
+        _ => anyhow::bail!("REPOSITORY must be in_memory or sqlite, got \"{repository}\""),
+    }
+
+    tracing::info!("Goodbye 👋");
+    Ok(())
+}
+
+async fn serve<C: ClientBalanceRepository>(repository: C) -> Result<(), anyhow::Error> {
     let file_exporter = FileExporter::new().await?;
 
-    let in_memory_repository = InMemoryRepository::new();
+    let exchange_rate_table = ExchangeRateTable::from_env().await?;
 
-    let service_client = Service::new(in_memory_repository, file_exporter);
+    let birth_date_rules = BirthDateRules::from_env()?;
+
+    let service_client = Service::new(repository, file_exporter, exchange_rate_table, SystemClock)
+        .with_birth_date_rules(birth_date_rules);
 
     let server = HttpServer::new(service_client)?;
 
     server.run().await?;
-    tracing::info!("Goodbye 👋");
     Ok(())
 }